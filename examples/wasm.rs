@@ -0,0 +1,39 @@
+//! Lint a commit message and get the problems back as JSON
+//!
+//! This is what a browser based commit message editor would call, via
+//! something like `wasm-bindgen`. To build it for the browser
+//!
+//! ``` shell
+//! cargo build --example wasm --target wasm32-unknown-unknown \
+//!     --no-default-features --features regex-lints,serde
+//! ```
+
+use mit_commit::CommitMessage;
+use mit_lint::{lint, Lints};
+
+fn lint_to_json(message: &str) -> String {
+    let problems = lint(&CommitMessage::from(message), Lints::available().clone());
+
+    serde_json::to_string(&problems).expect("Problems to be serialisable")
+}
+
+fn main() {
+    println!("{}", lint_to_json(&"x".repeat(73)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lint_to_json;
+
+    #[test]
+    fn it_returns_the_problems_as_json() {
+        let actual: serde_json::Value =
+            serde_json::from_str(&lint_to_json(&"x".repeat(73))).unwrap();
+
+        assert!(actual
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|problem| problem["code"] == "SubjectLongerThan72Characters"));
+    }
+}
@@ -0,0 +1,95 @@
+//! Check for tab characters in the body
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{paragraphs, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "body-contains-tabs";
+/// Description of the problem
+pub const ERROR: &str = "Your commit has tabs in the body";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Git indents the message when showing it in the log, and tabs \
+                            don't line up with that indentation, so anything aligned with them \
+                            ends up out of place.\n\nYou can fix this by replacing the tabs with \
+                            spaces";
+
+/// Configuration for where tabs are allowed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct BodyContainsTabsConfig {
+    /// Allow tabs at the start of a line, for indenting code blocks
+    pub allow_leading_tabs: bool,
+}
+
+fn first_tab(line: &str, config: &BodyContainsTabsConfig) -> Option<usize> {
+    let indent = if config.allow_leading_tabs {
+        line.len() - line.trim_start_matches('\t').len()
+    } else {
+        0
+    };
+
+    line[indent..].find('\t').map(|tab| indent + tab)
+}
+
+/// Lint the commit message, returning a [`Problem`] if there are tabs in the
+/// body
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &BodyContainsTabsConfig::default())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    lint_with_options(
+        commit_message,
+        comment_char,
+        &BodyContainsTabsConfig::default(),
+    )
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] labelling the first tab on each line of the body that has one
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &BodyContainsTabsConfig,
+) -> Option<Problem> {
+    lint_with_options(commit_message, commit_message.get_comment_char(), config)
+}
+
+fn lint_with_options(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+    config: &BodyContainsTabsConfig,
+) -> Option<Problem> {
+    let commit_text = text_above_scissors(commit_message);
+    let labels: Vec<(String, usize, usize)> = paragraphs(&commit_text, comment_char)
+        .into_iter()
+        .flatten()
+        .skip(1)
+        .filter_map(|(offset, line)| {
+            first_tab(line, config).map(|tab| ("Tab".to_string(), offset + tab, 1))
+        })
+        .collect();
+    if labels.is_empty() {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::BodyContainsTabs,
+        commit_message,
+        Some(labels),
+        Some("https://git-scm.com/docs/git-log".to_string()),
+    ))
+}
@@ -0,0 +1,144 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::body_contains_tabs::{
+    lint,
+    lint_with_config,
+    BodyContainsTabsConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+#[test]
+fn tab_free_body_passes() {
+    run_test("An example commit\n\nSome body content\n", None);
+}
+
+#[test]
+fn tabs_in_comments_and_below_the_scissors_pass() {
+    run_test(
+        "An example commit
+
+Some body content
+#\tA comment
+# ------------------------ >8 ------------------------
+# Do not modify or remove the line above.
+# Everything below it will be ignored.
+\tfn main() {}
+",
+        None,
+    );
+}
+
+#[test]
+fn mid_line_tab_fails() {
+    let message = "An example commit\n\nSome\tbody\tcontent\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::BodyContainsTabs,
+            &message.into(),
+            Some(vec![("Tab".to_string(), 23, 1)]),
+            Some("https://git-scm.com/docs/git-log".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn leading_tabs_can_be_allowed() {
+    let config = BodyContainsTabsConfig {
+        allow_leading_tabs: true,
+    };
+    let indented = CommitMessage::from("An example commit\n\n\t\tfn main() {}\n");
+    let mid_line = CommitMessage::from("An example commit\n\n\tfn\tmain() {}\n");
+
+    assert!(lint(&indented).is_some());
+    assert_eq!(lint_with_config(&indented, &config), None);
+    assert_eq!(
+        lint_with_config(&mid_line, &config),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::BodyContainsTabs,
+            &mid_line,
+            Some(vec![("Tab".to_string(), 22, 1)]),
+            Some("https://git-scm.com/docs/git-log".to_string()),
+        ))
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "An example commit\n\nSome\tbody content\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "BodyContainsTabs (https://git-scm.com/docs/git-log)
+
+  x Your commit has tabs in the body
+   ,-[3:5]
+ 2 | 
+ 3 | Some    body content
+   :     ^^|^
+   :       `-- Tab
+   `----
+  help: Git indents the message when showing it in the log, and tabs don't
+        line up with that indentation, so anything aligned with them ends up
+        out of place.
+        
+        You can fix this by replacing the tabs with spaces
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(body: String) -> TestResult {
+    if body.contains('\n') || body.contains('\t') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("An example commit\n\nSome body content {body}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(before: String, after: String) -> TestResult {
+    if before.contains('\n') || after.contains('\n') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body content {before}\t{after}\n"
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -0,0 +1,81 @@
+//! Check for extra blank lines at the start or end of the body
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{is_empty_message, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "body-leading-trailing-blank-lines";
+/// Description of the problem
+pub const ERROR: &str = "Your commit has extra blank lines around the body";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "A single blank line separates the subject from the body, and the \
+                            body from the trailers. Any more looks untidy, and can stop the \
+                            trailers being found.\n\nYou can fix this by removing the extra blank \
+                            lines";
+
+/// Get the offset of the first blank line that shouldn't be there
+fn find_extra_blank_line(commit_message: &CommitMessage<'_>) -> Option<usize> {
+    let comment_char = commit_message.get_comment_char();
+    if is_empty_message(commit_message, comment_char) {
+        return None;
+    }
+
+    let commit_text = text_above_scissors(commit_message);
+    let mut offset = 0;
+    let mut lines: Vec<(usize, bool)> = vec![];
+    for line in commit_text.split_inclusive('\n') {
+        if comment_char.is_none_or(|comment_char| !line.starts_with(comment_char)) {
+            lines.push((offset, line.trim().is_empty()));
+        }
+        offset += line.len();
+    }
+
+    let mut runs: Vec<(usize, usize)> = vec![];
+    for (index, (_, is_blank)) in lines.iter().enumerate().skip(1) {
+        match runs.last_mut() {
+            Some((start, length)) if *is_blank && *start + *length == index => *length += 1,
+            _ if *is_blank => runs.push((index, 1)),
+            _ => {}
+        }
+    }
+
+    let is_leading = |start: usize| start == 1;
+    let is_trailing = |start: usize, length: usize| start + length == lines.len();
+    let run_before_trailers = runs
+        .iter()
+        .rev()
+        .find(|(start, length)| !is_trailing(*start, *length))
+        .filter(|_| !commit_message.get_trailers().is_empty());
+
+    runs.iter()
+        .find(|(start, length)| {
+            *length > 1
+                && (is_leading(*start)
+                    || is_trailing(*start, *length)
+                    || run_before_trailers == Some(&(*start, *length)))
+        })
+        .and_then(|(start, _)| lines.get(start + 1))
+        .map(|(offset, _)| *offset)
+}
+
+/// Lint the commit message, returning a [`Problem`] if there are extra blank
+/// lines at the start of the body, before the trailers, or at the end
+///
+/// One blank line at the end is fine, as git's editor template leaves one
+/// before its comments.
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    let offset = find_extra_blank_line(commit_message)?;
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::BodyLeadingTrailingBlankLines,
+        commit_message,
+        Some(vec![("Extra blank line".to_string(), offset, 1)]),
+        Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+    ))
+}
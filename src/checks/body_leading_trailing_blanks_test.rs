@@ -0,0 +1,165 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::body_leading_trailing_blanks::{lint, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+#[test]
+fn clean_message_passes() {
+    run_test(
+        "Subject
+
+Body
+
+Signed-off-by: Someone Else <someone@example.com>
+",
+        None,
+    );
+}
+
+#[test]
+fn one_blank_line_before_the_comments_passes() {
+    run_test(
+        "Subject
+
+Body
+
+# Please enter the commit message for your changes.
+",
+        None,
+    );
+}
+
+#[test]
+fn blank_lines_in_the_middle_of_the_body_pass() {
+    run_test("Subject\n\nBody\n\n\nMore body\n", None);
+}
+
+#[test]
+fn leading_blank_lines_fail() {
+    let message = "Subject\n\n\nBody";
+    run_test(message, Some(&expected_problem(message, 9)));
+}
+
+#[test]
+fn trailing_blank_lines_fail() {
+    let message = "Subject\n\nBody\n\n\n";
+    run_test(message, Some(&expected_problem(message, 15)));
+}
+
+#[test]
+fn trailing_blank_lines_before_the_scissors_fail() {
+    let message = "Subject
+
+Body
+
+
+# ------------------------ >8 ------------------------
+# Do not modify or remove the line above.
+# Everything below it will be ignored.
+";
+    run_test(message, Some(&expected_problem(message, 15)));
+}
+
+#[test]
+fn blank_lines_before_the_trailers_fail() {
+    let message = "Subject
+
+Body
+
+
+Signed-off-by: Someone Else <someone@example.com>
+";
+    run_test(message, Some(&expected_problem(message, 15)));
+}
+
+fn expected_problem(message: &str, offset: usize) -> Problem {
+    Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::BodyLeadingTrailingBlankLines,
+        &message.into(),
+        Some(vec![("Extra blank line".to_string(), offset, 1)]),
+        Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+    )
+}
+
+#[test]
+fn formatting() {
+    let message = "Subject\n\n\nBody\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "BodyLeadingTrailingBlankLines (https://git-scm.com/docs/git-commit#_discussion)
+
+  x Your commit has extra blank lines around the body
+   ,-[3:1]
+ 2 | 
+ 3 | 
+   : |
+   : `-- Extra blank line
+ 4 | Body
+   `----
+  help: A single blank line separates the subject from the body, and the body
+        from the trailers. Any more looks untidy, and can stop the trailers
+        being found.
+        
+        You can fix this by removing the extra blank lines
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(body: Vec<String>) -> TestResult {
+    if body.is_empty()
+        || body
+            .iter()
+            .any(|line| line.trim().is_empty() || line.contains('\n'))
+    {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Subject\n\n{}\n", body.join("\n")));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(body: String, extra: u8) -> TestResult {
+    if body.trim().is_empty() || body.contains('\n') || body.starts_with('#') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "Subject\n\n{}{}",
+        "\n".repeat(usize::from(extra % 5) + 1),
+        body
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -0,0 +1,131 @@
+//! Check for a body that doesn't say anything
+
+use std::collections::HashSet;
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{paragraphs, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "body-not-meaningful";
+/// Description of the problem
+pub const ERROR: &str = "Your commit has a body that doesn't say anything";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "The body is where you explain what the change is and why you \
+                            made it. A body like `asdf` or `wip` leaves the reader with nothing \
+                            to go on.\n\nYou can fix this by describing the change in a few \
+                            words, or removing the body";
+
+pub(crate) const STOPWORDS: [&str; 28] = [
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "from", "has", "in", "is", "it",
+    "its", "of", "on", "or", "so", "that", "the", "this", "to", "was", "were", "will", "with",
+];
+
+/// Configuration for how many words make a meaningful body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BodyNotMeaningfulConfig {
+    /// The fewest different words, not counting stopwords, the body may have
+    pub min_unique_words: usize,
+    /// Words that don't count towards the minimum, in lowercase
+    pub stopwords: HashSet<String>,
+}
+
+impl Default for BodyNotMeaningfulConfig {
+    fn default() -> Self {
+        Self {
+            min_unique_words: 2,
+            stopwords: STOPWORDS.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+/// Split a line into lowercase words, dropping any punctuation
+pub(crate) fn words(line: &str) -> impl Iterator<Item = String> + '_ {
+    line.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Lint the commit message, returning a [`Problem`] if the body has fewer
+/// than 2 different words, not counting stopwords like "the" or "and"
+///
+/// Messages without a body pass.
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &BodyNotMeaningfulConfig::default())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    lint_with_options(
+        commit_message,
+        comment_char,
+        &BodyNotMeaningfulConfig::default(),
+    )
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if the body has fewer different words than the configured
+/// minimum
+///
+/// Words are compared ignoring case, and trailers aren't counted as part of
+/// the body.
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &BodyNotMeaningfulConfig,
+) -> Option<Problem> {
+    lint_with_options(commit_message, commit_message.get_comment_char(), config)
+}
+
+fn lint_with_options(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+    config: &BodyNotMeaningfulConfig,
+) -> Option<Problem> {
+    let trailers: HashSet<String> = commit_message
+        .get_trailers()
+        .iter()
+        .cloned()
+        .map(|trailer| String::from(trailer).trim().to_string())
+        .collect();
+    let commit_text = text_above_scissors(commit_message);
+    let body: Vec<(usize, &str)> = paragraphs(&commit_text, comment_char)
+        .into_iter()
+        .flatten()
+        .skip(1)
+        .filter(|(_, line)| !trailers.contains(line.trim()))
+        .collect();
+    let (offset, first_line) = *body.first()?;
+
+    let words: HashSet<String> = body
+        .iter()
+        .flat_map(|(_, line)| words(line))
+        .filter(|word| !config.stopwords.contains(word))
+        .collect();
+    if words.len() >= config.min_unique_words {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::BodyNotMeaningful,
+        commit_message,
+        Some(vec![(
+            "Not meaningful".to_string(),
+            offset,
+            first_line.len(),
+        )]),
+        Some("https://cbea.ms/git-commit/#why-not-how".to_string()),
+    ))
+}
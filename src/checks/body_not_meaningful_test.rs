@@ -0,0 +1,164 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::body_not_meaningful::{
+    lint,
+    lint_with_config,
+    BodyNotMeaningfulConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+#[test]
+fn descriptive_body_passes() {
+    run_test(
+        "An example commit\n\nThe cache was never cleared, so stale entries were served\n",
+        None,
+    );
+}
+
+#[test]
+fn no_body_passes() {
+    run_test("An example commit\n", None);
+}
+
+#[test]
+fn trailers_are_not_the_body() {
+    run_test(
+        "An example commit\n\nSigned-off-by: Someone <someone@example.com>\n",
+        None,
+    );
+}
+
+#[test]
+fn repeated_word_fails() {
+    let message = "An example commit\n\nasdf asdf\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::BodyNotMeaningful,
+            &message.into(),
+            Some(vec![("Not meaningful".to_string(), 19, 9)]),
+            Some("https://cbea.ms/git-commit/#why-not-how".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn stopword_only_body_fails() {
+    let message = "An example commit\n\nThe\n\nSigned-off-by: Someone <someone@example.com>\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::BodyNotMeaningful,
+            &message.into(),
+            Some(vec![("Not meaningful".to_string(), 19, 3)]),
+            Some("https://cbea.ms/git-commit/#why-not-how".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn minimum_and_stopwords_can_be_configured() {
+    let message = CommitMessage::from("An example commit\n\nFix typo\n");
+
+    assert!(lint_with_config(&message, &BodyNotMeaningfulConfig::default()).is_none());
+    assert!(lint_with_config(
+        &message,
+        &BodyNotMeaningfulConfig {
+            min_unique_words: 3,
+            ..BodyNotMeaningfulConfig::default()
+        }
+    )
+    .is_some());
+    assert!(lint_with_config(
+        &message,
+        &BodyNotMeaningfulConfig {
+            min_unique_words: 2,
+            stopwords: vec!["fix".to_string()].into_iter().collect(),
+        }
+    )
+    .is_some());
+}
+
+#[test]
+fn formatting() {
+    let message = "An example commit\n\nasdf asdf\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "BodyNotMeaningful (https://cbea.ms/git-commit/#why-not-how)
+
+  x Your commit has a body that doesn't say anything
+   ,-[3:1]
+ 2 | 
+ 3 | asdf asdf
+   : ^^^^|^^^^
+   :     `-- Not meaningful
+   `----
+  help: The body is where you explain what the change is and why you made it.
+        A body like `asdf` or `wip` leaves the reader with nothing to go on.
+        
+        You can fix this by describing the change in a few words, or removing
+        the body
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(body: Vec<String>) -> TestResult {
+    if body.iter().any(|line| line.contains('\n')) {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nExplain the change {}\n",
+        body.join(" ")
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(stopwords: Vec<bool>, repeats: u8) -> TestResult {
+    let body = stopwords
+        .iter()
+        .map(|the| if *the { "the" } else { "and" })
+        .chain(std::iter::repeat_n("asdf", usize::from(repeats) + 1))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let message = CommitMessage::from(format!("An example commit\n\n{body}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -0,0 +1,97 @@
+//! Check for a long paragraph in the body
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{paragraphs, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "body-paragraph-too-long";
+/// Description of the problem
+pub const ERROR: &str = "Your commit has a paragraph in the body that is too long";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Short paragraphs keep the body of the commit easy to skim, so \
+                            people can quickly find the part they need.\n\nYou can fix this by \
+                            splitting the paragraph up with blank lines, or cutting it down";
+
+/// Configuration for how long a paragraph in the body may be
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BodyParagraphTooLongConfig {
+    /// The maximum number of characters in a paragraph, not counting line
+    /// breaks
+    pub max_chars: usize,
+}
+
+impl Default for BodyParagraphTooLongConfig {
+    fn default() -> Self {
+        Self { max_chars: 500 }
+    }
+}
+
+/// Lint the commit message, returning a [`Problem`] if a paragraph in the
+/// body is longer than 500 characters
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &BodyParagraphTooLongConfig::default())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    lint_with_options(
+        commit_message,
+        comment_char,
+        &BodyParagraphTooLongConfig::default(),
+    )
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] for the first paragraph in the body longer than the
+/// configured maximum
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &BodyParagraphTooLongConfig,
+) -> Option<Problem> {
+    lint_with_options(commit_message, commit_message.get_comment_char(), config)
+}
+
+fn lint_with_options(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+    config: &BodyParagraphTooLongConfig,
+) -> Option<Problem> {
+    let commit_text = text_above_scissors(commit_message);
+    let (offset, first_line) = paragraphs(&commit_text, comment_char)
+        .into_iter()
+        .skip(1)
+        .find(|paragraph| {
+            paragraph
+                .iter()
+                .map(|(_, line)| line.chars().count())
+                .sum::<usize>()
+                > config.max_chars
+        })?
+        .first()
+        .copied()?;
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::BodyParagraphTooLong,
+        commit_message,
+        Some(vec![(
+            format!("Longer than {} characters", config.max_chars),
+            offset,
+            first_line.len(),
+        )]),
+        Some("https://cbea.ms/git-commit/#wrap-72".to_string()),
+    ))
+}
@@ -0,0 +1,158 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::body_paragraph_too_long::{
+    lint,
+    lint_with_config,
+    BodyParagraphTooLongConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+#[test]
+fn two_short_paragraphs_pass() {
+    run_test(
+        "An example commit
+
+This is the first paragraph of the body.
+
+This is the second paragraph of the body.
+",
+        None,
+    );
+}
+
+#[test]
+fn long_paragraph_fails() {
+    let message = format!(
+        "An example commit
+
+A short paragraph.
+
+{}",
+        "This line is part of a paragraph that goes on far too long.\n".repeat(10)
+    );
+    run_test(
+        &message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::BodyParagraphTooLong,
+            &message.clone().into(),
+            Some(vec![("Longer than 500 characters".to_string(), 39, 59)]),
+            Some("https://cbea.ms/git-commit/#wrap-72".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn long_subject_is_not_a_body_paragraph() {
+    run_test(&format!("{}\n\nA short body\n", "x".repeat(600)), None);
+}
+
+#[test]
+fn comments_and_scissors_are_not_counted() {
+    run_test(
+        &format!(
+            "An example commit
+
+A short paragraph.
+{}# ------------------------ >8 ------------------------
+# Do not modify or remove the line above.
+# Everything below it will be ignored.
+{}",
+            "# A long comment that is not part of the paragraph at all\n".repeat(10),
+            "+ A long diff line that is not part of the paragraph at all\n".repeat(10),
+        ),
+        None,
+    );
+}
+
+#[test]
+fn maximum_can_be_configured() {
+    let message = CommitMessage::from("An example commit\n\nA short paragraph.\n");
+
+    assert!(lint_with_config(&message, &BodyParagraphTooLongConfig { max_chars: 17 }).is_some());
+    assert!(lint_with_config(&message, &BodyParagraphTooLongConfig { max_chars: 18 }).is_none());
+}
+
+#[test]
+fn formatting() {
+    let message = "An example commit\n\nA short paragraph.\n";
+    let problem = lint_with_config(
+        &CommitMessage::from(message.to_string()),
+        &BodyParagraphTooLongConfig { max_chars: 10 },
+    );
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "BodyParagraphTooLong (https://cbea.ms/git-commit/#wrap-72)
+
+  x Your commit has a paragraph in the body that is too long
+   ,-[3:1]
+ 2 | 
+ 3 | A short paragraph.
+   : ^^^^^^^^^|^^^^^^^^
+   :          `-- Longer than 10 characters
+   `----
+  help: Short paragraphs keep the body of the commit easy to skim, so people
+        can quickly find the part they need.
+        
+        You can fix this by splitting the paragraph up with blank lines, or
+        cutting it down
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(paragraphs: Vec<String>) -> TestResult {
+    if paragraphs
+        .iter()
+        .any(|paragraph| paragraph.chars().count() > 500)
+    {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("An example commit\n\n{}", paragraphs.join("\n\n")));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(line: String) -> TestResult {
+    if line.contains('\n') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "An example commit\n\n{}",
+        format!("Some body content {line}\n").repeat(30)
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -0,0 +1,102 @@
+//! Check for a body that doesn't end with punctuation
+
+use std::collections::HashSet;
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{paragraphs, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "body-sentence-no-terminator";
+/// Description of the problem
+pub const ERROR: &str = "Your commit has a body that doesn't end with punctuation";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Finishing the body with a full stop makes it clear the \
+                            explanation is complete, rather than cut off part way \
+                            through.\n\nYou can fix this by ending the last sentence of the body \
+                            with a `.`, `!` or `?`";
+
+const TERMINATORS: [char; 4] = ['.', '!', '?', ':'];
+
+/// Configuration for which characters can end the body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BodySentenceConfig {
+    /// The characters the last line of the body may end with
+    pub terminators: HashSet<char>,
+}
+
+impl Default for BodySentenceConfig {
+    fn default() -> Self {
+        Self {
+            terminators: TERMINATORS.iter().copied().collect(),
+        }
+    }
+}
+
+/// Lint the commit message, returning a [`Problem`] if the last line of the
+/// body doesn't end with `.`, `!`, `?` or `:`
+///
+/// Messages without a body pass, and trailers aren't counted as part of the
+/// body.
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &BodySentenceConfig::default())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    lint_with_options(commit_message, comment_char, &BodySentenceConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if the last line of the body doesn't end with one of the
+/// configured terminators
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &BodySentenceConfig,
+) -> Option<Problem> {
+    lint_with_options(commit_message, commit_message.get_comment_char(), config)
+}
+
+fn lint_with_options(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+    config: &BodySentenceConfig,
+) -> Option<Problem> {
+    let trailers: HashSet<String> = commit_message
+        .get_trailers()
+        .iter()
+        .cloned()
+        .map(|trailer| String::from(trailer).trim().to_string())
+        .collect();
+    let commit_text = text_above_scissors(commit_message);
+    let (offset, last_line) = paragraphs(&commit_text, comment_char)
+        .into_iter()
+        .flatten()
+        .skip(1)
+        .filter(|(_, line)| !trailers.contains(line.trim()))
+        .last()?;
+    let last_line = last_line.trim_end();
+    if last_line.ends_with(|c: char| config.terminators.contains(&c)) {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::BodySentenceNoTerminator,
+        commit_message,
+        Some(vec![("No terminator".to_string(), offset, last_line.len())]),
+        None,
+    ))
+}
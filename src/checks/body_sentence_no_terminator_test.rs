@@ -0,0 +1,146 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::body_sentence_no_terminator::{
+    lint,
+    lint_with_config,
+    BodySentenceConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+#[test]
+fn terminated_body_passes() {
+    run_test("An example commit\n\nThe work is done.\n", None);
+}
+
+#[test]
+fn no_body_passes() {
+    run_test("An example commit\n", None);
+}
+
+#[test]
+fn trailers_are_ignored() {
+    run_test(
+        "An example commit\n\nThe work is done.\n\nSigned-off-by: Someone <someone@example.com>\n",
+        None,
+    );
+}
+
+#[test]
+fn comments_are_ignored() {
+    run_test(
+        "An example commit\n\nThe work is done.\n# Please enter the commit message\n",
+        None,
+    );
+}
+
+#[test]
+fn unterminated_body_fails() {
+    let message =
+        "An example commit\n\nThe work is done\n\nSigned-off-by: Someone <someone@example.com>\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::BodySentenceNoTerminator,
+            &message.into(),
+            Some(vec![("No terminator".to_string(), 19, 16)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn terminators_can_be_configured() {
+    let message = CommitMessage::from("An example commit\n\nThe work is done;\n");
+    let config = BodySentenceConfig {
+        terminators: vec![';'].into_iter().collect(),
+    };
+
+    assert!(lint(&message).is_some());
+    assert!(lint_with_config(&message, &config).is_none());
+    assert!(lint_with_config(
+        &CommitMessage::from("An example commit\n\nThe work is done.\n"),
+        &config
+    )
+    .is_some());
+}
+
+#[test]
+fn formatting() {
+    let message = "An example commit\n\nThe work is done\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "BodySentenceNoTerminator
+
+  x Your commit has a body that doesn't end with punctuation
+   ,-[3:1]
+ 2 | 
+ 3 | The work is done
+   : ^^^^^^^^|^^^^^^^
+   :         `-- No terminator
+   `----
+  help: Finishing the body with a full stop makes it clear the explanation is
+        complete, rather than cut off part way through.
+        
+        You can fix this by ending the last sentence of the body with a `.`,
+        `!` or `?`
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(body: String, terminator: usize) -> TestResult {
+    if body.contains('\n') || body.contains('#') {
+        return TestResult::discard();
+    }
+    let terminator = ['.', '!', '?', ':'][terminator % 4];
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body content {body}{terminator}\n"
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(body: String) -> TestResult {
+    if body.contains('\n') || body.contains('#') || body.contains(':') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body content {body} done\n"
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -0,0 +1,72 @@
+//! Check for separator lines in the body
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{paragraphs, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "body-separator-line";
+/// Description of the problem
+pub const ERROR: &str = "Your commit has a separator line in the body";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Lines like `----` or `====` are markdown, but git doesn't \
+                            render markdown, so they show up as they are in the log and read \
+                            oddly.\n\nYou can fix this by removing the separator line, and \
+                            using a blank line to separate paragraphs instead";
+
+const SEPARATOR_CHARACTERS: [char; 4] = ['-', '=', '*', '_'];
+
+fn is_separator(line: &str) -> bool {
+    let line = line.trim();
+    let mut characters = line.chars();
+
+    characters.next().is_some_and(|first| {
+        SEPARATOR_CHARACTERS.contains(&first)
+            && line.len() >= 3
+            && characters.all(|character| character == first)
+    })
+}
+
+/// Lint the commit message, returning a [`Problem`] if a line in the body is
+/// only a repeated `-`, `=`, `*` or `_`
+///
+/// At least three characters are needed for the line to count, so short
+/// things like `--` are left alone.
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_comment_char(commit_message, commit_message.get_comment_char())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    let commit_text = text_above_scissors(commit_message);
+    let labels: Vec<(String, usize, usize)> = paragraphs(&commit_text, comment_char)
+        .into_iter()
+        .flatten()
+        .skip(1)
+        .filter(|(_, line)| is_separator(line))
+        .map(|(offset, line)| ("Separator line".to_string(), offset, line.len()))
+        .collect();
+    if labels.is_empty() {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::BodySeparatorLine,
+        commit_message,
+        Some(labels),
+        Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+    ))
+}
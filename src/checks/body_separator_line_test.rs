@@ -0,0 +1,156 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::body_separator_line::{lint, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+#[test]
+fn bullets_pass() {
+    run_test("An example commit\n\n- A bullet\n* Another bullet\n", None);
+}
+
+#[test]
+fn short_dashes_pass() {
+    run_test("An example commit\n\nSome body content\n--\n", None);
+}
+
+#[test]
+fn separators_in_comments_pass() {
+    run_test(
+        "An example commit\n\nSome body content\n# ----------\n",
+        None,
+    );
+}
+
+#[test]
+fn separators_below_the_scissors_pass() {
+    run_test(
+        "An example commit
+
+Some body content
+# ------------------------ >8 ------------------------
+# Do not modify or remove the line above.
+# Everything below it will be ignored.
+----
+",
+        None,
+    );
+}
+
+#[test]
+fn dashed_line_fails() {
+    let message = "An example commit\n\nSome body content\n----\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::BodySeparatorLine,
+            &message.into(),
+            Some(vec![("Separator line".to_string(), 37, 4)]),
+            Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn every_separator_is_labelled() {
+    let message = "An example commit\n\nA heading\n=========\n\n***\n\n___\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::BodySeparatorLine,
+            &message.into(),
+            Some(vec![
+                ("Separator line".to_string(), 29, 9),
+                ("Separator line".to_string(), 40, 3),
+                ("Separator line".to_string(), 45, 3),
+            ]),
+            Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "An example commit\n\nSome body content\n----\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "BodySeparatorLine (https://git-scm.com/docs/git-commit#_discussion)
+
+  x Your commit has a separator line in the body
+   ,-[4:1]
+ 3 | Some body content
+ 4 | ----
+   : ^^|^
+   :   `-- Separator line
+   `----
+  help: Lines like `----` or `====` are markdown, but git doesn't render
+        markdown, so they show up as they are in the log and read oddly.
+        
+        You can fix this by removing the separator line, and using a blank
+        line to separate paragraphs instead
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(body: Vec<String>) -> TestResult {
+    if body.iter().any(|line| line.contains('\n')) {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "An example commit\n\n{}\n",
+        body.iter()
+            .map(|line| format!("Some body content {line}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(character: usize, length: u8, body: String) -> TestResult {
+    if body.contains('\n') {
+        return TestResult::discard();
+    }
+    let separator = ['-', '=', '*', '_'][character % 4]
+        .to_string()
+        .repeat(usize::from(length) + 3);
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body content {body}\n{separator}\n"
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -0,0 +1,127 @@
+//! Check for a body that doesn't seem to be about the subject
+
+use std::collections::HashSet;
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::{
+        body_not_meaningful::{words, STOPWORDS},
+        comments::{paragraphs, text_above_scissors},
+        not_conventional_commit::conventional_prefix_length,
+    },
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "body-unrelated-to-subject";
+/// Description of the problem
+pub const ERROR: &str = "Your commit has a body that doesn't mention the subject";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "The body should explain the change in the subject. When they \
+                            have no words in common, the body is often about something else, \
+                            like a change that belongs in another commit.\n\nYou can fix this by \
+                            rewording the subject or the body so they describe the same change";
+
+/// Configuration for how many words the subject and body must share
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BodyUnrelatedToSubjectConfig {
+    /// The fewest words, not counting stopwords, the subject and body must
+    /// have in common
+    pub min_overlap: usize,
+}
+
+impl Default for BodyUnrelatedToSubjectConfig {
+    fn default() -> Self {
+        Self { min_overlap: 1 }
+    }
+}
+
+fn significant_words<'a>(lines: impl Iterator<Item = &'a str>) -> HashSet<String> {
+    lines
+        .flat_map(words)
+        .filter(|word| !STOPWORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// Lint the commit message, returning a [`Problem`] if the body has no words
+/// in common with the subject
+///
+/// This is a heuristic, so it's wrong sometimes. Messages without a body, or
+/// with a subject made only of stopwords, pass.
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &BodyUnrelatedToSubjectConfig::default())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    lint_with_options(
+        commit_message,
+        comment_char,
+        &BodyUnrelatedToSubjectConfig::default(),
+    )
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if the body has fewer words in common with the subject than
+/// the configured minimum
+///
+/// Words are compared ignoring case. Any conventional commit prefix on the
+/// subject, and the trailers, are ignored.
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &BodyUnrelatedToSubjectConfig,
+) -> Option<Problem> {
+    lint_with_options(commit_message, commit_message.get_comment_char(), config)
+}
+
+fn lint_with_options(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+    config: &BodyUnrelatedToSubjectConfig,
+) -> Option<Problem> {
+    let trailers: HashSet<String> = commit_message
+        .get_trailers()
+        .iter()
+        .cloned()
+        .map(|trailer| String::from(trailer).trim().to_string())
+        .collect();
+    let commit_text = text_above_scissors(commit_message);
+    let mut lines = paragraphs(&commit_text, comment_char).into_iter().flatten();
+    let (_, subject) = lines.next()?;
+    let body: Vec<(usize, &str)> = lines
+        .filter(|(_, line)| !trailers.contains(line.trim()))
+        .collect();
+    let (offset, first_line) = *body.first()?;
+
+    let start = conventional_prefix_length(subject).unwrap_or_default();
+    let subject_words = significant_words(std::iter::once(&subject[start..]));
+    if subject_words.is_empty() {
+        return None;
+    }
+    let body_words = significant_words(body.iter().map(|(_, line)| *line));
+    if subject_words.intersection(&body_words).count() >= config.min_overlap {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::BodyUnrelatedToSubject,
+        commit_message,
+        Some(vec![(
+            "Unrelated to subject".to_string(),
+            offset,
+            first_line.len(),
+        )]),
+        Some("https://cbea.ms/git-commit/#why-not-how".to_string()),
+    ))
+}
@@ -0,0 +1,146 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::body_unrelated_to_subject::{
+    lint,
+    lint_with_config,
+    BodyUnrelatedToSubjectConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+#[test]
+fn overlapping_body_passes() {
+    run_test(
+        "Clear the cache on logout\n\nThe cache kept the old session around\n",
+        None,
+    );
+}
+
+#[test]
+fn words_are_compared_ignoring_case() {
+    run_test("Clear the cache on logout\n\nLogout was slow\n", None);
+}
+
+#[test]
+fn no_body_passes() {
+    run_test("Clear the cache on logout\n", None);
+}
+
+#[test]
+fn stopword_subject_passes() {
+    run_test("The and\n\nBump the linter version\n", None);
+}
+
+#[test]
+fn conventional_prefix_is_ignored() {
+    run_test(
+        "fix(cache): Clear it on logout\n\nThe cache kept the old session\n",
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::BodyUnrelatedToSubject,
+            &"fix(cache): Clear it on logout\n\nThe cache kept the old session\n".into(),
+            Some(vec![("Unrelated to subject".to_string(), 32, 30)]),
+            Some("https://cbea.ms/git-commit/#why-not-how".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn disjoint_body_fails() {
+    let message = "Clear the cache on logout\n\nBump the linter version\n\nSigned-off-by: Someone <someone@example.com>\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::BodyUnrelatedToSubject,
+            &message.into(),
+            Some(vec![("Unrelated to subject".to_string(), 27, 23)]),
+            Some("https://cbea.ms/git-commit/#why-not-how".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn minimum_overlap_can_be_configured() {
+    let message =
+        CommitMessage::from("Clear the cache on logout\n\nThe cache kept the old session\n");
+
+    assert!(lint_with_config(&message, &BodyUnrelatedToSubjectConfig { min_overlap: 1 }).is_none());
+    assert!(lint_with_config(&message, &BodyUnrelatedToSubjectConfig { min_overlap: 2 }).is_some());
+}
+
+#[test]
+fn formatting() {
+    let message = "Clear the cache on logout\n\nBump the linter version\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "BodyUnrelatedToSubject (https://cbea.ms/git-commit/#why-not-how)
+
+  x Your commit has a body that doesn't mention the subject
+   ,-[3:1]
+ 2 | 
+ 3 | Bump the linter version
+   : ^^^^^^^^^^^|^^^^^^^^^^^
+   :            `-- Unrelated to subject
+   `----
+  help: The body should explain the change in the subject. When they have no
+        words in common, the body is often about something else, like a change
+        that belongs in another commit.
+        
+        You can fix this by rewording the subject or the body so they describe
+        the same change
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(subject: String, body: String) -> TestResult {
+    if subject.contains('\n') || body.contains('\n') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Clear {subject}\n\nThe clear {body}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(repeats: u8) -> TestResult {
+    let message = CommitMessage::from(format!(
+        "Clear the cache\n\n{}\n",
+        "Bump the linter ".repeat(usize::from(repeats) + 1)
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -1,9 +1,14 @@
-use std::{ops::Add, option::Option::None};
+//! Check for a long body line
+
+use std::option::Option::None;
 
 use miette::{ByteOffset, SourceOffset};
 use mit_commit::CommitMessage;
 
-use crate::model::{Code, Problem};
+use crate::{
+    checks::comments::uncommented_text,
+    model::{Code, Problem},
+};
 
 /// Canonical lint ID
 pub const CONFIG: &str = "body-wider-than-72-characters";
@@ -17,22 +22,86 @@ pub const HELP_MESSAGE: &str = "It's important to keep the body of the commit na
 /// Description of the problem
 pub const ERROR: &str = "Your commit has a body wider than 72 characters";
 
-fn has_problem(commit: &CommitMessage<'_>) -> bool {
-    commit
-        .get_body()
-        .iter()
-        .map(Clone::clone)
-        .map(String::from)
-        .any(|body| body.lines().any(|line| line.chars().count() > LIMIT))
+fn is_unbreakable_and_allowed(line: &str, config: &BodyWidthConfig) -> bool {
+    config.allow_long_urls && !line.trim().contains(char::is_whitespace)
+}
+
+fn has_problem(
+    commit: &CommitMessage<'_>,
+    comment_char: Option<char>,
+    config: &BodyWidthConfig,
+) -> bool {
+    let text = uncommented_text(commit, comment_char);
+    let mut body = text.lines().skip_while(|line| !line.is_empty()).peekable();
+    if config.exempt_first_paragraph {
+        while body.next_if(|line| line.is_empty()).is_some() {}
+        while body.next_if(|line| !line.is_empty()).is_some() {}
+    }
+
+    body.any(|line| {
+        line.chars().count() > config.limit && !is_unbreakable_and_allowed(line, config)
+    })
 }
 
 const LIMIT: usize = 72;
 
+/// Configuration for how wide the body of a commit may be
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BodyWidthConfig {
+    /// The maximum number of characters on a line in the body
+    pub limit: usize,
+    /// Allow lines that are a single word with no spaces, like a URL, to be
+    /// wider than the limit, as there's no way to wrap them
+    pub allow_long_urls: bool,
+    /// Don't check the first paragraph of the body, for conventions where it
+    /// expands on the subject and is allowed to run long
+    pub exempt_first_paragraph: bool,
+}
+
+impl Default for BodyWidthConfig {
+    fn default() -> Self {
+        Self {
+            limit: LIMIT,
+            allow_long_urls: false,
+            exempt_first_paragraph: false,
+        }
+    }
+}
+
+/// Lint the commit message, returning a [`Problem`] if any line in the body is
+/// wider than 72 characters
 pub fn lint(commit: &CommitMessage<'_>) -> Option<Problem> {
-    if !has_problem(commit) {
+    lint_with_comment_char(commit, commit.get_comment_char())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message.
+pub fn lint_with_comment_char(
+    commit: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    lint_with_options(commit, comment_char, &BodyWidthConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] labelling every line in the body wider than the configured
+/// limit
+pub fn lint_with_config(commit: &CommitMessage<'_>, config: &BodyWidthConfig) -> Option<Problem> {
+    lint_with_options(commit, commit.get_comment_char(), config)
+}
+
+pub(crate) fn lint_with_options(
+    commit: &CommitMessage<'_>,
+    comment_char: Option<char>,
+    config: &BodyWidthConfig,
+) -> Option<Problem> {
+    if !has_problem(commit, comment_char, config) {
         return None;
     }
-    let comment_char = commit.get_comment_char().map(|x| format!("{x} "));
+    let comment_char = comment_char.map(|x| format!("{x} "));
     let commit_text: String = commit.clone().into();
     let scissors_start_line = commit_text.lines().count()
         - commit
@@ -49,8 +118,28 @@ pub fn lint(commit: &CommitMessage<'_>) -> Option<Problem> {
                 .is_none_or(|comment_char| !line.starts_with(comment_char))
         })
         .filter(|(line_index, _)| *line_index < scissors_start_line)
-        .filter(|(line_index, line)| line_index > &0 && line.len() > LIMIT)
-        .map(|(line_index, line)| label_line_over_limit(commit_text.clone(), line_index, line))
+        .scan(
+            (0_usize, false),
+            |(paragraph, after_blank), (line_index, line)| {
+                if line.is_empty() {
+                    *after_blank = true;
+                } else if *after_blank {
+                    *paragraph += 1;
+                    *after_blank = false;
+                }
+                Some((*paragraph, line_index, line))
+            },
+        )
+        .filter(|(paragraph, ..)| !(config.exempt_first_paragraph && *paragraph == 1))
+        .map(|(_, line_index, line)| (line_index, line))
+        .filter(|(line_index, line)| {
+            line_index > &0
+                && line.chars().count() > config.limit
+                && !is_unbreakable_and_allowed(line, config)
+        })
+        .map(|(line_index, line)| {
+            label_line_over_limit(commit_text.clone(), line_index, line, config.limit)
+        })
         .collect();
 
     Some(Problem::new(
@@ -65,14 +154,138 @@ pub fn lint(commit: &CommitMessage<'_>) -> Option<Problem> {
         ))
 }
 
+/// Label the part of the line past the limit, which is counted in
+/// characters, as a byte offset and length
 fn label_line_over_limit(
     commit_text: String,
     line_index: usize,
     line: &str,
+    limit: usize,
 ) -> (String, ByteOffset, usize) {
+    let line_start = SourceOffset::from_location(commit_text, line_index + 1, 1).offset();
+    let over_limit = line
+        .char_indices()
+        .nth(limit)
+        .map_or(line.len(), |(index, _)| index);
+
     (
         "Too long".to_string(),
-        SourceOffset::from_location(commit_text, line_index + 1, LIMIT.add(1)).offset(),
-        line.len() - (LIMIT),
+        line_start + over_limit,
+        line.len() - over_limit,
     )
 }
+
+/// Rewrap any paragraph in the body with a line wider than the configured
+/// limit
+///
+/// Blank lines, list markers, trailers, comments and everything below the
+/// scissors line are left as they are.
+#[must_use]
+pub fn fix(commit: &CommitMessage<'_>, config: &BodyWidthConfig) -> CommitMessage<'static> {
+    let commit_text: String = commit.clone().into();
+    let scissors_line_count = commit
+        .get_scissors()
+        .map(|s| String::from(s).lines().count())
+        .unwrap_or_default();
+    let lines: Vec<&str> = commit_text.split_inclusive('\n').collect();
+    let (head, tail) = lines.split_at(lines.len().saturating_sub(scissors_line_count));
+
+    let comment_char = commit.get_comment_char();
+    let trailers: Vec<String> = commit
+        .get_trailers()
+        .iter()
+        .cloned()
+        .map(|trailer| String::from(trailer).trim_end().to_string())
+        .collect();
+
+    let mut fixed: Vec<String> = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    for (line_index, line) in head
+        .iter()
+        .map(|line| line.trim_end_matches('\n'))
+        .enumerate()
+    {
+        let left_alone = line_index == 0
+            || line.trim().is_empty()
+            || comment_char.is_some_and(|comment_char| line.starts_with(comment_char))
+            || trailers.iter().any(|trailer| trailer == line);
+
+        if left_alone || list_marker(line).is_some() {
+            fixed.extend(rewrap(&paragraph, config.limit));
+            paragraph.clear();
+        }
+
+        if left_alone {
+            fixed.push(line.to_string());
+        } else {
+            paragraph.push(line);
+        }
+    }
+    fixed.extend(rewrap(&paragraph, config.limit));
+
+    let mut fixed_text = fixed.join("\n");
+    if head.last().is_some_and(|line| line.ends_with('\n')) {
+        fixed_text.push('\n');
+    }
+    fixed_text.extend(tail.iter().copied());
+
+    CommitMessage::from(fixed_text)
+}
+
+fn list_marker(line: &str) -> Option<usize> {
+    let indent = line.len() - line.trim_start().len();
+    let rest = &line[indent..];
+
+    if rest.starts_with("- ") || rest.starts_with("* ") {
+        Some(indent + 2)
+    } else {
+        None
+    }
+}
+
+fn rewrap(paragraph: &[&str], limit: usize) -> Vec<String> {
+    let Some(first_line) = paragraph.first() else {
+        return vec![];
+    };
+
+    if paragraph.iter().all(|line| line.chars().count() <= limit) {
+        return paragraph.iter().map(ToString::to_string).collect();
+    }
+
+    let prefix_len =
+        list_marker(first_line).unwrap_or_else(|| first_line.len() - first_line.trim_start().len());
+    let prefix = &first_line[..prefix_len];
+    let hanging_indent = if list_marker(first_line).is_some() {
+        " ".repeat(prefix.chars().count())
+    } else {
+        prefix.to_string()
+    };
+
+    let words = paragraph.iter().enumerate().flat_map(|(index, line)| {
+        if index == 0 {
+            line[prefix_len..].split_whitespace()
+        } else {
+            line.split_whitespace()
+        }
+    });
+
+    let mut lines = vec![];
+    let mut current = prefix.to_string();
+    let mut current_is_empty = true;
+    for word in words {
+        if !current_is_empty && current.chars().count() + 1 + word.chars().count() > limit {
+            lines.push(current);
+            current = hanging_indent.clone();
+            current_is_empty = true;
+        }
+
+        if !current_is_empty {
+            current.push(' ');
+        }
+        current.push_str(word);
+        current_is_empty = false;
+    }
+    lines.push(current);
+
+    lines
+}
@@ -6,7 +6,15 @@ use miette::{GraphicalReportHandler, GraphicalTheme, Report};
 use mit_commit::CommitMessage;
 use quickcheck::TestResult;
 
-use super::body_wider_than_72_characters::{lint, ERROR, HELP_MESSAGE};
+use super::body_wider_than_72_characters::{
+    fix,
+    lint,
+    lint_with_comment_char,
+    lint_with_config,
+    BodyWidthConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
 use crate::{model::Code, Problem};
 
 #[test]
@@ -113,6 +121,27 @@ fn longer_than_73_still_fails() {
     );
 }
 
+#[test]
+fn multibyte_lines_are_measured_in_characters() {
+    let message = format!(
+        "Subject\n\n{}\n{}\n{}",
+        "é".repeat(40),
+        "x".repeat(73),
+        "é".repeat(74)
+    );
+    test_body_wider_than_72_characters(
+        &message.clone(),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::BodyWiderThan72Characters,
+            &message.into(),
+            Some(vec![("Too long".to_string(), 162, 1), ("Too long".to_string(), 308, 4)]),
+            Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+        )).as_ref(),
+    );
+}
+
 #[test]
 fn multiple_long_lines_fails() {
     let message = format!("Subject\n\n{}\n{}", "x".repeat(73), "y".repeat(73));
@@ -175,6 +204,225 @@ fn lines_after_scissors_and_comments_are_not_included() {
     test_body_wider_than_72_characters(&message, None);
 }
 
+#[test]
+fn only_lines_starting_with_the_given_comment_char_are_skipped() {
+    let message = CommitMessage::from(format!("Subject\n\nA body\n\n; {}\n", "x".repeat(72)));
+    assert_eq!(lint_with_comment_char(&message, Some(';')), None);
+
+    let message = CommitMessage::from(format!(
+        "Subject\n\nA body\n; A comment\n# {}\n",
+        "x".repeat(72)
+    ));
+    assert!(lint_with_comment_char(&message, Some(';')).is_some());
+}
+
+#[test]
+fn long_urls_can_be_allowed() {
+    let config = BodyWidthConfig {
+        allow_long_urls: true,
+        ..BodyWidthConfig::default()
+    };
+    let message = CommitMessage::from(format!(
+        "Subject\n\nSee the docs at\n  https://example.com/{}\n",
+        "x".repeat(72)
+    ));
+
+    assert!(lint(&message).is_some());
+    assert_eq!(lint_with_config(&message, &config), None);
+}
+
+#[test]
+fn long_prose_fails_when_long_urls_are_allowed() {
+    let config = BodyWidthConfig {
+        allow_long_urls: true,
+        ..BodyWidthConfig::default()
+    };
+    let message = CommitMessage::from(format!("Subject\n\n{}\n", "word ".repeat(15)));
+
+    assert!(lint_with_config(&message, &config).is_some());
+}
+
+#[test]
+fn long_urls_with_prose_fail_when_long_urls_are_allowed() {
+    let config = BodyWidthConfig {
+        allow_long_urls: true,
+        ..BodyWidthConfig::default()
+    };
+    let message = CommitMessage::from(format!(
+        "Subject\n\nhttps://example.com/{} is where it lives\n",
+        "x".repeat(72)
+    ));
+
+    assert!(lint_with_config(&message, &config).is_some());
+    assert_eq!(lint_with_config(&message, &config), lint(&message));
+}
+
+#[test]
+fn the_first_paragraph_can_be_exempt() {
+    let config = BodyWidthConfig {
+        exempt_first_paragraph: true,
+        ..BodyWidthConfig::default()
+    };
+    let message = CommitMessage::from(format!(
+        "Subject\n\n{}\n{}\n\nShort.\n",
+        "x".repeat(80),
+        "x".repeat(80)
+    ));
+
+    assert!(lint(&message).is_some());
+    assert_eq!(lint_with_config(&message, &config), None);
+}
+
+#[test]
+fn later_paragraphs_fail_when_the_first_is_exempt() {
+    let config = BodyWidthConfig {
+        exempt_first_paragraph: true,
+        ..BodyWidthConfig::default()
+    };
+    let message = format!(
+        "Subject\n\n{}\n\nShort.\n{}\n",
+        "x".repeat(80),
+        "y".repeat(80)
+    );
+
+    assert_eq!(
+        lint_with_config(&CommitMessage::from(message.clone()), &config),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::BodyWiderThan72Characters,
+            &message.into(),
+            Some(vec![("Too long".to_string(), 170, 8)]),
+            Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+        ))
+    );
+}
+
+#[test]
+fn comments_do_not_start_a_paragraph_when_the_first_is_exempt() {
+    let config = BodyWidthConfig {
+        exempt_first_paragraph: true,
+        ..BodyWidthConfig::default()
+    };
+    let message = CommitMessage::from(format!(
+        "Subject\n\n{}\n\n# A comment\n\n{}\n",
+        "x".repeat(80),
+        "y".repeat(80)
+    ));
+
+    assert!(lint_with_config(&message, &config).is_some());
+}
+
+#[test]
+fn fix_rewraps_a_long_paragraph() {
+    let message = CommitMessage::from(format!(
+        "Subject\n\n{}\n\nA short paragraph\n",
+        "Some words that go on for far too long "
+            .repeat(5)
+            .trim_end()
+    ));
+
+    let actual = String::from(fix(&message, &BodyWidthConfig::default()));
+
+    assert_eq!(
+        actual,
+        "Subject
+
+Some words that go on for far too long Some words that go on for far too
+long Some words that go on for far too long Some words that go on for
+far too long Some words that go on for far too long
+
+A short paragraph
+"
+    );
+    assert!(actual.lines().all(|line| line.chars().count() <= 72));
+}
+
+#[test]
+fn fix_uses_the_configured_limit() {
+    let message = CommitMessage::from("Subject\n\nOne two three four five six\n");
+
+    let actual = String::from(fix(
+        &message,
+        &BodyWidthConfig {
+            limit: 10,
+            ..BodyWidthConfig::default()
+        },
+    ));
+
+    assert_eq!(actual, "Subject\n\nOne two\nthree four\nfive six\n");
+}
+
+#[test]
+fn fix_keeps_list_markers() {
+    let message = CommitMessage::from(format!(
+        "Subject\n\n- {}\n* Short item\n  - {}\n",
+        "first item ".repeat(10).trim_end(),
+        "nested item ".repeat(8).trim_end()
+    ));
+
+    let actual = String::from(fix(&message, &BodyWidthConfig::default()));
+
+    assert_eq!(
+        actual,
+        "Subject
+
+- first item first item first item first item first item first item
+  first item first item first item first item
+* Short item
+  - nested item nested item nested item nested item nested item nested
+    item nested item nested item
+"
+    );
+}
+
+#[test]
+fn fix_leaves_comments_trailers_and_scissors_alone() {
+    let long_line = "x ".repeat(40);
+    let message = [
+        "Subject",
+        "",
+        long_line.trim_end(),
+        "",
+        &format!("Co-authored-by: {}", "Someone ".repeat(10).trim_end()),
+        &format!("# {long_line}"),
+        "# ------------------------ >8 ------------------------",
+        &long_line,
+        "",
+    ]
+    .join("\n");
+
+    let actual = String::from(fix(
+        &CommitMessage::from(message.as_str()),
+        &BodyWidthConfig::default(),
+    ));
+
+    assert_eq!(
+        actual,
+        message.replacen(
+            long_line.trim_end(),
+            &format!(
+                "{}\n{}",
+                "x ".repeat(36).trim_end(),
+                "x ".repeat(4).trim_end()
+            ),
+            1
+        )
+    );
+}
+
+#[test]
+fn fix_does_nothing_to_a_narrow_body() {
+    let message = "Subject\n\nA body\nwith short lines\n";
+
+    let actual = String::from(fix(
+        &CommitMessage::from(message),
+        &BodyWidthConfig::default(),
+    ));
+
+    assert_eq!(actual, message);
+}
+
 fn test_body_wider_than_72_characters(message: &str, expected: Option<&Problem>) {
     let actual = lint(&CommitMessage::from(message));
     assert_eq!(
@@ -315,7 +563,7 @@ fn success_check(commit: String) -> TestResult {
         || commit
             .lines()
             .skip(1)
-            .filter(|x| x.starts_with('#'))
+            .filter(|x| !x.starts_with('#'))
             .any(|x| x.chars().count() > 72)
     {
         return TestResult::discard();
@@ -341,3 +589,51 @@ fn fail_check(commit: String) -> TestResult {
     let result = lint(&message);
     TestResult::from_bool(result.is_none())
 }
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn fixed_messages_pass(body: String) -> TestResult {
+    if body.contains('#')
+        || body.contains(':')
+        || body
+            .split_whitespace()
+            .any(|word| word.chars().count() > 60)
+    {
+        return TestResult::discard();
+    }
+
+    let message = CommitMessage::from(format!("Subject\n\n{body}"));
+    let fixed = fix(&message, &BodyWidthConfig::default());
+    TestResult::from_bool(lint(&fixed).is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn labels_start_at_the_limit_and_stay_on_their_line(lines: Vec<String>) -> TestResult {
+    if lines
+        .iter()
+        .any(|line| line.contains(['\n', '\r']) || line.trim().is_empty())
+    {
+        return TestResult::discard();
+    }
+
+    let body: Vec<String> = lines.iter().map(|line| format!("a {line}")).collect();
+    let message = CommitMessage::from(format!("Subject\n\n{}\n", body.join("\n")));
+    let Some(problem) = lint(&message) else {
+        return TestResult::from_bool(body.iter().all(|line| line.chars().count() <= 72));
+    };
+
+    let text = String::from(message);
+    TestResult::from_bool(problem.structured_labels().iter().all(|label| {
+        let line_start = text[..label.offset]
+            .rfind('\n')
+            .map_or(0, |index| index + 1);
+        let line_end = text[label.offset..]
+            .find('\n')
+            .map_or(text.len(), |index| label.offset + index);
+
+        text.is_char_boundary(label.offset)
+            && label.offset + label.length == line_end
+            && text[line_start..label.offset].chars().count() == 72
+    }))
+}
@@ -0,0 +1,103 @@
+//! Working out which parts of a commit message are comments
+
+use mit_commit::CommitMessage;
+
+/// Get the text of the commit above the scissors, without any comment lines
+///
+/// This is like [`CommitMessage::matches_pattern`], but lets the caller
+/// decide what the comment character is, rather than relying on the guess
+/// made when parsing the message.
+pub(crate) fn uncommented_text(commit: &CommitMessage<'_>, comment_char: Option<char>) -> String {
+    let commit_text = String::from(commit.clone());
+    let scissors_start_line = commit_text.lines().count()
+        - commit
+            .get_scissors()
+            .map(|s| String::from(s).lines().count())
+            .unwrap_or_default();
+
+    commit_text
+        .lines()
+        .take(scissors_start_line)
+        .filter(|line| comment_char.is_none_or(|comment_char| !line.starts_with(comment_char)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Check if the commit has nothing in it other than whitespace and comments
+pub(crate) fn is_empty_message(commit: &CommitMessage<'_>, comment_char: Option<char>) -> bool {
+    uncommented_text(commit, comment_char).trim().is_empty()
+}
+
+/// Get the text of the commit above the scissors, including any comments
+pub(crate) fn text_above_scissors(commit: &CommitMessage<'_>) -> String {
+    let commit_text = String::from(commit.clone());
+    let scissors_start = commit_text.len()
+        - commit
+            .get_scissors()
+            .map(|scissors| String::from(scissors).len())
+            .unwrap_or_default();
+
+    commit_text
+        .get(..scissors_start)
+        .unwrap_or(&commit_text)
+        .to_string()
+}
+
+/// Convert an offset into [`CommitMessage::get_subject`] into an offset into
+/// the whole commit text
+///
+/// The subject leaves out any comment lines before or inside it, so those
+/// lines are stepped over to find where the offset lands in the text.
+pub(crate) fn subject_offset_in_text(
+    text: &str,
+    comment_char: Option<char>,
+    subject_offset: usize,
+) -> usize {
+    let mut remaining = subject_offset;
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if comment_char.is_none_or(|comment_char| !line.starts_with(comment_char)) {
+            if remaining < line.len() {
+                return offset + remaining;
+            }
+            remaining -= line.len();
+        }
+        offset += line.len();
+    }
+
+    offset + remaining
+}
+
+/// Split text into paragraphs separated by blank lines, skipping comments
+///
+/// Each line is paired with the byte offset it starts at, so it can be
+/// labelled. The first paragraph is the subject.
+pub(crate) fn paragraphs(text: &str, comment_char: Option<char>) -> Vec<Vec<(usize, &str)>> {
+    let mut paragraphs: Vec<Vec<(usize, &str)>> = vec![vec![]];
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        let line_offset = offset;
+        offset += line.len();
+        let line = line.trim_end_matches(['\n', '\r']);
+
+        if comment_char.is_some_and(|comment_char| line.starts_with(comment_char)) {
+            continue;
+        }
+        if line.trim().is_empty() {
+            if paragraphs
+                .last()
+                .is_some_and(|paragraph| !paragraph.is_empty())
+            {
+                paragraphs.push(vec![]);
+            }
+            continue;
+        }
+
+        if let Some(paragraph) = paragraphs.last_mut() {
+            paragraph.push((line_offset, line));
+        }
+    }
+
+    paragraphs.retain(|paragraph| !paragraph.is_empty());
+    paragraphs
+}
@@ -0,0 +1,117 @@
+//! Check for a diff pasted into the body
+
+use std::option::Option::None;
+
+use miette::SourceOffset;
+use mit_commit::CommitMessage;
+
+use crate::model::{Code, Problem};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "diff-pasted-in-body";
+/// Description of the problem
+pub const ERROR: &str = "Your commit message has a diff pasted into the body";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "A diff in the body of a commit is almost always pasted in by \
+                            accident, usually from the output of `git commit --verbose`. The \
+                            changes are already recorded in the commit itself, so there's no \
+                            need to repeat them in the message.\n\nYou can fix this by deleting \
+                            the diff from the body";
+
+const DIFF_LINE_PREFIXES: [&str; 5] = ["diff --git", "@@ ", "+", "-", "index "];
+const DIFF_HEADER_PREFIXES: [&str; 5] = ["diff --git", "@@ ", "index ", "+++ ", "--- "];
+
+/// Configuration for how much of a diff is needed before we complain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DiffPastedInBodyConfig {
+    /// The number of consecutive diff-like lines needed to count as a diff
+    pub threshold: usize,
+}
+
+impl Default for DiffPastedInBodyConfig {
+    fn default() -> Self {
+        Self { threshold: 3 }
+    }
+}
+
+fn find_diff_start(commit: &CommitMessage<'_>, config: &DiffPastedInBodyConfig) -> Option<usize> {
+    let commit_text: String = commit.clone().into();
+    let scissors_start_line = commit_text.lines().count()
+        - commit
+            .get_scissors()
+            .map(|s| String::from(s).lines().count())
+            .unwrap_or_default();
+    let comment_char = commit.get_comment_char();
+
+    let mut run_start = None;
+    let mut run_length = 0;
+    let mut run_has_header = false;
+    for (line_index, line) in commit_text
+        .lines()
+        .enumerate()
+        .take(scissors_start_line)
+        .skip(1)
+    {
+        let is_comment = comment_char.is_some_and(|comment_char| {
+            line == comment_char.to_string() || line.starts_with(&format!("{comment_char} "))
+        });
+        if is_comment
+            || !DIFF_LINE_PREFIXES
+                .iter()
+                .any(|prefix| line.starts_with(prefix))
+        {
+            run_start = None;
+            run_length = 0;
+            run_has_header = false;
+            continue;
+        }
+
+        run_start = run_start.or(Some(line_index));
+        run_length += 1;
+        run_has_header = run_has_header
+            || DIFF_HEADER_PREFIXES
+                .iter()
+                .any(|prefix| line.starts_with(prefix));
+
+        if run_has_header && run_length >= config.threshold {
+            return run_start;
+        }
+    }
+
+    None
+}
+
+/// Lint the commit message, returning a [`Problem`] if there is a diff pasted
+/// into the body
+pub fn lint(commit: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit, &DiffPastedInBodyConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if there is a diff pasted into the body
+///
+/// A diff is a run of at least `threshold` lines starting with `diff --git`,
+/// `@@ `, `+`, `-` or `index `, where at least one of them is a diff header
+/// rather than an added or removed line. This stops a bulleted list from
+/// looking like a diff.
+pub fn lint_with_config(
+    commit: &CommitMessage<'_>,
+    config: &DiffPastedInBodyConfig,
+) -> Option<Problem> {
+    let diff_start = find_diff_start(commit, config)?;
+    let commit_text: String = commit.clone().into();
+    let first_line = commit_text.lines().nth(diff_start).unwrap_or_default();
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::DiffPastedInBody,
+        commit,
+        Some(vec![(
+            "Pasted diff".to_string(),
+            SourceOffset::from_location(&commit_text, diff_start + 1, 1).offset(),
+            first_line.len(),
+        )]),
+        Some("https://git-scm.com/docs/git-commit".to_string()),
+    ))
+}
@@ -0,0 +1,221 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::diff_pasted_in_body::{
+    lint,
+    lint_with_config,
+    DiffPastedInBodyConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+#[test]
+fn no_diff() {
+    run_test(
+        "Subject Line
+
+Some body content
+",
+        None,
+    );
+}
+
+#[test]
+fn bulleted_body_passes() {
+    run_test(
+        "Subject Line
+
+- Remove the unused function
+- Rename the module
+- Update the changelog
++ And a plus for good measure
+",
+        None,
+    );
+}
+
+#[test]
+fn single_bullet_passes() {
+    run_test(
+        "Subject Line
+
+- Remove the unused function
+",
+        None,
+    );
+}
+
+#[test]
+fn diff_in_body_fails() {
+    let message = "Remove duplicated function
+
+The function got skipped in the previous round of refactoring
+
+diff --git a/src/lints/missing_pivotal_tracker_id.rs b/src/lints/missing_pivotal_tracker_id.rs
+index 5a83784..ebaee48 100644
+--- a/src/lints/missing_pivotal_tracker_id.rs
++++ b/src/lints/missing_pivotal_tracker_id.rs
+@@ -1,6 +1,2 @@
+-fn has_missing_pivotal_tracker_id(commit_message: &CommitMessage) -> bool {
+-    has_no_pivotal_tracker_id(commit_message)
+-}
+-
+ fn has_no_pivotal_tracker_id(text: &CommitMessage) -> bool {
+";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::DiffPastedInBody,
+            &message.into(),
+            Some(vec![("Pasted diff".to_string(), 91, 94)]),
+            Some("https://git-scm.com/docs/git-commit".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn diff_below_the_scissors_passes() {
+    run_test(
+        "Subject Line
+
+Some body content
+# ------------------------ >8 ------------------------
+# Do not modify or remove the line above.
+# Everything below it will be ignored.
+diff --git a/file.rs b/file.rs
+index 5a83784..ebaee48 100644
+--- a/file.rs
++++ b/file.rs
+",
+        None,
+    );
+}
+
+#[test]
+fn comments_break_up_a_diff() {
+    run_test(
+        "Subject Line
+
+--- a/file.rs
+# A comment
++++ b/file.rs
+# Another comment
+@@ -1 +1 @@
+",
+        None,
+    );
+}
+
+#[test]
+fn threshold_can_be_configured() {
+    let message = CommitMessage::from(
+        "Subject Line
+
+@@ -1 +1 @@
+-old
++new
+",
+    );
+
+    assert!(lint_with_config(&message, &DiffPastedInBodyConfig { threshold: 3 }).is_some());
+    assert!(lint_with_config(&message, &DiffPastedInBodyConfig { threshold: 4 }).is_none());
+}
+
+#[test]
+fn formatting() {
+    let message = "An example commit
+
+@@ -1 +1 @@
+-old
++new
+";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "DiffPastedInBody (https://git-scm.com/docs/git-commit)
+
+  x Your commit message has a diff pasted into the body
+   ,-[3:1]
+ 2 | 
+ 3 | @@ -1 +1 @@
+   : ^^^^^|^^^^^
+   :      `-- Pasted diff
+ 4 | -old
+   `----
+  help: A diff in the body of a commit is almost always pasted in by accident,
+        usually from the output of `git commit --verbose`. The changes are
+        already recorded in the commit itself, so there's no need to repeat
+        them in the message.
+        
+        You can fix this by deleting the diff from the body
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(subject: String, body: Vec<String>) -> TestResult {
+    if subject.contains('\n') || body.iter().any(|line| line.contains('\n')) {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "{}\n\n{}",
+        subject,
+        body.iter()
+            .map(|line| format!("- {line}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(subject: String, removed: Vec<String>) -> TestResult {
+    if subject.contains('\n') || subject.starts_with('#') {
+        return TestResult::discard();
+    }
+    if removed.len() < 2 || removed.iter().any(|line| line.contains('\n')) {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "{}\n\n@@ -1 +1 @@\n{}",
+        subject,
+        removed
+            .iter()
+            .map(|line| format!("-{line}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
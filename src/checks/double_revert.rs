@@ -0,0 +1,44 @@
+//! Check for a revert of a revert
+
+use mit_commit::CommitMessage;
+
+use crate::model::{Code, Problem};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "double-revert";
+/// Description of the problem
+pub const ERROR: &str = "Your commit reverts a revert";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "A subject like `Revert \"Revert \"Add login\"\"` is hard to read, \
+                            and going back and forth on a change makes the history harder to \
+                            follow.\n\nYou can fix this by recommitting the original change with \
+                            its own subject, or using `Reapply \"Add login\"` like newer versions \
+                            of git do";
+
+const REVERT_PREFIX: &str = "Revert \"";
+
+/// Lint the commit message, returning a [`Problem`] labelling the inner
+/// `Revert` when the subject is a revert of a revert
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    let commit_text = String::from(commit_message.clone());
+    let subject = commit_text.lines().next().unwrap_or_default();
+    if !subject
+        .strip_prefix(REVERT_PREFIX)?
+        .starts_with(REVERT_PREFIX)
+    {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::DoubleRevert,
+        commit_message,
+        Some(vec![(
+            "Reverts a revert".to_string(),
+            REVERT_PREFIX.len(),
+            "Revert".len(),
+        )]),
+        Some("https://git-scm.com/docs/git-revert".to_string()),
+    ))
+}
@@ -0,0 +1,112 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::double_revert::{lint, ERROR, HELP_MESSAGE};
+use crate::{
+    checks::malformed_revert,
+    model::{Code, Problem},
+};
+
+#[test]
+fn single_reverts_pass() {
+    let message = "Revert \"Add login\"\n\nThis reverts commit abc123.\n";
+
+    run_test(message, None);
+    assert_eq!(malformed_revert::lint(&CommitMessage::from(message)), None);
+}
+
+#[test]
+fn reverts_of_reverts_fail() {
+    let message = "Revert \"Revert \"Add login\"\"\n\nThis reverts commit abc123.\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::DoubleRevert,
+            &message.into(),
+            Some(vec![("Reverts a revert".to_string(), 8, 6)]),
+            Some("https://git-scm.com/docs/git-revert".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn reapplies_pass() {
+    run_test("Reapply \"Add login\"\n", None);
+}
+
+#[test]
+fn revert_later_in_the_subject_passes() {
+    run_test("Revert \"Stop using Revert \"helpers\"\"\n", None);
+}
+
+#[test]
+fn formatting() {
+    let message = "Revert \"Revert \"Add login\"\"\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "DoubleRevert (https://git-scm.com/docs/git-revert)
+
+  x Your commit reverts a revert
+   ,----
+ 1 | Revert \"Revert \"Add login\"\"
+   :         ^^^|^^
+   :            `-- Reverts a revert
+   `----
+  help: A subject like `Revert \"Revert \"Add login\"\"` is hard to read, and
+        going back and forth on a change makes the history harder to follow.
+        
+        You can fix this by recommitting the original change with its own
+        subject, or using `Reapply \"Add login\"` like newer versions of git do
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(subject: String) -> TestResult {
+    if subject.contains('\n') || subject.starts_with("Revert \"") {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Revert \"{subject}\"\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(subject: String) -> TestResult {
+    if subject.contains('\n') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Revert \"Revert \"{subject}\"\"\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
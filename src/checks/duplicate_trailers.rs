@@ -1,3 +1,5 @@
+//! Check for duplicated trailers
+
 use std::{collections::BTreeMap, ops::Add, option::Option::None};
 
 use mit_commit::{CommitMessage, Trailer};
@@ -15,7 +17,17 @@ pub const ERROR: &str = "Your commit message has duplicated trailers";
 
 const FIELD_PLURAL: &str = "fields";
 
-fn get_duplicated_trailers(commit_message: &CommitMessage<'_>) -> Vec<String> {
+/// Configuration for which trailers may be duplicated
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct DuplicatedTrailersConfig {
+    /// Trailer keys that may appear more than once, like `Co-authored-by`
+    pub ignore: Vec<String>,
+}
+
+fn get_duplicated_trailers(
+    commit_message: &CommitMessage<'_>,
+    config: &DuplicatedTrailersConfig,
+) -> Vec<String> {
     commit_message
         .get_trailers()
         .iter()
@@ -35,7 +47,10 @@ fn get_duplicated_trailers(commit_message: &CommitMessage<'_>) -> Vec<String> {
         .filter_map(|(trailer, usize)| {
             let key: &str = &trailer.get_key();
 
-            if usize > 1 && TRAILERS_TO_CHECK_FOR_DUPLICATES.contains(&key) {
+            if usize > 1
+                && TRAILERS_TO_CHECK_FOR_DUPLICATES.contains(&key)
+                && !config.ignore.iter().any(|ignored| ignored == key)
+            {
                 Some(trailer.get_key())
             } else {
                 None
@@ -44,8 +59,19 @@ fn get_duplicated_trailers(commit_message: &CommitMessage<'_>) -> Vec<String> {
         .collect::<Vec<_>>()
 }
 
+/// Lint the commit message, returning a [`Problem`] if a trailer has been
+/// duplicated
 pub fn lint(commit: &CommitMessage<'_>) -> Option<Problem> {
-    let duplicated_trailers = get_duplicated_trailers(commit);
+    lint_with_config(commit, &DuplicatedTrailersConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if a trailer that isn't ignored has been duplicated
+pub fn lint_with_config(
+    commit: &CommitMessage<'_>,
+    config: &DuplicatedTrailersConfig,
+) -> Option<Problem> {
+    let duplicated_trailers = get_duplicated_trailers(commit, config);
     if duplicated_trailers.is_empty() {
         None
     } else {
@@ -4,7 +4,7 @@ use miette::{GraphicalReportHandler, GraphicalTheme, Report};
 use mit_commit::CommitMessage;
 use quickcheck::TestResult;
 
-use super::duplicate_trailers::{lint, ERROR};
+use super::duplicate_trailers::{lint, lint_with_config, DuplicatedTrailersConfig, ERROR};
 use crate::{model::Code, Problem};
 
 #[test]
@@ -197,6 +197,35 @@ Anything: Billie Thompson <email@example.com>
     );
 }
 
+#[test]
+fn ignored_trailers_may_be_duplicated() {
+    let config = DuplicatedTrailersConfig {
+        ignore: vec!["Co-authored-by".into()],
+    };
+    let co_authored = CommitMessage::from(
+        "An example commit
+
+This is an example commit
+
+Co-authored-by: Billie Thompson <email@example.com>
+Co-authored-by: Billie Thompson <email@example.com>
+",
+    );
+    let signed_off = CommitMessage::from(
+        "An example commit
+
+This is an example commit
+
+Signed-off-by: Billie Thompson <email@example.com>
+Signed-off-by: Billie Thompson <email@example.com>
+",
+    );
+
+    assert_eq!(lint_with_config(&co_authored, &config), None);
+    assert!(lint(&co_authored).is_some());
+    assert!(lint_with_config(&signed_off, &config).is_some());
+}
+
 fn test_lint_duplicated_trailers(message: String, expected: Option<&Problem>) {
     let actual = lint(&CommitMessage::from(message));
     assert_eq!(
@@ -0,0 +1,115 @@
+//! Check for email addresses in the body
+
+use std::collections::HashSet;
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{paragraphs, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "email-in-body";
+/// Description of the problem
+pub const ERROR: &str = "Your commit has an email address in the body";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Commit messages are copied to every clone of the repository and \
+                            are very hard to remove later, so an email address in the body is \
+                            shared with everyone who can see the history.\n\nYou can fix this by \
+                            removing the address, or crediting people with a trailer like \
+                            `Co-authored-by` instead";
+
+const SEPARATORS: [char; 9] = ['<', '>', '(', ')', '[', ']', ',', ';', '"'];
+
+fn is_local_part_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || "._%+-".contains(character)
+}
+
+fn is_domain(domain: &str) -> bool {
+    let labels: Vec<&str> = domain.split('.').collect();
+
+    labels.len() > 1
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label
+                    .chars()
+                    .all(|character| character.is_ascii_alphanumeric() || character == '-')
+        })
+        && labels
+            .last()
+            .is_some_and(|tld| tld.len() > 1 && tld.chars().all(|c| c.is_ascii_alphabetic()))
+}
+
+fn is_email(token: &str) -> bool {
+    token.split_once('@').is_some_and(|(local, domain)| {
+        !local.is_empty() && local.chars().all(is_local_part_character) && is_domain(domain)
+    })
+}
+
+/// Find the offset and length of the first email address in a line
+fn find_email(line: &str) -> Option<(usize, usize)> {
+    line.split_inclusive(|c: char| c.is_whitespace() || SEPARATORS.contains(&c))
+        .scan(0, |offset, token| {
+            let start = *offset;
+            *offset += token.len();
+            Some((start, token))
+        })
+        .map(|(start, token)| {
+            (
+                start,
+                token
+                    .trim_end_matches(|c: char| c.is_whitespace() || SEPARATORS.contains(&c))
+                    .trim_end_matches(|c: char| c.is_ascii_punctuation()),
+            )
+        })
+        .find(|(_, token)| is_email(token))
+        .map(|(start, token)| (start, token.len()))
+}
+
+/// Lint the commit message, returning a [`Problem`] labelling the first email
+/// address in the body
+///
+/// Addresses in trailers, like `Co-authored-by: Someone <someone@example.com>`,
+/// are allowed.
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_comment_char(commit_message, commit_message.get_comment_char())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    let trailers: HashSet<String> = commit_message
+        .get_trailers()
+        .iter()
+        .cloned()
+        .map(|trailer| String::from(trailer).trim().to_string())
+        .collect();
+    let commit_text = text_above_scissors(commit_message);
+    let (offset, length) = paragraphs(&commit_text, comment_char)
+        .into_iter()
+        .flatten()
+        .skip(1)
+        .filter(|(_, line)| !trailers.contains(line.trim()))
+        .find_map(|(offset, line)| {
+            find_email(line).map(|(start, length)| (offset + start, length))
+        })?;
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::EmailInBody,
+        commit_message,
+        Some(vec![("Email address".to_string(), offset, length)]),
+        None,
+    ))
+}
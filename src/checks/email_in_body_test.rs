@@ -0,0 +1,147 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::email_in_body::{lint, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+#[test]
+fn body_without_an_email_passes() {
+    run_test("An example commit\n\nThe work is done.\n", None);
+}
+
+#[test]
+fn co_authored_by_trailers_pass() {
+    run_test(
+        "An example commit\n\nPaired on this.\n\nCo-authored-by: X <x@e.com>\n",
+        None,
+    );
+}
+
+#[test]
+fn emails_in_comments_pass() {
+    run_test(
+        "An example commit\n\nThe work is done.\n\n# Author: Someone <someone@example.com>\n",
+        None,
+    );
+}
+
+#[test]
+fn emails_in_the_subject_pass() {
+    run_test("Update someone@example.com's address\n", None);
+}
+
+#[test]
+fn things_that_look_like_emails_pass() {
+    run_test(
+        "An example commit\n\nMention @someone, pin serde@1.0 and ask user@localhost.\n",
+        None,
+    );
+}
+
+#[test]
+fn an_inline_email_fails() {
+    let message = "An example commit\n\nAsk someone@example.com about it.\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::EmailInBody,
+            &message.into(),
+            Some(vec![("Email address".to_string(), 23, 19)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn emails_in_brackets_are_labelled_without_the_brackets() {
+    let message = "An example commit\n\nReported by Someone <some.one+git@mail.example.co.uk>.\n\nCo-authored-by: X <x@e.com>\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::EmailInBody,
+            &message.into(),
+            Some(vec![("Email address".to_string(), 40, 31)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "An example commit\n\nAsk someone@example.com about it.\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "EmailInBody
+
+  x Your commit has an email address in the body
+   ,-[3:5]
+ 2 | 
+ 3 | Ask someone@example.com about it.
+   :     ^^^^^^^^^|^^^^^^^^^
+   :              `-- Email address
+   `----
+  help: Commit messages are copied to every clone of the repository and are
+        very hard to remove later, so an email address in the body is shared
+        with everyone who can see the history.
+        
+        You can fix this by removing the address, or crediting people with a
+        trailer like `Co-authored-by` instead
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(body: String) -> TestResult {
+    if body.contains('#') || body.contains(':') {
+        return TestResult::discard();
+    }
+    let body: String = body.chars().filter(|c| *c != '@').collect();
+    let message = CommitMessage::from(format!("An example commit\n\nSome body content {body}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(before: String, local: String) -> TestResult {
+    let local: String = local.chars().filter(char::is_ascii_alphanumeric).collect();
+    if before.contains('\n') || before.contains('#') || before.contains(':') || local.is_empty() {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body content {before} {local}@example.com\n"
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
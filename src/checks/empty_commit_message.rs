@@ -0,0 +1,44 @@
+//! Check for an empty commit message
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::is_empty_message,
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "empty-commit-message";
+/// Description of the problem
+pub const ERROR: &str = "Your commit message is empty";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "A commit message with nothing but whitespace and comments \
+                            doesn't tell anyone why the change was made, and git will refuse to \
+                            use it.\n\nYou can fix this by writing a subject describing your \
+                            change";
+
+/// Lint the commit message, returning a [`Problem`] if it is empty once
+/// comments are removed
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_comment_char(commit_message, commit_message.get_comment_char())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    if !is_empty_message(commit_message, comment_char) {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::EmptyCommitMessage,
+        commit_message,
+        None,
+        Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+    ))
+}
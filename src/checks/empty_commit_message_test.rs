@@ -0,0 +1,149 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::empty_commit_message::{lint, lint_with_comment_char, ERROR, HELP_MESSAGE};
+use crate::{
+    cmd::lint as lint_all,
+    model::{Code, Lints, Problem},
+};
+
+#[test]
+fn message_with_content_passes() {
+    run_test("An example commit\n\nSome body content\n", None);
+}
+
+#[test]
+fn empty_message_fails() {
+    run_test(
+        "",
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::EmptyCommitMessage,
+            &"".into(),
+            None,
+            Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn whitespace_only_message_fails() {
+    let message = "   \n\n\t\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::EmptyCommitMessage,
+            &message.into(),
+            None,
+            Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn comments_only_message_fails() {
+    let message = "
+# Please enter the commit message for your changes. Lines starting
+# with '#' will be ignored, and an empty message aborts the commit.
+# ------------------------ >8 ------------------------
+# Do not modify or remove the line above.
+# Everything below it will be ignored.
+diff --git a/file.rs b/file.rs
+";
+    assert!(lint(&CommitMessage::from(message)).is_some());
+}
+
+#[test]
+fn comment_char_can_be_given() {
+    let message = CommitMessage::from("#642\n");
+
+    assert!(lint_with_comment_char(&message, Some('#')).is_some());
+    assert!(lint_with_comment_char(&message, Some(';')).is_none());
+}
+
+#[test]
+fn empty_messages_only_trigger_this_lint() {
+    for message in ["", "  \n\n \n", "\n# A comment\n"] {
+        let actual: Vec<Code> = lint_all(&CommitMessage::from(message), Lints::available().clone())
+            .iter()
+            .map(|problem| *problem.code())
+            .collect();
+
+        assert_eq!(
+            actual,
+            vec![Code::EmptyCommitMessage],
+            "Expected {:?} to only be empty, found {:?}",
+            message,
+            actual
+        );
+    }
+}
+
+#[test]
+fn formatting() {
+    let message = "\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "EmptyCommitMessage (https://git-scm.com/docs/git-commit#_discussion)
+
+  x Your commit message is empty
+  help: A commit message with nothing but whitespace and comments doesn't tell
+        anyone why the change was made, and git will refuse to use it.
+        
+        You can fix this by writing a subject describing your change
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(subject: String) -> TestResult {
+    if subject.trim().is_empty() || subject.contains('\n') || subject.starts_with('#') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("{subject}\n\n# A comment\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(whitespace: Vec<bool>) -> TestResult {
+    let message = CommitMessage::from(
+        whitespace
+            .iter()
+            .map(|newline| if *newline { "\n" } else { " " })
+            .collect::<String>(),
+    );
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
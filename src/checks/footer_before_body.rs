@@ -0,0 +1,65 @@
+//! Check for footers placed before the body
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::{
+        comments::{paragraphs, text_above_scissors},
+        malformed_trailer::is_well_formed,
+    },
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "footer-before-body";
+/// Description of the problem
+pub const ERROR: &str = "Your commit message has a footer before the body";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Git only reads trailers from the last paragraph of the commit \
+                            message, so footers placed before the body are silently treated as \
+                            part of it, and tools reading the trailers won't see them.\n\nYou can \
+                            fix this by moving the footers to the end of the commit message";
+
+/// Lint the commit message, returning a [`Problem`] labelling every footer
+/// that has body text after it
+///
+/// A paragraph is only treated as footers when every line in it looks like
+/// a trailer, so prose with a colon in it isn't flagged.
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    let commit_text = text_above_scissors(commit_message);
+    let paragraphs = paragraphs(&commit_text, commit_message.get_comment_char());
+    let body: Vec<(bool, &Vec<(usize, &str)>)> = paragraphs
+        .iter()
+        .skip(1)
+        .map(|paragraph| {
+            let is_footer = paragraph.iter().all(|(_, line)| is_well_formed(line));
+            (is_footer, paragraph)
+        })
+        .collect();
+    let last_prose = body.iter().rposition(|(is_footer, _)| !is_footer)?;
+
+    let labels: Vec<(String, usize, usize)> = body[..last_prose]
+        .iter()
+        .filter(|(is_footer, _)| *is_footer)
+        .flat_map(|(_, paragraph)| paragraph.iter())
+        .map(|(offset, line)| {
+            (
+                "Footer before the body".to_string(),
+                *offset,
+                line.trim_end().len(),
+            )
+        })
+        .collect();
+    if labels.is_empty() {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::FooterBeforeBody,
+        commit_message,
+        Some(labels),
+        Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+    ))
+}
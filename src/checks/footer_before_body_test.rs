@@ -0,0 +1,175 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::footer_before_body::{lint, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+const URL: &str = "https://git-scm.com/docs/git-interpret-trailers";
+
+#[test]
+fn subject_only_passes() {
+    run_test("Add login\n", None);
+}
+
+#[test]
+fn body_then_footers_passes() {
+    run_test(
+        "Add login
+
+Add a login form to the home page.
+
+Refs: #1
+Signed-off-by: Billie Thompson <billie@example.com>
+",
+        None,
+    );
+}
+
+#[test]
+fn prose_with_a_colon_passes() {
+    run_test(
+        "Add login
+
+Note: this only adds the form,
+the route comes later.
+
+Refs: #1
+",
+        None,
+    );
+}
+
+#[test]
+fn footer_then_prose_fails() {
+    let message = "Add login
+
+Refs: #1
+Signed-off-by: Billie Thompson <billie@example.com>
+
+Add a login form to the home page.
+";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::FooterBeforeBody,
+            &message.into(),
+            Some(vec![
+                ("Footer before the body".to_string(), 11, 8),
+                ("Footer before the body".to_string(), 20, 51),
+            ]),
+            Some(URL.to_string()),
+        )),
+    );
+}
+
+#[test]
+fn only_footers_before_the_last_prose_are_labelled() {
+    let message = "Add login
+
+Refs: #1
+
+Add a login form to the home page.
+
+Refs: #2
+";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::FooterBeforeBody,
+            &message.into(),
+            Some(vec![("Footer before the body".to_string(), 11, 8)]),
+            Some(URL.to_string()),
+        )),
+    );
+}
+
+#[test]
+fn comments_are_not_body_text() {
+    run_test(
+        "Add login
+
+Refs: #1
+
+# Please enter the commit message for your changes.
+",
+        None,
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "Add login
+
+Refs: #1
+
+Add a login form to the home page.
+";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "FooterBeforeBody (https://git-scm.com/docs/git-interpret-trailers)
+
+  x Your commit message has a footer before the body
+   ,-[3:1]
+ 2 | 
+ 3 | Refs: #1
+   : ^^^^|^^^
+   :     `-- Footer before the body
+ 4 | 
+   `----
+  help: Git only reads trailers from the last paragraph of the commit message,
+        so footers placed before the body are silently treated as part of it,
+        and tools reading the trailers won't see them.
+        
+        You can fix this by moving the footers to the end of the commit
+        message
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(id: u16, body: String) -> TestResult {
+    let body: String = body.chars().filter(|c| c.is_alphanumeric()).collect();
+    let message = CommitMessage::from(format!("Add login\n\nSome body {body}\n\nRefs: #{id}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(id: u16, body: String) -> TestResult {
+    let body: String = body.chars().filter(|c| c.is_alphanumeric()).collect();
+    let message = CommitMessage::from(format!("Add login\n\nRefs: #{id}\n\nSome body {body}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
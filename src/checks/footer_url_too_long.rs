@@ -0,0 +1,102 @@
+//! Check for URLs in the trailers that are too long
+
+use mit_commit::CommitMessage;
+
+use crate::model::{Code, Problem};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "footer-url-too-long";
+/// Description of the problem
+pub const ERROR: &str = "Your commit message has a URL in the trailers that's too long";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Very long URLs, like ones full of tracking parameters, are hard \
+                            to read in the footer, and some tools that read trailers cut them \
+                            off.\n\nYou can fix this by removing the parts of the URL that aren't \
+                            needed, or using a shorter link to the same page";
+
+const URL_SCHEMES: [&str; 2] = ["http://", "https://"];
+
+/// Configuration for how long a URL in the trailers may be
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FooterUrlTooLongConfig {
+    /// The most characters a URL may have
+    pub max_chars: usize,
+}
+
+impl Default for FooterUrlTooLongConfig {
+    fn default() -> Self {
+        Self { max_chars: 100 }
+    }
+}
+
+/// Find the URLs in a line, as byte offsets and lengths
+fn urls(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_inclusive(char::is_whitespace)
+        .scan(0, |offset, word| {
+            let start = *offset;
+            *offset += word.len();
+            Some((start, word.trim_end()))
+        })
+        .filter(|(_, word)| URL_SCHEMES.iter().any(|scheme| word.starts_with(scheme)))
+}
+
+/// Lint the commit message, returning a [`Problem`] if a trailer has a URL
+/// longer than 100 characters
+pub fn lint(commit: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit, &FooterUrlTooLongConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] labelling every URL in the trailers that's longer than the
+/// configured maximum
+pub fn lint_with_config(
+    commit: &CommitMessage<'_>,
+    config: &FooterUrlTooLongConfig,
+) -> Option<Problem> {
+    let trailers: Vec<String> = commit
+        .get_trailers()
+        .iter()
+        .cloned()
+        .map(|trailer| String::from(trailer).trim_end().to_string())
+        .collect();
+    if trailers.is_empty() {
+        return None;
+    }
+
+    let commit_text = String::from(commit.clone());
+    let mut matched = 0;
+    let mut offset = 0;
+    let mut labels = vec![];
+    for line in commit_text.split_inclusive('\n') {
+        let line_offset = offset;
+        offset += line.len();
+        let line = line.trim_end();
+
+        if trailers.get(matched).is_some_and(|trailer| trailer == line) {
+            matched += 1;
+            labels.extend(
+                urls(line)
+                    .filter(|(_, url)| url.chars().count() > config.max_chars)
+                    .map(|(url_offset, url)| {
+                        (
+                            format!("Longer than {} characters", config.max_chars),
+                            line_offset + url_offset,
+                            url.len(),
+                        )
+                    }),
+            );
+        }
+    }
+    if labels.is_empty() {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::FooterUrlTooLong,
+        commit,
+        Some(labels),
+        Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+    ))
+}
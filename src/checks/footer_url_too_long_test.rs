@@ -0,0 +1,148 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::footer_url_too_long::{
+    lint,
+    lint_with_config,
+    FooterUrlTooLongConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+fn long_url() -> String {
+    format!("https://example.com/issues/1?utm_source={}", "a".repeat(80))
+}
+
+#[test]
+fn commit_without_trailers() {
+    run_test("An example commit\n\nSome body content\n", None);
+}
+
+#[test]
+fn a_short_footer_url_passes() {
+    run_test(
+        "An example commit\n\nSome body content\n\nRefs: https://example.com/issues/1\n",
+        None,
+    );
+}
+
+#[test]
+fn a_long_url_in_the_body_passes() {
+    run_test(&format!("An example commit\n\n{}\n", long_url()), None);
+}
+
+#[test]
+fn a_long_footer_url_fails() {
+    let message = format!(
+        "An example commit\n\nSome body content\n\nRefs: #1\nSee-also: {}\n",
+        long_url()
+    );
+    run_test(
+        &message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::FooterUrlTooLong,
+            &message.clone().into(),
+            Some(vec![("Longer than 100 characters".to_string(), 57, 120)]),
+            Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn max_chars_can_be_configured() {
+    let message = "An example commit\n\nRefs: https://example.com/issues/1\n";
+    let config = FooterUrlTooLongConfig { max_chars: 20 };
+
+    assert_eq!(
+        lint_with_config(&CommitMessage::from(message), &config),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::FooterUrlTooLong,
+            &message.into(),
+            Some(vec![("Longer than 20 characters".to_string(), 25, 28)]),
+            Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+        ))
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "An example commit\n\nRefs: https://example.com/issues/1\n";
+    let problem = lint_with_config(
+        &CommitMessage::from(message),
+        &FooterUrlTooLongConfig { max_chars: 20 },
+    );
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "FooterUrlTooLong (https://git-scm.com/docs/git-interpret-trailers)
+
+  x Your commit message has a URL in the trailers that's too long
+   ,-[3:7]
+ 2 | 
+ 3 | Refs: https://example.com/issues/1
+   :       ^^^^^^^^^^^^^^|^^^^^^^^^^^^^
+   :                     `-- Longer than 20 characters
+   `----
+  help: Very long URLs, like ones full of tracking parameters, are hard to
+        read in the footer, and some tools that read trailers cut them off.
+        
+        You can fix this by removing the parts of the URL that aren't needed,
+        or using a shorter link to the same page
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(path: String) -> TestResult {
+    let path: String = path.chars().filter(char::is_ascii_alphanumeric).collect();
+    if path.len() > 80 {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body content\n\nRefs: https://example.com/{path}\n"
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(path: String) -> TestResult {
+    let path: String = path.chars().filter(char::is_ascii_alphanumeric).collect();
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body content\n\nRefs: https://example.com/{path}{}\n",
+        "a".repeat(100)
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
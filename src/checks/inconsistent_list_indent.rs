@@ -0,0 +1,84 @@
+//! Check for bullet lists in the body that aren't indented consistently
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{paragraphs, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "inconsistent-list-indent";
+/// Description of the problem
+pub const ERROR: &str = "Your commit has a list that isn't indented consistently";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Lists where the nested items are indented by different amounts \
+                            are hard to follow in the log, and render poorly when the message is \
+                            shown as markdown.\n\nYou can fix this by indenting each level of the \
+                            list by the same number of spaces";
+
+const LIST_MARKERS: [char; 3] = ['-', '*', '+'];
+
+/// Get the width of the indent before a list marker, if the line is a list
+/// item
+pub(crate) fn list_indent(line: &str) -> Option<usize> {
+    let content = line.trim_start();
+    let mut characters = content.chars();
+    let is_list_item = characters
+        .next()
+        .is_some_and(|marker| LIST_MARKERS.contains(&marker))
+        && characters.next().is_some_and(char::is_whitespace);
+
+    is_list_item.then(|| line.len() - content.len())
+}
+
+/// Lint the commit message, returning a [`Problem`] labelling the indent of
+/// the first list item that's out of step with the rest
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_comment_char(commit_message, commit_message.get_comment_char())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+///
+/// The first indented list item sets the step. Every item must then be
+/// indented by a multiple of the step, and no more than one step deeper than
+/// the item before it.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    let commit_text = text_above_scissors(commit_message);
+    let mut step = None;
+
+    let (offset, indent) = paragraphs(&commit_text, comment_char)
+        .into_iter()
+        .skip(1)
+        .find_map(|paragraph| {
+            let mut previous = 0;
+            paragraph.into_iter().find_map(|(offset, line)| {
+                let indent = list_indent(line)?;
+                if indent > 0 && step.is_none() {
+                    step = Some(indent);
+                }
+                let is_consistent =
+                    step.is_none_or(|step| indent % step == 0 && indent <= previous + step);
+                previous = indent;
+
+                (!is_consistent).then_some((offset, indent))
+            })
+        })?;
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::InconsistentListIndent,
+        commit_message,
+        Some(vec![("Inconsistent indent".to_string(), offset, indent)]),
+        None,
+    ))
+}
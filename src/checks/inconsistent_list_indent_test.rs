@@ -0,0 +1,163 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::inconsistent_list_indent::{lint, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+#[test]
+fn consistent_lists_pass() {
+    run_test(
+        "Add login\n\n- Add a form\n  - With a password field\n    * Hidden as it's typed\n  - With a username field\n- Add a route\n",
+        None,
+    );
+}
+
+#[test]
+fn flat_lists_pass() {
+    run_test(
+        "Add login\n\n- Add a form\n* Add a route\n+ Add a test\n",
+        None,
+    );
+}
+
+#[test]
+fn a_step_of_four_passes() {
+    run_test(
+        "Add login\n\n- Add a form\n    - With a password field\n",
+        None,
+    );
+}
+
+#[test]
+fn indented_text_that_is_not_a_list_passes() {
+    run_test(
+        "Add login\n\n- Add a form\n   that has a password field\n",
+        None,
+    );
+}
+
+#[test]
+fn the_subject_is_not_checked() {
+    run_test("- Add login\n\nSome body content\n", None);
+}
+
+#[test]
+fn mixed_indentation_fails() {
+    let message = "Add login\n\n- Add a form\n  - With a password field\n   - Add a route\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::InconsistentListIndent,
+            &message.into(),
+            Some(vec![("Inconsistent indent".to_string(), 50, 3)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn skipping_a_level_fails() {
+    let message = "Add login\n\n- Add a form\n  - With a password field\n\n- Add a route\n    - With a test\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::InconsistentListIndent,
+            &message.into(),
+            Some(vec![("Inconsistent indent".to_string(), 65, 4)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn comments_are_ignored() {
+    run_test(
+        "Add login\n\n- Add a form\n  - With a password field\n# - Add a route\n",
+        None,
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "Add login\n\n- Add a form\n  - With a password field\n   - Add a route\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "InconsistentListIndent
+
+  x Your commit has a list that isn't indented consistently
+   ,-[5:1]
+ 4 |   - With a password field
+ 5 |    - Add a route
+   : ^|^
+   :  `-- Inconsistent indent
+   `----
+  help: Lists where the nested items are indented by different amounts are
+        hard to follow in the log, and render poorly when the message is shown
+        as markdown.
+        
+        You can fix this by indenting each level of the list by the same
+        number of spaces
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(levels: Vec<u8>, step: u8) -> TestResult {
+    let step = usize::from(step % 4) + 1;
+    let mut level = 0;
+    let items: Vec<String> = levels
+        .iter()
+        .map(|next| {
+            level = usize::from(*next) % (level + 2);
+            format!("{}- Item", " ".repeat(level * step))
+        })
+        .collect();
+    let message = CommitMessage::from(format!("Add login\n\n{}\n", items.join("\n")));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(step: u8, offset: u8) -> TestResult {
+    let step = usize::from(step % 4) + 2;
+    let indent = step + usize::from(offset) % (step - 1) + 1;
+    let message = CommitMessage::from(format!(
+        "Add login\n\n- Item\n{}- Item\n{}- Item\n",
+        " ".repeat(step),
+        " ".repeat(indent)
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -0,0 +1,86 @@
+//! Check for bullet lists in the body where only some items end with a period
+
+use std::cmp::Ordering;
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::{
+        comments::{paragraphs, text_above_scissors},
+        inconsistent_list_indent::list_indent,
+    },
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "inconsistent-list-punctuation";
+/// Description of the problem
+pub const ERROR: &str = "Your commit has a list where only some items end with a period";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Lists where some items end with a period and others don't look \
+                            untidy, and make it unclear whether the items are sentences.\n\nYou \
+                            can fix this by ending every item with a period, or none of them";
+
+/// Lint the commit message, returning a [`Problem`] labelling the list items
+/// that don't end the way most of the others do
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_comment_char(commit_message, commit_message.get_comment_char())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+///
+/// Every list item in the body is counted together. When as many end with a
+/// period as don't, the first item decides which style is right.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    let commit_text = text_above_scissors(commit_message);
+    let items: Vec<(usize, &str, bool)> = paragraphs(&commit_text, comment_char)
+        .into_iter()
+        .skip(1)
+        .flatten()
+        .filter_map(|(offset, line)| {
+            let indent = list_indent(line)?;
+            let item = line[indent..].trim_end();
+            Some((offset + indent, item, item.ends_with('.')))
+        })
+        .collect();
+
+    let with_period = items.iter().filter(|(_, _, period)| *period).count();
+    let without_period = items.len() - with_period;
+    if with_period == 0 || without_period == 0 {
+        return None;
+    }
+
+    let expect_period = match with_period.cmp(&without_period) {
+        Ordering::Greater => true,
+        Ordering::Less => false,
+        Ordering::Equal => items.first().is_some_and(|(_, _, period)| *period),
+    };
+    let label = if expect_period {
+        "No period"
+    } else {
+        "Ends with a period"
+    };
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::InconsistentListPunctuation,
+        commit_message,
+        Some(
+            items
+                .into_iter()
+                .filter(|(_, _, period)| *period != expect_period)
+                .map(|(offset, item, _)| (label.to_string(), offset, item.len()))
+                .collect(),
+        ),
+        None,
+    ))
+}
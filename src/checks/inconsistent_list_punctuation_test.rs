@@ -0,0 +1,160 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::inconsistent_list_punctuation::{lint, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+#[test]
+fn lists_with_periods_pass() {
+    run_test(
+        "Add login\n\n- Add a form.\n- Add a route.\n  - With a redirect.\n",
+        None,
+    );
+}
+
+#[test]
+fn lists_without_periods_pass() {
+    run_test(
+        "Add login\n\n- Add a form\n* Add a route\n+ Add a test\n",
+        None,
+    );
+}
+
+#[test]
+fn prose_is_not_counted() {
+    run_test(
+        "Add login\n\nThis adds a login form.\n\n- Add a form\n- Add a route\n",
+        None,
+    );
+}
+
+#[test]
+fn the_odd_one_out_without_a_period_fails() {
+    let message = "Add login\n\n- Add a form.\n- Add a route.\n- Add a redirect\n- Add a test.\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::InconsistentListPunctuation,
+            &message.into(),
+            Some(vec![("No period".to_string(), 40, 16)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn the_odd_one_out_with_a_period_fails() {
+    let message = "Add login\n\n- Add a form\n  - Add a route.\n- Add a test\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::InconsistentListPunctuation,
+            &message.into(),
+            Some(vec![("Ends with a period".to_string(), 26, 14)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn the_first_item_breaks_a_tie() {
+    let message = "Add login\n\n- Add a form\n- Add a route.\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::InconsistentListPunctuation,
+            &message.into(),
+            Some(vec![("Ends with a period".to_string(), 24, 14)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "Add login\n\n- Add a form.\n- Add a route.\n- Add a redirect\n- Add a test.\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "InconsistentListPunctuation
+
+  x Your commit has a list where only some items end with a period
+   ,-[5:1]
+ 4 | - Add a route.
+ 5 | - Add a redirect
+   : ^^^^^^^^|^^^^^^^
+   :         `-- No period
+ 6 | - Add a test.
+   `----
+  help: Lists where some items end with a period and others don't look untidy,
+        and make it unclear whether the items are sentences.
+        
+        You can fix this by ending every item with a period, or none of them
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn list(items: &[String], period: &str) -> String {
+    items
+        .iter()
+        .map(|item| {
+            let item: String = item.chars().filter(|c| c.is_alphanumeric()).collect();
+            format!("- Item {item}{period}\n")
+        })
+        .collect()
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(items: Vec<String>, period: bool) -> TestResult {
+    let period = if period { "." } else { "" };
+    let message = CommitMessage::from(format!("Add login\n\n{}", list(&items, period)));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(with_period: Vec<String>, without_period: Vec<String>) -> TestResult {
+    if with_period.is_empty() || without_period.is_empty() {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "Add login\n\n{}{}",
+        list(&with_period, "."),
+        list(&without_period, "")
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -0,0 +1,50 @@
+//! Check for a revert subject that doesn't quote the reverted subject
+
+use mit_commit::CommitMessage;
+
+use crate::model::{Code, Problem};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "malformed-revert";
+/// Description of the problem
+pub const ERROR: &str = "Your revert subject doesn't quote the subject it reverts";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Git writes the subject of a revert as `Revert \"<original \
+                            subject>\"`, which makes it easy to find the commit being undone, \
+                            and for tools to recognise it as a revert.\n\nYou can fix this by \
+                            quoting the subject of the commit you're reverting, like `Revert \
+                            \"Add login\"`";
+
+fn is_malformed(subject: &str) -> bool {
+    let Some(rest) = subject.strip_prefix("Revert") else {
+        return false;
+    };
+    if !(rest.is_empty() || rest.starts_with(char::is_whitespace)) {
+        return false;
+    }
+
+    let quoted = rest
+        .strip_prefix(" \"")
+        .and_then(|quoted| quoted.strip_suffix('"'));
+
+    quoted.is_none_or(|original| original.trim().is_empty())
+}
+
+/// Lint the commit message, returning a [`Problem`] if the subject starts
+/// with `Revert` but isn't in the `Revert "<subject>"` form
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    let commit_text = String::from(commit_message.clone());
+    let subject = commit_text.lines().next().unwrap_or_default().trim_end();
+    if !is_malformed(subject) {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::MalformedRevert,
+        commit_message,
+        Some(vec![("Malformed revert".to_string(), 0, subject.len())]),
+        Some("https://git-scm.com/docs/git-revert".to_string()),
+    ))
+}
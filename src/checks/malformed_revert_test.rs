@@ -0,0 +1,128 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::malformed_revert::{lint, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+#[test]
+fn quoted_revert_passes() {
+    run_test(
+        "Revert \"Add login\"\n\nThis reverts commit 1234567.\n",
+        None,
+    );
+}
+
+#[test]
+fn revert_of_a_revert_passes() {
+    run_test(
+        "Revert \"Revert \"Add login\"\"\n\nThis reverts commit 1234567.\n",
+        None,
+    );
+}
+
+#[test]
+fn other_subjects_pass() {
+    run_test("Add login\n", None);
+    run_test("Reverted the login change\n", None);
+    run_test("revert: add login\n", None);
+}
+
+#[test]
+fn unquoted_revert_fails() {
+    let message = "Revert login change\n\nThis reverts commit 1234567.\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::MalformedRevert,
+            &message.into(),
+            Some(vec![("Malformed revert".to_string(), 0, 19)]),
+            Some("https://git-scm.com/docs/git-revert".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn empty_or_unclosed_quotes_fail() {
+    for message in ["Revert \"\"\n", "Revert \"Add login\n", "Revert\n"] {
+        let actual = lint(&CommitMessage::from(message));
+        assert!(actual.is_some(), "Expected {:?} to fail", message);
+    }
+}
+
+#[test]
+fn formatting() {
+    let message = "Revert login change\n\nThis reverts commit 1234567.\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "MalformedRevert (https://git-scm.com/docs/git-revert)
+
+  x Your revert subject doesn't quote the subject it reverts
+   ,-[1:1]
+ 1 | Revert login change
+   : ^^^^^^^^^|^^^^^^^^^
+   :          `-- Malformed revert
+ 2 | 
+   `----
+  help: Git writes the subject of a revert as `Revert \"<original subject>\"`,
+        which makes it easy to find the commit being undone, and for tools to
+        recognise it as a revert.
+        
+        You can fix this by quoting the subject of the commit you're
+        reverting, like `Revert \"Add login\"`
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(original: String) -> TestResult {
+    if original.contains('\n') || original.trim().is_empty() {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "Revert \"{original}\"\n\nThis reverts commit 1234567.\n"
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(original: String) -> TestResult {
+    if original.contains('\n') || original.contains('"') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "Revert {original}\n\nThis reverts commit 1234567.\n"
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
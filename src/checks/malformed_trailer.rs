@@ -0,0 +1,112 @@
+//! Check for lines in the trailer block that git won't parse as trailers
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{paragraphs, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "malformed-trailer";
+/// Description of the problem
+pub const ERROR: &str = "Your commit message has a malformed trailer";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Trailers need to be in the form `Token: value`, where the token \
+                            is made of letters, numbers and hyphens, and starts at the beginning \
+                            of the line. Anything else is silently treated as part of the body, \
+                            so tools reading the trailers won't see it.\n\nYou can fix this by \
+                            replacing any spaces in the token with hyphens, adding the missing \
+                            colon, or removing the whitespace before the token";
+
+fn is_token(token: &str) -> bool {
+    !token.is_empty()
+        && !token.starts_with('-')
+        && token
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || character == '-')
+}
+
+pub(crate) fn is_well_formed(line: &str) -> bool {
+    line.split_once(':').is_some_and(|(token, value)| {
+        is_token(token) && (value.is_empty() || value.starts_with(char::is_whitespace))
+    })
+}
+
+fn resembles_trailer(line: &str) -> bool {
+    let line = line.trim_start();
+    if is_well_formed(line) {
+        return true;
+    }
+
+    let spaced_token = line.split_once(':').is_some_and(|(token, value)| {
+        let words: Vec<&str> = token.split_whitespace().collect();
+        (1..=4).contains(&words.len())
+            && words.iter().all(|word| is_token(word))
+            && token.starts_with(|character: char| character.is_ascii_alphabetic())
+            && (value.is_empty() || value.starts_with(char::is_whitespace))
+    });
+    let missing_colon = line.split_once(' ').is_some_and(|(token, value)| {
+        is_token(token)
+            && token.contains('-')
+            && token.starts_with(|character: char| character.is_ascii_uppercase())
+            && !value.trim().is_empty()
+    });
+
+    spaced_token || missing_colon
+}
+
+fn find_malformed(commit_message: &CommitMessage<'_>) -> Vec<(usize, usize)> {
+    let commit_text = text_above_scissors(commit_message);
+    let mut paragraphs = paragraphs(&commit_text, commit_message.get_comment_char());
+    if paragraphs.len() < 2 {
+        return vec![];
+    }
+    let block = paragraphs.pop().unwrap_or_default();
+
+    let mut malformed = vec![];
+    let mut previous_is_trailer = false;
+    for (offset, line) in block {
+        let is_continuation = previous_is_trailer && line.starts_with(char::is_whitespace);
+        if is_continuation {
+            continue;
+        }
+        if !resembles_trailer(line) {
+            return vec![];
+        }
+
+        previous_is_trailer = is_well_formed(line);
+        if !previous_is_trailer {
+            malformed.push((offset, line.len()));
+        }
+    }
+
+    malformed
+}
+
+/// Lint the commit message, returning a [`Problem`] if a line in the trailer
+/// block looks like a trailer but won't be parsed as one
+///
+/// The last paragraph is only treated as a trailer block when every line in
+/// it looks like a trailer, so a paragraph of prose with a colon in it isn't
+/// flagged.
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    let malformed = find_malformed(commit_message);
+    if malformed.is_empty() {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::MalformedTrailer,
+        commit_message,
+        Some(
+            malformed
+                .into_iter()
+                .map(|(offset, length)| ("Malformed trailer".to_string(), offset, length))
+                .collect(),
+        ),
+        Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+    ))
+}
@@ -0,0 +1,199 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::malformed_trailer::{lint, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+#[test]
+fn well_formed_trailers_pass() {
+    run_test(
+        "An example commit
+
+Some body content
+
+Signed-off-by: Someone Else <someone@example.com>
+Co-authored-by: Another Person <another@example.com>
+Relates-to: #315
+",
+        None,
+    );
+}
+
+#[test]
+fn continuation_lines_pass() {
+    run_test(
+        "An example commit
+
+Some body content
+
+Signed-off-by: Someone Else <someone@example.com>
+Note: a long value
+  that carries on here
+",
+        None,
+    );
+}
+
+#[test]
+fn prose_with_a_colon_passes() {
+    run_test(
+        "An example commit
+
+Some body content
+
+See the docs for more: they explain it well, and there is quite a lot
+of detail about how it works
+",
+        None,
+    );
+}
+
+#[test]
+fn subject_only_passes() {
+    run_test("Fixes: the build\n", None);
+}
+
+#[test]
+fn space_in_the_token_fails() {
+    let message = "An example commit
+
+Some body content
+
+Signed off by: Someone Else <someone@example.com>
+";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::MalformedTrailer,
+            &message.into(),
+            Some(vec![("Malformed trailer".to_string(), 38, 49)]),
+            Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn missing_colon_and_leading_whitespace_fail() {
+    let message = "An example commit
+
+Some body content
+
+  Co-authored-by: Another Person <another@example.com>
+Signed-off-by: Someone Else <someone@example.com>
+Relates-to #315
+";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::MalformedTrailer,
+            &message.into(),
+            Some(vec![
+                ("Malformed trailer".to_string(), 38, 54),
+                ("Malformed trailer".to_string(), 143, 15),
+            ]),
+            Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn trailers_below_the_scissors_are_ignored() {
+    run_test(
+        "An example commit
+
+Some body content
+# ------------------------ >8 ------------------------
+# Do not modify or remove the line above.
+# Everything below it will be ignored.
+
+Signed off by: Someone Else <someone@example.com>
+",
+        None,
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "An example commit
+
+Some body content
+
+Signed off by: Someone Else <someone@example.com>
+";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "MalformedTrailer (https://git-scm.com/docs/git-interpret-trailers)
+
+  x Your commit message has a malformed trailer
+   ,-[5:1]
+ 4 | 
+ 5 | Signed off by: Someone Else <someone@example.com>
+   : ^^^^^^^^^^^^^^^^^^^^^^^^|^^^^^^^^^^^^^^^^^^^^^^^^
+   :                         `-- Malformed trailer
+   `----
+  help: Trailers need to be in the form `Token: value`, where the token is
+        made of letters, numbers and hyphens, and starts at the beginning of
+        the line. Anything else is silently treated as part of the body, so
+        tools reading the trailers won't see it.
+        
+        You can fix this by replacing any spaces in the token with hyphens,
+        adding the missing colon, or removing the whitespace before the token
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(value: String) -> TestResult {
+    if value.contains('\n') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body content\n\nRelates-to: {value}\n"
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(value: String) -> TestResult {
+    if value.contains('\n') || value.contains('#') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body content\n\nRelates to: {value}\n"
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
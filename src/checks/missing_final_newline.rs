@@ -0,0 +1,80 @@
+//! Check the message ends with a newline
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{is_empty_message, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "missing-final-newline";
+/// Description of the problem
+pub const ERROR: &str = "Your commit message doesn't end with a newline";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Git and most editors end every line, including the last one, \
+                            with a newline. Messages written by some tools don't, which can \
+                            join the last line onto whatever comes after it.\n\nYou can fix this \
+                            by adding a newline to the end of the message";
+
+/// Lint the commit message, returning a [`Problem`] labelling the last
+/// character when the message doesn't end with a newline
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_comment_char(commit_message, commit_message.get_comment_char())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, a message of
+/// only comment lines is linted like any other.
+///
+/// Anything below a scissors line is ignored.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    if is_empty_message(commit_message, comment_char) {
+        return None;
+    }
+
+    let commit_text = text_above_scissors(commit_message);
+    let last_character = commit_text.chars().next_back()?;
+    if last_character == '\n' {
+        return None;
+    }
+
+    let length = last_character.len_utf8();
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::MissingFinalNewline,
+        commit_message,
+        Some(vec![(
+            "No final newline".to_string(),
+            commit_text.len() - length,
+            length,
+        )]),
+        None,
+    ))
+}
+
+/// Add a newline to the end of the message, if it doesn't have one
+///
+/// Any scissors section is left as it is.
+#[must_use]
+pub fn fix(commit_message: &CommitMessage<'_>) -> CommitMessage<'static> {
+    let commit_text = String::from(commit_message.clone());
+    let above_scissors = text_above_scissors(commit_message);
+
+    if above_scissors.is_empty() || above_scissors.ends_with('\n') {
+        return CommitMessage::from(commit_text);
+    }
+
+    CommitMessage::from(format!(
+        "{}\n{}",
+        above_scissors,
+        &commit_text[above_scissors.len()..]
+    ))
+}
@@ -0,0 +1,152 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::missing_final_newline::{fix, lint, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Lint, Problem};
+
+#[test]
+fn newline_terminated_messages_pass() {
+    run_test("Add login\n\nSome body content\n", None);
+}
+
+#[test]
+fn messages_without_a_final_newline_fail() {
+    let message = "Add login\n\nSome body content";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::MissingFinalNewline,
+            &message.into(),
+            Some(vec![("No final newline".to_string(), 27, 1)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn the_last_character_is_labelled_whole() {
+    let message = "Add login 🎉";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::MissingFinalNewline,
+            &message.into(),
+            Some(vec![("No final newline".to_string(), 10, 4)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn the_scissors_section_is_ignored() {
+    run_test(
+        "Add login\n# ------------------------ >8 ------------------------\ndiff --git a/file b/file",
+        None,
+    );
+}
+
+#[test]
+fn empty_messages_pass() {
+    run_test("", None);
+    run_test("# Only a comment", None);
+}
+
+#[test]
+fn fix_adds_a_newline() {
+    let message = CommitMessage::from("Add login\n\nSome body content");
+
+    assert_eq!(
+        String::from(fix(&message)),
+        "Add login\n\nSome body content\n"
+    );
+}
+
+#[test]
+fn fix_is_idempotent() {
+    let message = CommitMessage::from("Add login");
+    let fixed = fix(&message);
+
+    assert_eq!(fix(&fixed), fixed);
+    assert_eq!(lint(&fixed), None);
+}
+
+#[test]
+fn fix_is_available_from_the_lint() {
+    let message = CommitMessage::from("Add login");
+
+    assert_eq!(
+        Lint::MissingFinalNewline.fix(&message),
+        Some(CommitMessage::from("Add login\n"))
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "Add login";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "MissingFinalNewline
+
+  x Your commit message doesn't end with a newline
+   ,----
+ 1 | Add login
+   :         |
+   :         `-- No final newline
+   `----
+  help: Git and most editors end every line, including the last one, with a
+        newline. Messages written by some tools don't, which can join the last
+        line onto whatever comes after it.
+        
+        You can fix this by adding a newline to the end of the message
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(subject: String) -> TestResult {
+    let message = CommitMessage::from(format!("{subject}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(subject: String) -> TestResult {
+    if subject.ends_with('\n') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Add login\n\nSome body content {subject}"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -1,8 +1,13 @@
+//! Check for a missing GitHub ID
+
 use std::{ops::Add, option::Option::None};
 
 use mit_commit::CommitMessage;
 
-use crate::model::{Code, Problem};
+use crate::{
+    checks::comments::{is_empty_message, text_above_scissors, uncommented_text},
+    model::{Code, Problem},
+};
 
 /// Canonical lint ID
 pub const CONFIG: &str = "github-id-missing";
@@ -22,17 +27,71 @@ Be careful just putting '#642' on a line by itself, as '#' is the default commen
 /// Description of the problem
 pub const ERROR: &str = "Your commit message is missing a GitHub ID";
 
+/// The pattern used to detect a GitHub ID
+pub const PATTERN: &str = r"(?m)(^| )([a-zA-Z0-9_-]{3,39}/[a-zA-Z0-9-]+#|GH-|#)[0-9]+( |$)";
+
+/// The pattern used to detect a full GitHub issue or pull request URL
+pub const URL_PATTERN: &str =
+    r"(^|\s)https?://github\.com/[a-zA-Z0-9_.-]+/[a-zA-Z0-9_.-]+/(issues|pull)/[0-9]+(\s|[/#?]|$)";
+
 lazy_static! {
-    static ref RE: regex::Regex =
-        regex::Regex::new(r"(?m)(^| )([a-zA-Z0-9_-]{3,39}/[a-zA-Z0-9-]+#|GH-|#)[0-9]+( |$)")
-            .unwrap();
+    static ref RE: regex::Regex = regex::Regex::new(PATTERN).unwrap();
+    static ref URL_RE: regex::Regex = regex::Regex::new(URL_PATTERN).unwrap();
 }
 
+/// Configuration for which forms of GitHub reference are accepted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct GitHubIdConfig {
+    /// Also accept full URLs, like
+    /// `https://github.com/AnOrganisation/git-mit/issues/642` or
+    /// `https://github.com/AnOrganisation/git-mit/pull/642`
+    pub accept_full_urls: bool,
+}
+
+/// Lint the commit message, returning a [`Problem`] if there is no GitHub ID in
+/// the message
 pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
-    if commit_message.matches_pattern(&RE) {
+    lint_with_comment_char(commit_message, commit_message.get_comment_char())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message.
+///
+/// A `#642` on a line by itself is only a GitHub ID when `#` isn't the
+/// comment character.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    lint_with_options(commit_message, comment_char, &GitHubIdConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if there is no GitHub ID in the message
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &GitHubIdConfig,
+) -> Option<Problem> {
+    lint_with_options(commit_message, commit_message.get_comment_char(), config)
+}
+
+pub(crate) fn lint_with_options(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+    config: &GitHubIdConfig,
+) -> Option<Problem> {
+    if is_empty_message(commit_message, comment_char) {
+        return None;
+    }
+
+    let text = uncommented_text(commit_message, comment_char);
+    if RE.is_match(&text) || (config.accept_full_urls && URL_RE.is_match(&text)) {
         None
     } else {
-        let commit_text = String::from(commit_message.clone());
+        let commit_text = text_above_scissors(commit_message);
         let last_line_location = commit_text
             .trim_end()
             .rfind('\n')
@@ -46,7 +105,7 @@ pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
             Some(vec![(
                 "No GitHub ID".to_string(),
                 last_line_location,
-                commit_text.len().saturating_sub(last_line_location+1),
+                commit_text.trim_end().len().saturating_sub(last_line_location),
             )]),
             Some("https://docs.github.com/en/github/writing-on-github/working-with-advanced-formatting/autolinked-references-and-urls#issues-and-pull-requests".to_string()),
         ))
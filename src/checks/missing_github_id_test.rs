@@ -4,8 +4,141 @@ use miette::{GraphicalReportHandler, GraphicalTheme, Report};
 use mit_commit::CommitMessage;
 use quickcheck::TestResult;
 
-use super::missing_github_id::{lint, ERROR, HELP_MESSAGE};
-use crate::model::{Code, Problem};
+use super::missing_github_id::{
+    lint,
+    lint_with_comment_char,
+    lint_with_config,
+    GitHubIdConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::{
+    checks::comments::is_empty_message,
+    model::{Code, Lint, Problem},
+};
+
+#[test]
+fn id_on_its_own_line_is_an_id_when_hash_is_not_the_comment_char() {
+    let message = CommitMessage::from(
+        "An example commit
+
+This is an example commit
+
+; A comment
+#642
+",
+    );
+
+    assert_eq!(lint_with_comment_char(&message, Some(';')), None);
+}
+
+#[test]
+fn id_on_its_own_line_is_a_comment_when_hash_is_the_comment_char() {
+    let message = CommitMessage::from(
+        "An example commit
+
+This is an example commit
+
+#642
+# A comment
+",
+    );
+
+    assert!(lint_with_comment_char(&message, Some('#')).is_some());
+    assert!(lint(&message).is_some());
+}
+
+#[test]
+fn the_lint_honours_the_comment_char_it_is_given() {
+    let message = CommitMessage::from(
+        "An example commit
+
+This is an example commit
+
+#642
+",
+    );
+
+    assert_eq!(
+        Lint::GitHubIdMissing.lint_with_comment_char(&message, ';'),
+        None
+    );
+    assert!(Lint::GitHubIdMissing
+        .lint_with_comment_char(&message, '#')
+        .is_some());
+}
+
+#[test]
+fn guessed_semicolon_comment_char_is_honoured() {
+    let message = CommitMessage::from(
+        "An example commit
+
+This is an example commit
+
+#642
+; A comment
+",
+    );
+
+    assert_eq!(lint(&message), None);
+}
+
+#[test]
+fn full_urls_are_not_ids_by_default() {
+    let message = CommitMessage::from(
+        "An example commit
+
+Relates-to: https://github.com/AnOrganisation/git-mit/issues/642
+",
+    );
+
+    assert!(lint(&message).is_some());
+    assert!(lint_with_config(&message, &GitHubIdConfig::default()).is_some());
+}
+
+#[test]
+fn full_urls_can_be_accepted() {
+    let config = GitHubIdConfig {
+        accept_full_urls: true,
+    };
+
+    for url in [
+        "https://github.com/AnOrganisation/git-mit/issues/642",
+        "https://github.com/AnOrganisation/git-mit/pull/642",
+        "https://github.com/AnOrganisation/git-mit/pull/642/files",
+        "http://github.com/an-user/git.mit/issues/642#issuecomment-1",
+    ] {
+        let message = CommitMessage::from(format!("An example commit\n\nRelates-to: {url}\n"));
+        let actual = lint_with_config(&message, &config);
+        assert!(
+            actual.is_none(),
+            "Expected {:?} to pass, found {:?}",
+            url,
+            actual
+        );
+    }
+}
+
+#[test]
+fn other_github_urls_are_not_ids() {
+    let config = GitHubIdConfig {
+        accept_full_urls: true,
+    };
+
+    for url in [
+        "https://github.com/AnOrganisation/git-mit",
+        "https://github.com/AnOrganisation/git-mit/issues",
+        "https://example.com/AnOrganisation/git-mit/issues/642",
+        "https://github.com/AnOrganisation/git-mit/commit/642",
+    ] {
+        let message = CommitMessage::from(format!("An example commit\n\nRelates-to: {url}\n"));
+        assert!(
+            lint_with_config(&message, &config).is_some(),
+            "Expected {:?} to fail",
+            url
+        );
+    }
+}
 
 #[test]
 fn id_and_close() {
@@ -191,6 +324,31 @@ This is an example commit
         );
 }
 
+#[test]
+fn an_id_below_the_scissors_is_missing() {
+    let message = "An example commit
+
+This is an example commit
+
+# ------------------------ >8 ------------------------
+# Do not modify or remove the line above.
+# Everything below it will be ignored.
+diff --git a/README.md b/README.md
++fixes #642
+";
+    test_has_missing_github_id(
+            message,
+            Some(Problem::new(
+                ERROR.into(),
+                HELP_MESSAGE.into(),
+                Code::GitHubIdMissing,
+                &message.into(),
+                Some(vec![(String::from("No GitHub ID"), 19, 25)]),
+                Some(String::from("https://docs.github.com/en/github/writing-on-github/working-with-advanced-formatting/autolinked-references-and-urls#issues-and-pull-requests")),
+            )).as_ref(),
+        );
+}
+
 #[test]
 fn id_malformed() {
     let message_1 = "An example commit
@@ -294,6 +452,9 @@ fn fail_check(commit: String) -> TestResult {
     }
 
     let message = CommitMessage::from(commit);
+    if is_empty_message(&message, message.get_comment_char()) {
+        return TestResult::discard();
+    }
     let result = lint(&message);
     TestResult::from_bool(result.is_some())
 }
@@ -0,0 +1,144 @@
+//! Check for a missing issue reference in a configurable part of the message
+
+use std::option::Option::None;
+
+use mit_commit::CommitMessage;
+use regex::Regex;
+
+use crate::{
+    checks::{
+        comments::{is_empty_message, paragraphs, text_above_scissors},
+        malformed_trailer::is_well_formed,
+    },
+    model::{Code, Problem, ProblemBuilder},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "missing-issue-reference";
+/// Description of the problem
+pub const ERROR: &str = "Your commit message is missing an issue reference";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "It's important to reference the issue because it links the code \
+                            back to the motivations for the change, and lets the tracker show \
+                            the work that was done for it.\n\nYou can fix this by adding a \
+                            reference like `#123` or `ABC-123` where this repository expects it";
+
+/// The pattern used to detect an issue reference by default, either a GitHub
+/// style `#123` or a JIRA style `ABC-123`
+pub const PATTERN: &str = r"(^|[\s(\[])(#[0-9]+|[A-Z]{2,}-[0-9]+)\b";
+
+lazy_static! {
+    static ref RE: Regex = Regex::new(PATTERN).unwrap();
+}
+
+/// Where in the commit message the issue reference must be
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Location {
+    /// Anywhere in the message
+    #[default]
+    Anywhere,
+    /// In the footer, the trailers like `Refs: #123` at the end of the
+    /// message
+    ///
+    /// The last paragraph is only the footer when every line in it is a
+    /// trailer, so a message with only a subject, or with no trailers after
+    /// the body, always fails.
+    Footer,
+    /// In the subject line
+    Subject,
+}
+
+/// Configuration for what an issue reference looks like, and where it must be
+#[derive(Debug, Clone)]
+pub struct MissingIssueReferenceConfig {
+    /// The pattern an issue reference matches, tried against one line at a
+    /// time
+    pub pattern: Regex,
+    /// Where the reference must be
+    pub location: Location,
+}
+
+impl Default for MissingIssueReferenceConfig {
+    fn default() -> Self {
+        Self {
+            pattern: RE.clone(),
+            location: Location::Anywhere,
+        }
+    }
+}
+
+/// Lint the commit message, returning a [`Problem`] if there is no issue
+/// reference like `#123` or `ABC-123` anywhere in the message
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &MissingIssueReferenceConfig::default())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    lint_with_options(
+        commit_message,
+        comment_char,
+        &MissingIssueReferenceConfig::default(),
+    )
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] labelling the last line if there's no match for the pattern in
+/// the configured location
+///
+/// Comments and anything below a scissors line are ignored.
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &MissingIssueReferenceConfig,
+) -> Option<Problem> {
+    lint_with_options(commit_message, commit_message.get_comment_char(), config)
+}
+
+pub(crate) fn lint_with_options(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+    config: &MissingIssueReferenceConfig,
+) -> Option<Problem> {
+    if is_empty_message(commit_message, comment_char) {
+        return None;
+    }
+
+    let commit_text = text_above_scissors(commit_message);
+    let paragraphs = paragraphs(&commit_text, comment_char);
+    let searched: Vec<&(usize, &str)> = match config.location {
+        Location::Anywhere => paragraphs.iter().flatten().collect(),
+        Location::Footer => paragraphs
+            .iter()
+            .skip(1)
+            .last()
+            .filter(|paragraph| paragraph.iter().all(|(_, line)| is_well_formed(line)))
+            .into_iter()
+            .flatten()
+            .collect(),
+        Location::Subject => paragraphs.iter().flatten().take(1).collect(),
+    };
+    if searched
+        .iter()
+        .any(|(_, line)| config.pattern.is_match(line))
+    {
+        return None;
+    }
+
+    Some(
+        ProblemBuilder::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::MissingIssueReference,
+            commit_message,
+        )
+        .with_label_at_last_line("No issue reference".to_string())
+        .build(),
+    )
+}
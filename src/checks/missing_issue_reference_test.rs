@@ -0,0 +1,214 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+use regex::Regex;
+
+use super::missing_issue_reference::{
+    lint,
+    lint_with_config,
+    Location,
+    MissingIssueReferenceConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+#[test]
+fn github_style_references_pass() {
+    run_test("Add login\n\nCloses #123\n", None);
+}
+
+#[test]
+fn jira_style_references_pass() {
+    run_test("[ABC-123] Add login\n", None);
+}
+
+#[test]
+fn messages_without_a_reference_fail() {
+    let message = "Add login\n\nSome body content\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::MissingIssueReference,
+            &message.into(),
+            Some(vec![("No issue reference".to_string(), 11, 17)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn references_in_comments_do_not_count() {
+    let message = "Add login\n\n# Refs: ABC-123\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::MissingIssueReference,
+            &message.into(),
+            Some(vec![("No issue reference".to_string(), 11, 15)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn empty_messages_pass() {
+    run_test("", None);
+    run_test("# Only a comment\n", None);
+}
+
+#[test]
+fn a_footer_reference_passes_when_the_footer_is_required() {
+    let config = MissingIssueReferenceConfig {
+        location: Location::Footer,
+        ..MissingIssueReferenceConfig::default()
+    };
+
+    assert_eq!(
+        lint_with_config(
+            &CommitMessage::from("Add login\n\nSome body content\n\nRefs: ABC-123\n"),
+            &config
+        ),
+        None
+    );
+}
+
+#[test]
+fn a_subject_reference_fails_when_the_footer_is_required() {
+    let config = MissingIssueReferenceConfig {
+        location: Location::Footer,
+        ..MissingIssueReferenceConfig::default()
+    };
+    let message = "[ABC-123] Add login\n\nSome body content\n\nCo-authored-by: Someone <someone@example.com>\n";
+
+    assert_eq!(
+        lint_with_config(&CommitMessage::from(message), &config),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::MissingIssueReference,
+            &message.into(),
+            Some(vec![("No issue reference".to_string(), 40, 45)]),
+            None,
+        ))
+    );
+    assert!(lint_with_config(&CommitMessage::from("[ABC-123] Add login\n"), &config).is_some());
+}
+
+#[test]
+fn a_body_reference_fails_when_the_footer_is_required() {
+    let config = MissingIssueReferenceConfig {
+        location: Location::Footer,
+        ..MissingIssueReferenceConfig::default()
+    };
+
+    assert!(lint_with_config(
+        &CommitMessage::from("Add login\n\nThis fixes #123 by adding a form\n"),
+        &config
+    )
+    .is_some());
+    assert!(lint_with_config(
+        &CommitMessage::from(
+            "Add login\n\nThis fixes #123\n\nSigned-off-by: Someone <someone@example.com>\n"
+        ),
+        &config
+    )
+    .is_some());
+}
+
+#[test]
+fn a_body_reference_fails_when_the_subject_is_required() {
+    let config = MissingIssueReferenceConfig {
+        location: Location::Subject,
+        ..MissingIssueReferenceConfig::default()
+    };
+
+    assert!(lint_with_config(&CommitMessage::from("Add login\n\nRefs #123\n"), &config).is_some());
+    assert!(lint_with_config(&CommitMessage::from("Add login (#123)\n"), &config).is_none());
+}
+
+#[test]
+fn the_pattern_can_be_configured() {
+    let config = MissingIssueReferenceConfig {
+        pattern: Regex::new(r"\bTICKET-[0-9]+\b").unwrap(),
+        ..MissingIssueReferenceConfig::default()
+    };
+
+    assert!(lint_with_config(&CommitMessage::from("Add login\n\nRefs #123\n"), &config).is_some());
+    assert!(lint_with_config(&CommitMessage::from("Add login\n\nTICKET-9\n"), &config).is_none());
+}
+
+#[test]
+fn formatting() {
+    let message = "Add login\n\nSome body content\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "MissingIssueReference
+
+  x Your commit message is missing an issue reference
+   ,-[3:1]
+ 2 | 
+ 3 | Some body content
+   : ^^^^^^^^|^^^^^^^^
+   :         `-- No issue reference
+   `----
+  help: It's important to reference the issue because it links the code back
+        to the motivations for the change, and lets the tracker show the work
+        that was done for it.
+        
+        You can fix this by adding a reference like `#123` or `ABC-123` where
+        this repository expects it
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(subject: String, issue: u16) -> TestResult {
+    if subject.contains('\n') || subject.starts_with('#') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("{subject}\n\nRefs: #{issue}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(body: String) -> TestResult {
+    if body.contains('#') || body.contains('-') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Add login\n\nSome body content {body}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -1,8 +1,13 @@
+//! Check for a missing JIRA issue key
+
 use std::{ops::Add, option::Option::None};
 
 use mit_commit::CommitMessage;
 
-use crate::model::{Code, Problem};
+use crate::{
+    checks::comments::{is_empty_message, text_above_scissors, uncommented_text},
+    model::{Code, Problem},
+};
 
 /// Canonical lint ID
 pub const CONFIG: &str = "jira-issue-key-missing";
@@ -14,16 +19,36 @@ You can fix this by adding a key like `JRA-123` to the commit message" ;
 /// Description of the problem
 pub const ERROR: &str = "Your commit message is missing a JIRA Issue Key";
 
+/// The pattern used to detect a JIRA issue key
+pub const PATTERN: &str = r"(?m)(^| )\[?[A-Z]{2,}-[0-9]+\]?(| |$)";
+
 lazy_static! {
-    static ref RE: regex::Regex =
-        regex::Regex::new(r"(?m)(^| )\[?[A-Z]{2,}-[0-9]+\]?(| |$)").unwrap();
+    static ref RE: regex::Regex = regex::Regex::new(PATTERN).unwrap();
 }
 
+/// Lint the commit message, returning a [`Problem`] if there is no JIRA issue
+/// key in the message
 pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
-    if commit_message.matches_pattern(&RE) {
+    lint_with_comment_char(commit_message, commit_message.get_comment_char())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    if is_empty_message(commit_message, comment_char) {
+        return None;
+    }
+
+    if RE.is_match(&uncommented_text(commit_message, comment_char)) {
         None
     } else {
-        let commit_text = String::from(commit_message.clone());
+        let commit_text = text_above_scissors(commit_message);
         let last_line_location = commit_text
             .trim_end()
             .rfind('\n')
@@ -37,7 +62,7 @@ pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
             Some(vec![(
                 "No JIRA Issue Key".to_string(),
                 last_line_location,
-                commit_text.len().saturating_sub(last_line_location+1),
+                commit_text.trim_end().len().saturating_sub(last_line_location),
             )]),
             Some("https://support.atlassian.com/jira-software-cloud/docs/what-is-an-issue/#Workingwithissues-Projectkeys".to_string()),
         ))
@@ -6,8 +6,27 @@ use miette::{GraphicalReportHandler, GraphicalTheme, Report};
 use mit_commit::CommitMessage;
 use quickcheck::TestResult;
 
-use super::missing_jira_issue_key::{lint, ERROR, HELP_MESSAGE};
-use crate::model::{Code, Problem};
+use super::missing_jira_issue_key::{lint, lint_with_comment_char, ERROR, HELP_MESSAGE, PATTERN};
+use crate::{
+    checks::comments::is_empty_message,
+    model::{Code, Problem},
+};
+
+#[test]
+fn id_is_only_a_comment_with_the_matching_comment_char() {
+    let message = CommitMessage::from(
+        "An example commit
+
+This is an example commit
+
+; A comment
+# JRA-123
+",
+    );
+
+    assert_eq!(lint_with_comment_char(&message, Some(';')), None);
+    assert!(lint_with_comment_char(&message, Some('#')).is_some());
+}
 
 #[test]
 fn id_present() {
@@ -130,6 +149,31 @@ JRA-
     );
 }
 
+#[test]
+fn a_key_below_the_scissors_is_missing() {
+    let message = "An example commit
+
+This is an example commit
+
+# ------------------------ >8 ------------------------
+# Do not modify or remove the line above.
+# Everything below it will be ignored.
+diff --git a/README.md b/README.md
++JRA-123
+";
+    test_has_missing_jira_issue_key(
+        message,
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::JiraIssueKeyMissing,
+            &message.into(),
+            Some(vec![("No JIRA Issue Key".to_string(), 19_usize, 25_usize)]),
+            Some("https://support.atlassian.com/jira-software-cloud/docs/what-is-an-issue/#Workingwithissues-Projectkeys".parse().unwrap()),
+        )).as_ref(),
+    );
+}
+
 #[test]
 fn formatting() {
     let message = "An example commit
@@ -181,7 +225,13 @@ fn test_has_missing_jira_issue_key(message: &str, expected: Option<&Problem>) {
 #[allow(clippy::needless_pass_by_value)]
 #[quickcheck]
 fn fail_check(commit: String) -> TestResult {
+    if regex::Regex::new(PATTERN).unwrap().is_match(&commit) {
+        return TestResult::discard();
+    }
     let message = CommitMessage::from(commit);
+    if is_empty_message(&message, message.get_comment_char()) {
+        return TestResult::discard();
+    }
     let result = lint(&message);
     TestResult::from_bool(result.is_some())
 }
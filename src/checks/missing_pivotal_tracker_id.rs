@@ -1,8 +1,13 @@
-use std::{ops::Add, option::Option::None};
+//! Check for a missing Pivotal Tracker ID
+
+use std::{borrow::Cow, ops::Add, option::Option::None};
 
 use mit_commit::CommitMessage;
 
-use crate::model::{Code, Problem};
+use crate::{
+    checks::comments::{is_empty_message, text_above_scissors, uncommented_text},
+    model::{Code, Problem},
+};
 
 /// Canonical lint ID
 pub const CONFIG: &str = "pivotal-tracker-id-missing";
@@ -27,18 +32,95 @@ This will address [#12345884]";
 /// Description of the problem
 pub const ERROR: &str = "Your commit message is missing a Pivotal Tracker ID";
 
+/// The pattern used to detect a Pivotal Tracker ID
+pub const PATTERN: &str =
+    r"(?i)\[(((finish|fix)(ed|es)?|complete[ds]?|deliver(s|ed)?) )?#\d+([, ]#\d+)*]";
+
+const STATE_WORDS: &str = r"(finish|fix)(ed|es)?|complete[ds]?|deliver(s|ed)?";
+
 lazy_static! {
-    static ref RE: regex::Regex = regex::Regex::new(
-        r"(?i)\[(((finish|fix)(ed|es)?|complete[ds]?|deliver(s|ed)?) )?#\d+([, ]#\d+)*]"
-    )
-    .unwrap();
+    static ref RE: regex::Regex = regex::Regex::new(PATTERN).unwrap();
+}
+
+/// Configuration for which state words may come before the ID
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PivotalTrackerIdConfig {
+    /// Words accepted as well as `finishes`, `fixes`, `completes` and
+    /// `delivers`, like `resolves` in `[resolves #12345678]`
+    ///
+    /// These are matched exactly, ignoring case.
+    pub extra_state_words: Vec<String>,
+}
+
+impl PivotalTrackerIdConfig {
+    fn regex(&self) -> Cow<'static, regex::Regex> {
+        let extra_state_words: Vec<String> = self
+            .extra_state_words
+            .iter()
+            .filter(|word| !word.is_empty())
+            .map(|word| regex::escape(word))
+            .collect();
+        if extra_state_words.is_empty() {
+            return Cow::Borrowed(&RE);
+        }
+
+        Cow::Owned(
+            regex::Regex::new(&format!(
+                r"(?i)\[(({STATE_WORDS}|{}) )?#\d+([, ]#\d+)*]",
+                extra_state_words.join("|")
+            ))
+            .unwrap(),
+        )
+    }
 }
 
+/// Lint the commit message, returning a [`Problem`] if there is no Pivotal
+/// Tracker ID in the message
 pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
-    if commit_message.matches_pattern(&RE) {
+    lint_with_comment_char(commit_message, commit_message.get_comment_char())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    lint_with_options(
+        commit_message,
+        comment_char,
+        &PivotalTrackerIdConfig::default(),
+    )
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if there is no Pivotal Tracker ID in the message
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &PivotalTrackerIdConfig,
+) -> Option<Problem> {
+    lint_with_options(commit_message, commit_message.get_comment_char(), config)
+}
+
+pub(crate) fn lint_with_options(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+    config: &PivotalTrackerIdConfig,
+) -> Option<Problem> {
+    if is_empty_message(commit_message, comment_char) {
+        return None;
+    }
+
+    if config
+        .regex()
+        .is_match(&uncommented_text(commit_message, comment_char))
+    {
         None
     } else {
-        let commit_text = String::from(commit_message.clone());
+        let commit_text = text_above_scissors(commit_message);
         let last_line_location = commit_text
             .trim_end()
             .rfind('\n')
@@ -52,7 +134,7 @@ pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
             Some(vec![(
                 "No Pivotal Tracker ID".to_string(),
                 last_line_location,
-                commit_text.len().saturating_sub(last_line_location+1),
+                commit_text.trim_end().len().saturating_sub(last_line_location),
             )]),
             Some("https://www.pivotaltracker.com/help/api?version=v5#Tracker_Updates_in_SCM_Post_Commit_Hooks".to_string()),
         ))
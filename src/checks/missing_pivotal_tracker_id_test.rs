@@ -4,8 +4,17 @@ use miette::{GraphicalReportHandler, GraphicalTheme, Report};
 use mit_commit::CommitMessage;
 use quickcheck::TestResult;
 
-use super::missing_pivotal_tracker_id::{lint, ERROR, HELP_MESSAGE};
-use crate::model::{Code, Problem};
+use super::missing_pivotal_tracker_id::{
+    lint,
+    lint_with_config,
+    PivotalTrackerIdConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::{
+    checks::comments::is_empty_message,
+    model::{Code, Problem},
+};
 
 #[test]
 fn with_id() {
@@ -30,6 +39,45 @@ fn test_has_missing_pivotal_tracker_id(message: &str, expected: Option<&Problem>
     );
 }
 
+#[test]
+fn extra_state_words_can_be_configured() {
+    let message = CommitMessage::from("An example commit\n\n[resolves #12345678]\n");
+    let config = PivotalTrackerIdConfig {
+        extra_state_words: vec!["resolves".to_string()],
+    };
+
+    assert!(lint(&message).is_some());
+    assert!(lint_with_config(&message, &config).is_none());
+    assert!(lint_with_config(
+        &CommitMessage::from("An example commit\n\n[Delivers #12345678]\n"),
+        &config
+    )
+    .is_none());
+}
+
+#[test]
+fn extra_state_words_are_matched_literally() {
+    let config = PivotalTrackerIdConfig {
+        extra_state_words: vec!["re.solves|".to_string(), String::new()],
+    };
+
+    assert!(lint_with_config(
+        &CommitMessage::from("An example commit\n\n[re.solves| #12345678]\n"),
+        &config
+    )
+    .is_none());
+    assert!(lint_with_config(
+        &CommitMessage::from("An example commit\n\n[reXsolves #12345678]\n"),
+        &config
+    )
+    .is_some());
+    assert!(lint_with_config(
+        &CommitMessage::from("An example commit\n\n[ #12345678]\n"),
+        &config
+    )
+    .is_some());
+}
+
 #[test]
 fn multiple_ids() {
     test_has_missing_pivotal_tracker_id(
@@ -322,6 +370,31 @@ This is an example commit
     );
 }
 
+#[test]
+fn an_id_below_the_scissors_is_missing() {
+    let message = "An example commit
+
+This is an example commit
+
+# ------------------------ >8 ------------------------
+# Do not modify or remove the line above.
+# Everything below it will be ignored.
+diff --git a/README.md b/README.md
++[Delivers #12345678]
+";
+    test_has_missing_pivotal_tracker_id(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::PivotalTrackerIdMissing,
+            &message.into(),
+            Some(vec![("No Pivotal Tracker ID".to_string(), 19, 25)]),
+            Some("https://www.pivotaltracker.com/help/api?version=v5#Tracker_Updates_in_SCM_Post_Commit_Hooks".parse().unwrap()),
+        )),
+    );
+}
+
 #[test]
 fn formatting() {
     let message = "An example commit
@@ -375,6 +448,9 @@ fn fmt_report(diag: &Report) -> String {
 #[quickcheck]
 fn fail_check(commit: String) -> TestResult {
     let message = CommitMessage::from(commit);
+    if is_empty_message(&message, message.get_comment_char()) {
+        return TestResult::discard();
+    }
     let result = lint(&message);
     TestResult::from_bool(result.is_some())
 }
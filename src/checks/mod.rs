@@ -1,9 +1,73 @@
+//! The checks that make up each of the lints
+
+pub mod body_contains_tabs;
+#[cfg(test)]
+mod body_contains_tabs_test;
+pub mod body_leading_trailing_blanks;
+#[cfg(test)]
+mod body_leading_trailing_blanks_test;
+pub mod body_not_meaningful;
+#[cfg(test)]
+mod body_not_meaningful_test;
+pub mod body_paragraph_too_long;
+#[cfg(test)]
+mod body_paragraph_too_long_test;
+pub mod body_sentence_no_terminator;
+#[cfg(test)]
+mod body_sentence_no_terminator_test;
+pub mod body_separator_line;
+#[cfg(test)]
+mod body_separator_line_test;
+pub mod body_unrelated_to_subject;
+#[cfg(test)]
+mod body_unrelated_to_subject_test;
 pub mod body_wider_than_72_characters;
+mod comments;
+pub mod diff_pasted_in_body;
+#[cfg(test)]
+mod diff_pasted_in_body_test;
+pub mod double_revert;
+#[cfg(test)]
+mod double_revert_test;
 pub mod duplicate_trailers;
+pub mod email_in_body;
+#[cfg(test)]
+mod email_in_body_test;
+pub mod empty_commit_message;
+#[cfg(test)]
+mod empty_commit_message_test;
+pub mod footer_before_body;
+#[cfg(test)]
+mod footer_before_body_test;
+pub mod footer_url_too_long;
+#[cfg(test)]
+mod footer_url_too_long_test;
+pub mod inconsistent_list_indent;
+#[cfg(test)]
+mod inconsistent_list_indent_test;
+pub mod inconsistent_list_punctuation;
+#[cfg(test)]
+mod inconsistent_list_punctuation_test;
+pub mod malformed_revert;
+#[cfg(test)]
+mod malformed_revert_test;
+pub mod malformed_trailer;
+#[cfg(test)]
+mod malformed_trailer_test;
+pub mod missing_final_newline;
+#[cfg(test)]
+mod missing_final_newline_test;
+#[cfg(feature = "regex-lints")]
 pub mod missing_github_id;
+#[cfg(feature = "regex-lints")]
+pub mod missing_issue_reference;
+#[cfg(all(test, feature = "regex-lints"))]
+mod missing_issue_reference_test;
+#[cfg(feature = "regex-lints")]
 pub mod missing_jira_issue_key;
+#[cfg(feature = "regex-lints")]
 pub mod missing_pivotal_tracker_id;
-#[cfg(test)]
+#[cfg(all(test, feature = "regex-lints"))]
 mod missing_pivotal_tracker_id_test;
 pub mod not_conventional_commit;
 #[cfg(test)]
@@ -11,24 +75,83 @@ mod not_conventional_commit_test;
 pub mod not_emoji_log;
 #[cfg(test)]
 mod not_emoji_log_test;
+pub mod replacement_characters;
+#[cfg(test)]
+mod replacement_characters_test;
+pub mod shell_artifact;
+#[cfg(test)]
+mod shell_artifact_test;
+pub mod subject_all_caps;
+#[cfg(test)]
+mod subject_all_caps_test;
+pub mod subject_case_style;
+#[cfg(test)]
+mod subject_case_style_test;
+pub mod subject_echoed_in_body;
+#[cfg(test)]
+mod subject_echoed_in_body_test;
+pub mod subject_ends_with_punctuation;
+#[cfg(test)]
+mod subject_ends_with_punctuation_test;
+pub mod subject_filler_words;
+#[cfg(test)]
+mod subject_filler_words_test;
+pub mod subject_gerund_start;
+#[cfg(test)]
+mod subject_gerund_start_test;
+pub mod subject_leading_emoji;
+#[cfg(test)]
+mod subject_leading_emoji_test;
 pub mod subject_line_ends_with_period;
 #[cfg(test)]
 mod subject_line_ends_with_period_test;
 pub mod subject_longer_than_72_characters;
 #[cfg(test)]
 mod subject_longer_than_72_characters_test;
+pub mod subject_markdown_heading;
+#[cfg(test)]
+mod subject_markdown_heading_test;
+#[cfg(feature = "regex-lints")]
+pub mod subject_missing_prefix;
+#[cfg(all(test, feature = "regex-lints"))]
+mod subject_missing_prefix_test;
+pub mod subject_not_atomic;
+#[cfg(test)]
+mod subject_not_atomic_test;
 pub mod subject_not_capitalized;
 #[cfg(test)]
 mod subject_not_capitalized_test;
 pub mod subject_not_separate_from_body;
 #[cfg(test)]
 mod subject_not_separate_from_body_test;
+#[cfg(feature = "regex-lints")]
+pub mod subject_only_ticket;
+#[cfg(all(test, feature = "regex-lints"))]
+mod subject_only_ticket_test;
+pub mod subject_whitespace;
+#[cfg(test)]
+mod subject_whitespace_test;
+pub mod too_many_co_authors;
+#[cfg(test)]
+mod too_many_co_authors_test;
+pub mod too_many_trailers;
+#[cfg(test)]
+mod too_many_trailers_test;
+pub mod unedited_template;
+#[cfg(test)]
+mod unedited_template_test;
+pub mod unknown_footer_token;
+#[cfg(test)]
+mod unknown_footer_token_test;
+pub mod work_in_progress;
+#[cfg(test)]
+mod work_in_progress_test;
 
 #[cfg(test)]
 mod body_wider_than_72_characters_test;
 #[cfg(test)]
 mod duplicate_trailers_test;
-#[cfg(test)]
+#[cfg(all(test, feature = "regex-lints"))]
 mod missing_github_id_test;
-#[cfg(test)]
+#[cfg(all(test, feature = "regex-lints"))]
 mod missing_jira_issue_key_test;
@@ -1,8 +1,13 @@
+//! Check for commits following the conventional standard
+
 use std::option::Option::None;
 
 use mit_commit::CommitMessage;
 
-use crate::model::{Code, Problem};
+use crate::{
+    checks::comments::{is_empty_message, uncommented_text},
+    model::{Code, Problem},
+};
 
 /// Canonical lint ID
 pub const CONFIG: &str = "not-conventional-commit";
@@ -23,34 +28,311 @@ You can fix it by following style
 /// Description of the problem
 pub const ERROR: &str = "Your commit message isn't in conventional style";
 
-lazy_static! {
-    static ref RE: regex::Regex = regex::Regex::new("^[a-zA-Z0-9]+(\\(\\w+\\))?!?: ").unwrap();
+/// The types used by the Angular convention
+pub const ANGULAR_TYPES: [&str; 11] = [
+    "feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore", "revert",
+];
+
+/// What to do with the `!` that marks a breaking change, like `feat!: x`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BangPolicy {
+    /// Any commit may use the marker
+    #[default]
+    Allow,
+    /// No commit may use the marker, so breaking changes are only described
+    /// in a `BREAKING CHANGE` footer
+    Forbid,
+    /// Commits with a `BREAKING CHANGE` footer must also use the marker
+    RequireForBreaking,
 }
 
-fn has_problem(commit_message: &CommitMessage<'_>) -> bool {
-    let subject: String = commit_message.get_subject().into();
+/// Which case the first letter of the description must be in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DescriptionCase {
+    /// Start with a lowercase letter, like `feat: add x`
+    Lower,
+    /// Start with an uppercase letter, like `feat: Add x`
+    Upper,
+}
 
-    !RE.is_match(&subject)
+/// Configuration for which types and scopes a conventional commit may have
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ConventionalCommitConfig {
+    /// The types that may be used, or any type when this is empty
+    pub allowed_types: Vec<String>,
+    /// Allow path-style scopes made of `/` separated segments, like
+    /// `feat(api/v2): x`, for monorepos
+    pub allow_path_scopes: bool,
+    /// The scopes that may be used, or any scope when this is empty
+    ///
+    /// A path-style scope is allowed when the whole path is in this list, or
+    /// when every one of its segments is.
+    pub allowed_scopes: Vec<String>,
+    /// Flag subjects with nothing but whitespace after the prefix, like
+    /// `feat: `
+    pub require_nonempty_description: bool,
+    /// When the `!` breaking change marker may or must be used
+    pub bang_policy: BangPolicy,
+    /// The most characters a scope may have, or any length when this is
+    /// `None`
+    pub max_scope_length: Option<usize>,
+    /// The case the description must start with, or either case when this
+    /// is `None`
+    ///
+    /// Descriptions that don't start with a letter, like `feat: 2fa login`,
+    /// are allowed either way.
+    pub description_case: Option<DescriptionCase>,
+    /// Flag subjects without a scope, like `feat: x`
+    pub require_scope: bool,
 }
 
+impl ConventionalCommitConfig {
+    /// Only allow the types used by the Angular convention, like `feat` and
+    /// `fix`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::checks::not_conventional_commit::{lint_with_config, ConventionalCommitConfig};
+    ///
+    /// let config = ConventionalCommitConfig::angular();
+    /// assert!(lint_with_config(&CommitMessage::from("feat: x"), &config).is_none());
+    /// assert!(lint_with_config(&CommitMessage::from("wip: x"), &config).is_some());
+    /// ```
+    #[must_use]
+    pub fn angular() -> Self {
+        Self {
+            allowed_types: ANGULAR_TYPES.iter().map(ToString::to_string).collect(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Get the length of the `<type>[(<scope>)][!]: ` at the start of the subject,
+/// if it has one
+///
+/// This accepts any scope, including path-style ones.
+pub(crate) fn conventional_prefix_length(subject: &str) -> Option<usize> {
+    prefix_length(
+        subject,
+        &ConventionalCommitConfig {
+            allowed_types: vec![],
+            allow_path_scopes: true,
+            allowed_scopes: vec![],
+            require_nonempty_description: false,
+            bang_policy: BangPolicy::Allow,
+            max_scope_length: None,
+            description_case: None,
+            require_scope: false,
+        },
+    )
+}
+
+fn prefix_length(subject: &str, config: &ConventionalCommitConfig) -> Option<usize> {
+    let type_length = subject
+        .find(|character: char| !character.is_ascii_alphanumeric())
+        .unwrap_or(subject.len());
+    if type_length == 0
+        || !(config.allowed_types.is_empty()
+            || config
+                .allowed_types
+                .iter()
+                .any(|allowed_type| *allowed_type == subject[..type_length]))
+    {
+        return None;
+    }
+
+    let rest = &subject[type_length..];
+    let rest = match rest.strip_prefix('(') {
+        Some(scope_and_rest) => match scope_and_rest.split_once(')') {
+            Some((scope, rest)) if is_valid_scope(scope, config) => rest,
+            _ => return None,
+        },
+        None => rest,
+    };
+    let rest = rest.strip_prefix('!').unwrap_or(rest);
+
+    rest.strip_prefix(": ")
+        .map(|description| subject.len() - description.len())
+}
+
+fn is_valid_scope(scope: &str, config: &ConventionalCommitConfig) -> bool {
+    let segments: Vec<&str> = if config.allow_path_scopes {
+        scope.split('/').collect()
+    } else {
+        vec![scope]
+    };
+    if segments
+        .iter()
+        .any(|segment| segment.is_empty() || !segment.chars().all(is_word_character))
+    {
+        return false;
+    }
+
+    let is_allowed = |scope: &str| {
+        config
+            .allowed_scopes
+            .iter()
+            .any(|allowed_scope| allowed_scope == scope)
+    };
+
+    config.allowed_scopes.is_empty()
+        || is_allowed(scope)
+        || segments.iter().all(|segment| is_allowed(segment))
+}
+
+fn is_word_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+/// Lint the commit message, returning a [`Problem`] if the subject isn't in
+/// conventional commit style
 pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
-    if has_problem(commit_message) {
-        let commit_text = String::from(commit_message.clone());
-        Some(Problem::new(
-            ERROR.into(),
-            HELP_MESSAGE.into(),
-            Code::NotConventionalCommit,
-            commit_message,
-            Some(vec![(
-                "Not conventional".to_string(),
-                0_usize,
-                commit_text.lines().next().map(str::len).unwrap_or_default(),
-            )]),
-            Some("https://www.conventionalcommits.org/".to_string()),
-        ))
+    lint_with_config(commit_message, &ConventionalCommitConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if the subject isn't in conventional commit style, or uses a
+/// type or scope that isn't allowed
+///
+/// When a description is required, a subject like `feat: ` is labelled from
+/// just after the colon. When the `!` marker is forbidden it is labelled, and
+/// when it's required for breaking changes the colon of a prefix missing it
+/// is labelled. A missing scope, when one is required, is labelled from the
+/// start of the type to the colon. A scope longer than the maximum, and the
+/// first letter of a description in the wrong case, are labelled too.
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &ConventionalCommitConfig,
+) -> Option<Problem> {
+    if is_empty_message(commit_message, commit_message.get_comment_char()) {
+        return None;
+    }
+
+    let subject: String = commit_message.get_subject().into();
+    if let Some(prefix) = prefix_length(&subject, config) {
+        return prefix_problem(commit_message, &subject, prefix, config)
+            .map(|label| problem(commit_message, label));
+    }
+
+    let any_type = ConventionalCommitConfig {
+        allowed_types: vec![],
+        ..config.clone()
+    };
+    let label = if prefix_length(&subject, &any_type).is_some() {
+        (
+            "Type not allowed".to_string(),
+            0_usize,
+            subject
+                .find(|character: char| !character.is_ascii_alphanumeric())
+                .unwrap_or_default(),
+        )
     } else {
-        None
+        let commit_text = String::from(commit_message.clone());
+        // Labels are byte spans, so this covers multibyte subjects exactly
+        (
+            "Not conventional".to_string(),
+            0_usize,
+            commit_text.lines().next().map(str::len).unwrap_or_default(),
+        )
+    };
+
+    Some(problem(commit_message, label))
+}
+
+fn prefix_problem(
+    commit_message: &CommitMessage<'_>,
+    subject: &str,
+    prefix: usize,
+    config: &ConventionalCommitConfig,
+) -> Option<(String, usize, usize)> {
+    let colon = subject[..prefix].rfind(':').unwrap_or(prefix);
+    let has_bang = subject[..colon].ends_with('!');
+
+    if config.require_nonempty_description && subject[prefix..].trim().is_empty() {
+        let subject_length = subject.lines().next().map(str::len).unwrap_or_default();
+        return Some((
+            "Missing description".to_string(),
+            colon + 1,
+            subject_length.saturating_sub(colon + 1),
+        ));
+    }
+
+    if let Some(label) = description_case_problem(subject, prefix, config.description_case) {
+        return Some(label);
+    }
+
+    let scope = scope(subject);
+    if config.require_scope && scope.is_none() {
+        return Some(("Missing scope".to_string(), 0, colon + 1));
+    }
+
+    if let Some((scope_start, scope)) = scope {
+        if config
+            .max_scope_length
+            .is_some_and(|max_scope_length| scope.chars().count() > max_scope_length)
+        {
+            return Some(("Scope too long".to_string(), scope_start, scope.len()));
+        }
     }
+
+    match config.bang_policy {
+        BangPolicy::Forbid if has_bang => Some((
+            "Breaking change marker not allowed".to_string(),
+            colon - 1,
+            1,
+        )),
+        BangPolicy::RequireForBreaking if !has_bang && has_breaking_footer(commit_message) => {
+            Some(("Missing `!` for breaking change".to_string(), colon, 1))
+        }
+        _ => None,
+    }
+}
+
+fn description_case_problem(
+    subject: &str,
+    prefix: usize,
+    description_case: Option<DescriptionCase>,
+) -> Option<(String, usize, usize)> {
+    let first = subject[prefix..].chars().next()?;
+    let label = match description_case? {
+        DescriptionCase::Lower if first.is_uppercase() => "Not lowercase",
+        DescriptionCase::Upper if first.is_lowercase() => "Not uppercase",
+        _ => return None,
+    };
+
+    Some((label.to_string(), prefix, first.len_utf8()))
+}
+
+/// Get the offset and text of the scope in a subject with a valid prefix
+fn scope(subject: &str) -> Option<(usize, &str)> {
+    let scope_start = subject.find(|character: char| !character.is_ascii_alphanumeric())? + 1;
+    if !subject[..scope_start].ends_with('(') {
+        return None;
+    }
+
+    subject[scope_start..]
+        .split_once(')')
+        .map(|(scope, _)| (scope_start, scope))
+}
+
+fn has_breaking_footer(commit_message: &CommitMessage<'_>) -> bool {
+    uncommented_text(commit_message, commit_message.get_comment_char())
+        .lines()
+        .skip(1)
+        .any(|line| line.starts_with("BREAKING CHANGE:") || line.starts_with("BREAKING-CHANGE:"))
+}
+
+fn problem(commit_message: &CommitMessage<'_>, label: (String, usize, usize)) -> Problem {
+    Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::NotConventionalCommit,
+        commit_message,
+        Some(vec![label]),
+        Some("https://www.conventionalcommits.org/".to_string()),
+    )
 }
 
 #[cfg(test)]
@@ -4,8 +4,16 @@ use miette::{GraphicalReportHandler, GraphicalTheme, Report};
 use mit_commit::{CommitMessage, Trailer};
 use quickcheck::TestResult;
 
-use super::not_conventional_commit::{lint, ERROR, HELP_MESSAGE};
-use crate::{model::Code, Problem};
+use super::not_conventional_commit::{
+    lint,
+    lint_with_config,
+    BangPolicy,
+    ConventionalCommitConfig,
+    DescriptionCase,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::{checks::comments::is_empty_message, model::Code, Problem};
 
 // Examples from https://www.conventionalcommits.org/en/v1.0.0/
 
@@ -146,6 +154,424 @@ This is an example commit
     );
 }
 
+#[test]
+fn path_scopes_are_not_conventional_by_default() {
+    let message = "feat(api/v2): x
+";
+    test_subject_not_separate_from_body(
+        message,
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::NotConventionalCommit,
+            &message.into(),
+            Some(vec![("Not conventional".to_string(), 0_usize, 15_usize)]),
+            Some("https://www.conventionalcommits.org/".parse().unwrap()),
+        ))
+        .as_ref(),
+    );
+}
+
+#[test]
+fn path_scopes_can_be_allowed() {
+    let config = ConventionalCommitConfig {
+        allowed_types: vec![],
+        allow_path_scopes: true,
+        allowed_scopes: vec![],
+        ..ConventionalCommitConfig::default()
+    };
+
+    for subject in ["feat(api/v2): x", "feat(packages/ui): x", "feat(api): x"] {
+        let actual = lint_with_config(&CommitMessage::from(subject), &config);
+        assert!(
+            actual.is_none(),
+            "Expected {:?} to pass, found {:?}",
+            subject,
+            actual
+        );
+    }
+    for subject in [
+        "feat(api/): x",
+        "feat(/api): x",
+        "feat(api//v2): x",
+        "feat(api/v 2): x",
+    ] {
+        let actual = lint_with_config(&CommitMessage::from(subject), &config);
+        assert!(actual.is_some(), "Expected {:?} to fail", subject);
+    }
+}
+
+#[test]
+fn scopes_can_be_restricted() {
+    let config = ConventionalCommitConfig {
+        allowed_types: vec![],
+        allow_path_scopes: false,
+        allowed_scopes: vec!["api".into(), "ui".into()],
+        ..ConventionalCommitConfig::default()
+    };
+
+    assert!(lint_with_config(&CommitMessage::from("feat(api): x"), &config).is_none());
+    assert!(lint_with_config(&CommitMessage::from("feat: x"), &config).is_none());
+    assert!(lint_with_config(&CommitMessage::from("feat(docs): x"), &config).is_some());
+}
+
+#[test]
+fn path_scopes_match_the_whole_path_or_every_segment() {
+    let config = ConventionalCommitConfig {
+        allowed_types: vec![],
+        allow_path_scopes: true,
+        allowed_scopes: vec!["api".into(), "v2".into(), "packages/ui".into()],
+        ..ConventionalCommitConfig::default()
+    };
+
+    assert!(lint_with_config(&CommitMessage::from("feat(api/v2): x"), &config).is_none());
+    assert!(lint_with_config(&CommitMessage::from("feat(packages/ui): x"), &config).is_none());
+    assert!(lint_with_config(&CommitMessage::from("feat(api/v3): x"), &config).is_some());
+    assert!(lint_with_config(&CommitMessage::from("feat(packages/db): x"), &config).is_some());
+}
+
+#[test]
+fn angular_types_are_allowed() {
+    let config = ConventionalCommitConfig::angular();
+
+    for subject in ["feat: x", "fix(api): x", "revert!: x", "chore(deps): x"] {
+        let actual = lint_with_config(&CommitMessage::from(subject), &config);
+        assert!(
+            actual.is_none(),
+            "Expected {:?} to pass, found {:?}",
+            subject,
+            actual
+        );
+    }
+}
+
+#[test]
+fn types_outside_the_angular_set_are_reported() {
+    let message = "wip: x";
+
+    assert_eq!(
+        lint_with_config(
+            &CommitMessage::from(message),
+            &ConventionalCommitConfig::angular()
+        ),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::NotConventionalCommit,
+            &message.into(),
+            Some(vec![("Type not allowed".to_string(), 0_usize, 3_usize)]),
+            Some("https://www.conventionalcommits.org/".parse().unwrap()),
+        ))
+    );
+}
+
+#[test]
+fn types_can_be_restricted_to_a_custom_list() {
+    let config = ConventionalCommitConfig {
+        allowed_types: vec!["wip".into()],
+        ..ConventionalCommitConfig::default()
+    };
+
+    assert!(lint_with_config(&CommitMessage::from("wip: x"), &config).is_none());
+    assert!(lint_with_config(&CommitMessage::from("feat: x"), &config).is_some());
+}
+
+#[test]
+fn empty_descriptions_pass_by_default() {
+    assert!(lint(&CommitMessage::from("feat: \n")).is_none());
+}
+
+#[test]
+fn empty_descriptions_can_be_required() {
+    let config = ConventionalCommitConfig {
+        require_nonempty_description: true,
+        ..ConventionalCommitConfig::default()
+    };
+    let message = "feat(api): \n";
+
+    assert_eq!(
+        lint_with_config(&CommitMessage::from(message), &config),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::NotConventionalCommit,
+            &message.into(),
+            Some(vec![("Missing description".to_string(), 10_usize, 1_usize)]),
+            Some("https://www.conventionalcommits.org/".parse().unwrap()),
+        ))
+    );
+}
+
+#[test]
+fn whitespace_only_descriptions_are_empty() {
+    let config = ConventionalCommitConfig {
+        require_nonempty_description: true,
+        ..ConventionalCommitConfig::default()
+    };
+
+    assert!(lint_with_config(&CommitMessage::from("feat: \n"), &config).is_some());
+    assert!(lint_with_config(&CommitMessage::from("feat!:   \n"), &config).is_some());
+    assert!(lint_with_config(&CommitMessage::from("feat: add x\n"), &config).is_none());
+}
+
+#[test]
+fn the_breaking_change_marker_is_allowed_by_default() {
+    let config = ConventionalCommitConfig::default();
+
+    assert_eq!(config.bang_policy, BangPolicy::Allow);
+    assert!(lint_with_config(&CommitMessage::from("feat!: add x\n"), &config).is_none());
+    assert!(lint_with_config(
+        &CommitMessage::from("feat: add x\n\nBREAKING CHANGE: removes y\n"),
+        &config
+    )
+    .is_none());
+}
+
+#[test]
+fn the_breaking_change_marker_can_be_forbidden() {
+    let config = ConventionalCommitConfig {
+        bang_policy: BangPolicy::Forbid,
+        ..ConventionalCommitConfig::default()
+    };
+    let message = "feat(api)!: add x\n";
+
+    assert_eq!(
+        lint_with_config(&CommitMessage::from(message), &config),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::NotConventionalCommit,
+            &message.into(),
+            Some(vec![(
+                "Breaking change marker not allowed".to_string(),
+                9_usize,
+                1_usize
+            )]),
+            Some("https://www.conventionalcommits.org/".to_string()),
+        ))
+    );
+    assert!(lint_with_config(
+        &CommitMessage::from("feat: add x\n\nBREAKING CHANGE: removes y\n"),
+        &config
+    )
+    .is_none());
+}
+
+#[test]
+fn the_breaking_change_marker_can_be_required_for_breaking_changes() {
+    let config = ConventionalCommitConfig {
+        bang_policy: BangPolicy::RequireForBreaking,
+        ..ConventionalCommitConfig::default()
+    };
+    let message = "feat: add x\n\nBREAKING CHANGE: removes y\n";
+
+    assert_eq!(
+        lint_with_config(&CommitMessage::from(message), &config),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::NotConventionalCommit,
+            &message.into(),
+            Some(vec![(
+                "Missing `!` for breaking change".to_string(),
+                4_usize,
+                1_usize
+            )]),
+            Some("https://www.conventionalcommits.org/".to_string()),
+        ))
+    );
+    assert!(lint_with_config(
+        &CommitMessage::from("feat!: add x\n\nBREAKING-CHANGE: removes y\n"),
+        &config
+    )
+    .is_none());
+    assert!(lint_with_config(&CommitMessage::from("feat: add x\n"), &config).is_none());
+    assert!(lint_with_config(
+        &CommitMessage::from("feat: add x\n\n# BREAKING CHANGE: removes y\n"),
+        &config
+    )
+    .is_none());
+}
+
+#[test]
+fn scopes_longer_than_the_maximum_fail() {
+    let config = ConventionalCommitConfig {
+        max_scope_length: Some(8),
+        ..ConventionalCommitConfig::default()
+    };
+    let message = "feat(really_long_module_name_here): x\n";
+
+    assert_eq!(
+        lint_with_config(&CommitMessage::from(message), &config),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::NotConventionalCommit,
+            &message.into(),
+            Some(vec![("Scope too long".to_string(), 5_usize, 28_usize)]),
+            Some("https://www.conventionalcommits.org/".to_string()),
+        ))
+    );
+}
+
+#[test]
+fn scopes_up_to_the_maximum_pass() {
+    let config = ConventionalCommitConfig {
+        max_scope_length: Some(3),
+        ..ConventionalCommitConfig::default()
+    };
+
+    assert!(lint_with_config(&CommitMessage::from("feat(api): x\n"), &config).is_none());
+    assert!(lint_with_config(&CommitMessage::from("feat(café)!: x\n"), &config).is_some());
+    assert!(lint_with_config(&CommitMessage::from("feat: x\n"), &config).is_none());
+}
+
+#[test]
+fn scope_lengths_are_not_limited_by_default() {
+    assert!(lint(&CommitMessage::from(
+        "feat(really_long_module_name_here): x\n"
+    ))
+    .is_none());
+}
+
+#[test]
+fn the_maximum_scope_length_applies_to_allowed_scopes() {
+    let config = ConventionalCommitConfig {
+        allowed_scopes: vec!["api".to_string(), "really_long_module_name".to_string()],
+        max_scope_length: Some(8),
+        ..ConventionalCommitConfig::default()
+    };
+    let problem_label = |message: &str| {
+        lint_with_config(&CommitMessage::from(message), &config)
+            .map(|problem| problem.structured_labels()[0].text.clone())
+    };
+
+    assert_eq!(problem_label("feat(api): x\n"), None);
+    assert_eq!(
+        problem_label("feat(really_long_module_name): x\n"),
+        Some("Scope too long".to_string())
+    );
+    assert_eq!(
+        problem_label("feat(cli): x\n"),
+        Some("Not conventional".to_string())
+    );
+}
+
+#[test]
+fn uppercase_descriptions_fail_when_lowercase_is_required() {
+    let config = ConventionalCommitConfig {
+        description_case: Some(DescriptionCase::Lower),
+        ..ConventionalCommitConfig::default()
+    };
+    let message = "feat(api): Add x\n";
+
+    assert_eq!(
+        lint_with_config(&CommitMessage::from(message), &config),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::NotConventionalCommit,
+            &message.into(),
+            Some(vec![("Not lowercase".to_string(), 11_usize, 1_usize)]),
+            Some("https://www.conventionalcommits.org/".to_string()),
+        ))
+    );
+    assert!(lint_with_config(&CommitMessage::from("feat: add x\n"), &config).is_none());
+}
+
+#[test]
+fn lowercase_descriptions_fail_when_uppercase_is_required() {
+    let config = ConventionalCommitConfig {
+        description_case: Some(DescriptionCase::Upper),
+        ..ConventionalCommitConfig::default()
+    };
+    let message = "feat: état\n";
+
+    assert_eq!(
+        lint_with_config(&CommitMessage::from(message), &config),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::NotConventionalCommit,
+            &message.into(),
+            Some(vec![("Not uppercase".to_string(), 6_usize, 2_usize)]),
+            Some("https://www.conventionalcommits.org/".to_string()),
+        ))
+    );
+    assert!(lint_with_config(&CommitMessage::from("feat: Add x\n"), &config).is_none());
+}
+
+#[test]
+fn descriptions_not_starting_with_a_letter_pass_either_case() {
+    for description_case in [DescriptionCase::Lower, DescriptionCase::Upper]
+        .iter()
+        .copied()
+    {
+        let config = ConventionalCommitConfig {
+            description_case: Some(description_case),
+            ..ConventionalCommitConfig::default()
+        };
+
+        assert!(lint_with_config(&CommitMessage::from("feat: 2fa login\n"), &config).is_none());
+    }
+}
+
+#[test]
+fn description_case_is_not_checked_by_default() {
+    assert!(lint(&CommitMessage::from("feat: Add x\n")).is_none());
+    assert!(lint(&CommitMessage::from("feat: add x\n")).is_none());
+}
+
+#[test]
+fn missing_scopes_fail_when_a_scope_is_required() {
+    let config = ConventionalCommitConfig {
+        require_scope: true,
+        ..ConventionalCommitConfig::default()
+    };
+    let message = "feat!: x\n";
+
+    assert_eq!(
+        lint_with_config(&CommitMessage::from(message), &config),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::NotConventionalCommit,
+            &message.into(),
+            Some(vec![("Missing scope".to_string(), 0_usize, 6_usize)]),
+            Some("https://www.conventionalcommits.org/".to_string()),
+        ))
+    );
+    assert!(lint_with_config(&CommitMessage::from("feat(api): x\n"), &config).is_none());
+}
+
+#[test]
+fn scopes_are_not_required_by_default() {
+    assert!(lint(&CommitMessage::from("feat: x\n")).is_none());
+}
+
+#[test]
+fn required_scopes_must_still_be_allowed() {
+    let config = ConventionalCommitConfig {
+        require_scope: true,
+        allowed_scopes: vec!["api".to_string()],
+        ..ConventionalCommitConfig::default()
+    };
+    let problem_label = |message: &str| {
+        lint_with_config(&CommitMessage::from(message), &config)
+            .map(|problem| problem.structured_labels()[0].text.clone())
+    };
+
+    assert_eq!(problem_label("feat(api): x\n"), None);
+    assert_eq!(
+        problem_label("feat: x\n"),
+        Some("Missing scope".to_string())
+    );
+    assert_eq!(
+        problem_label("feat(cli): x\n"),
+        Some("Not conventional".to_string())
+    );
+}
+
 fn test_subject_not_separate_from_body(message: &str, expected: Option<&Problem>) {
     let actual = &lint(&CommitMessage::from(message));
     assert_eq!(
@@ -193,6 +619,60 @@ This is an example commit
     );
 }
 
+#[test]
+fn multibyte_subjects_are_labelled_in_bytes() {
+    let message = "🎉 An example commit\n\nThis is an example commit\n";
+    test_subject_not_separate_from_body(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::NotConventionalCommit,
+            &message.into(),
+            Some(vec![("Not conventional".to_string(), 0_usize, 22_usize)]),
+            Some("https://www.conventionalcommits.org/".parse().unwrap()),
+        )),
+    );
+}
+
+#[test]
+fn formatting_multibyte_subjects_underlines_only_the_subject() {
+    let message = "🎉 An example commit
+
+This is an example commit
+";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "NotConventionalCommit (https://www.conventionalcommits.org/)
+
+  x Your commit message isn't in conventional style
+   ,-[1:1]
+ 1 | 🎉 An example commit
+   : ^^^^^^^^^^|^^^^^^^^^
+   :           `-- Not conventional
+ 2 | 
+   `----
+  help: It's important to follow the conventional commit style when creating
+        your commit message. By using this style we can automatically
+        calculate the version of software using deployment pipelines, and also
+        generate changelogs and other useful information without human
+        interaction.
+        
+        You can fix it by following style
+        
+        <type>[optional scope]: <description>
+        
+        [optional body]
+        
+        [optional footer(s)]
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
 fn fmt_report(diag: &Report) -> String {
     let mut out = String::new();
     GraphicalReportHandler::new_themed(GraphicalTheme::none())
@@ -210,10 +690,16 @@ fn fail_check(commit: String) -> TestResult {
         .chars()
         .position(|x| x == ':')
         .is_some_and(|x| commit.chars().take(x).any(|x| !x.is_ascii_alphanumeric()));
-    if has_non_alpha_type {
+    let is_conventional = commit
+        .split_once(':')
+        .is_some_and(|(commit_type, rest)| !commit_type.is_empty() && rest.starts_with(' '));
+    if has_non_alpha_type || is_conventional {
         return TestResult::discard();
     }
     let message = CommitMessage::from(format!("{commit}\n# comment"));
+    if is_empty_message(&message, message.get_comment_char()) {
+        return TestResult::discard();
+    }
     let result = lint(&message);
     TestResult::from_bool(result.is_some())
 }
@@ -1,11 +1,17 @@
+//! Check for commits following the emoji log standard
+
 use std::option::Option::None;
 
 use mit_commit::CommitMessage;
+#[cfg(any(test, feature = "quickcheck"))]
 use quickcheck::{Arbitrary, Gen};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
-use crate::model::{Code, Problem};
+use crate::{
+    checks::comments::is_empty_message,
+    model::{Code, Problem},
+};
 
 /// Canonical lint ID
 pub const CONFIG: &str = "not-emoji-log";
@@ -27,13 +33,70 @@ You can fix it using one of the prefixes:
 /// Description of the problem
 pub const ERROR: &str = "Your commit message isn't in emoji log style";
 
+/// Configuration for how strictly to match the emoji log prefix
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NotEmojiLogConfig {
+    /// The characters that may separate the prefix from the description, like
+    /// the `:` in `🐛 FIX: x` or the `-` in `🐛 FIX - x`
+    pub separators: Vec<char>,
+    /// Treat an emoji with a variation selector (`\u{FE0F}`) the same as one
+    /// without
+    pub tolerate_variation_selectors: bool,
+}
+
+impl Default for NotEmojiLogConfig {
+    fn default() -> Self {
+        Self {
+            separators: vec![':'],
+            tolerate_variation_selectors: false,
+        }
+    }
+}
+
+const VARIATION_SELECTOR: char = '\u{fe0f}';
+
+fn has_prefix(subject: &str, prefix: Prefix, config: &NotEmojiLogConfig) -> bool {
+    let Some((emoji, rest)) = subject.split_once(' ') else {
+        return false;
+    };
+    let matches_emoji = if config.tolerate_variation_selectors {
+        emoji.replace(VARIATION_SELECTOR, "") == prefix.emoji().replace(VARIATION_SELECTOR, "")
+    } else {
+        emoji == prefix.emoji()
+    };
+    let Some(rest) = rest.strip_prefix(prefix.name()) else {
+        return false;
+    };
+    let rest = rest.strip_prefix(' ').unwrap_or(rest);
+
+    matches_emoji
+        && config.separators.iter().any(|separator| {
+            rest.strip_prefix(*separator)
+                .is_some_and(|description| description.starts_with(' '))
+        })
+}
+
+/// Lint the commit message, returning a [`Problem`] if the subject doesn't
+/// start with an emoji log prefix
 pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
-    if Prefix::iter().any(|x| {
-        commit_message
-            .get_subject()
-            .to_string()
-            .starts_with(&String::from(x))
-    }) {
+    lint_with_config(commit_message, &NotEmojiLogConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if the subject doesn't start with an emoji log prefix
+///
+/// The separator may have a single space before it, so both `🐛 FIX: x` and
+/// `🐛 FIX - x` are matched when `-` is one of the separators.
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &NotEmojiLogConfig,
+) -> Option<Problem> {
+    if is_empty_message(commit_message, commit_message.get_comment_char()) {
+        return None;
+    }
+
+    let subject = commit_message.get_subject().to_string();
+    if Prefix::iter().any(|prefix| has_prefix(&subject, prefix, config)) {
         None
     } else {
         let commit_text = String::from(commit_message.clone());
@@ -52,31 +115,58 @@ pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
     }
 }
 
+/// The prefixes that are valid in emoji log style
 #[derive(Clone, Copy, Eq, PartialEq, Debug, EnumIter)]
 pub enum Prefix {
+    /// A bug fix
     Fix,
+    /// A new feature
     New,
+    /// An improvement to existing code
     Improve,
+    /// Documentation changes
     Doc,
+    /// A new release
     Release,
+    /// Test changes
     Test,
+    /// A breaking change
     Breaking,
 }
 
+impl Prefix {
+    const fn emoji(self) -> &'static str {
+        match self {
+            Self::Fix => "\u{1f41b}",
+            Self::New => "\u{1f4e6}",
+            Self::Improve => "\u{1f44c}",
+            Self::Doc => "\u{1f4d6}",
+            Self::Release => "\u{1f680}",
+            Self::Test => "\u{1f916}",
+            Self::Breaking => "\u{203c}\u{fe0f}",
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Fix => "FIX",
+            Self::New => "NEW",
+            Self::Improve => "IMPROVE",
+            Self::Doc => "DOC",
+            Self::Release => "RELEASE",
+            Self::Test => "TEST",
+            Self::Breaking => "BREAKING",
+        }
+    }
+}
+
 impl From<Prefix> for String {
     fn from(input: Prefix) -> Self {
-        match input {
-            Prefix::Fix => Self::from("\u{1f41b} FIX: "),
-            Prefix::New => Self::from("\u{1f4e6} NEW: "),
-            Prefix::Improve => Self::from("\u{1f44c} IMPROVE: "),
-            Prefix::Doc => Self::from("\u{1f4d6} DOC: "),
-            Prefix::Release => Self::from("\u{1f680} RELEASE: "),
-            Prefix::Test => Self::from("\u{1f916} TEST: "),
-            Prefix::Breaking => Self::from("\u{203c}\u{fe0f} BREAKING: "),
-        }
+        format!("{} {}: ", input.emoji(), input.name())
     }
 }
 
+#[cfg(any(test, feature = "quickcheck"))]
 impl Arbitrary for Prefix {
     fn arbitrary(g: &mut Gen) -> Self {
         *g.choose(&Self::iter().collect::<Vec<_>>()).unwrap()
@@ -5,9 +5,9 @@ use mit_commit::CommitMessage;
 use quickcheck::TestResult;
 use strum::IntoEnumIterator;
 
-use super::not_emoji_log::{lint, ERROR, HELP_MESSAGE};
+use super::not_emoji_log::{lint, lint_with_config, NotEmojiLogConfig, ERROR, HELP_MESSAGE};
 use crate::{
-    checks::not_emoji_log::Prefix,
+    checks::{comments::is_empty_message, not_emoji_log::Prefix},
     model::{Code, Problem},
 };
 
@@ -107,6 +107,51 @@ This is an example commit
     );
 }
 
+#[test]
+fn other_separators_can_be_allowed() {
+    let message = CommitMessage::from("\u{1f41b} FIX - An example commit\n");
+
+    assert!(lint(&message).is_some());
+    assert!(lint_with_config(
+        &message,
+        &NotEmojiLogConfig {
+            separators: vec![':', '-'],
+            tolerate_variation_selectors: false,
+        }
+    )
+    .is_none());
+}
+
+#[test]
+fn variation_selectors_can_be_tolerated() {
+    let message = CommitMessage::from("\u{1f41b}\u{fe0f} FIX: An example commit\n");
+
+    assert!(lint(&message).is_some());
+    assert!(lint_with_config(
+        &message,
+        &NotEmojiLogConfig {
+            separators: vec![':'],
+            tolerate_variation_selectors: true,
+        }
+    )
+    .is_none());
+}
+
+#[test]
+fn tolerating_variation_selectors_allows_them_to_be_missing() {
+    let message = CommitMessage::from("\u{203c} BREAKING: An example commit\n");
+
+    assert!(lint(&message).is_some());
+    assert!(lint_with_config(
+        &message,
+        &NotEmojiLogConfig {
+            separators: vec![':'],
+            tolerate_variation_selectors: true,
+        }
+    )
+    .is_none());
+}
+
 #[test]
 fn unknown_emoji() {
     let message = "\u{1f408} UNKNOWN: An example commit
@@ -290,6 +335,9 @@ fn fail_check(commit: String) -> TestResult {
     }
 
     let message = CommitMessage::from(commit);
+    if is_empty_message(&message, message.get_comment_char()) {
+        return TestResult::discard();
+    }
     let result = lint(&message);
     TestResult::from_bool(result.is_some())
 }
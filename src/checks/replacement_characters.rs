@@ -0,0 +1,60 @@
+//! Check for replacement characters left behind by a lossy encoding
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{paragraphs, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "replacement-characters";
+/// Description of the problem
+pub const ERROR: &str = "Your commit message has replacement characters in it";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "A `\u{FFFD}` is what's left when text is read with the wrong \
+                            encoding, so some of the message has been lost. This usually means \
+                            an editor or a tool in a hook isn't using UTF-8.\n\nYou can fix this \
+                            by retyping the damaged text, and checking the encoding your editor \
+                            and hooks use";
+
+const REPLACEMENT_CHARACTER: char = '\u{FFFD}';
+
+/// Lint the commit message, returning a [`Problem`] labelling the first
+/// replacement character in the subject or body
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_comment_char(commit_message, commit_message.get_comment_char())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    let commit_text = text_above_scissors(commit_message);
+    let offset = paragraphs(&commit_text, comment_char)
+        .into_iter()
+        .flatten()
+        .find_map(|(offset, line)| {
+            line.find(REPLACEMENT_CHARACTER)
+                .map(|position| offset + position)
+        })?;
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::ReplacementCharacters,
+        commit_message,
+        Some(vec![(
+            "Replacement character".to_string(),
+            offset,
+            REPLACEMENT_CHARACTER.len_utf8(),
+        )]),
+        None,
+    ))
+}
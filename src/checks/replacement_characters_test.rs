@@ -0,0 +1,120 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::replacement_characters::{lint, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+#[test]
+fn clean_message_passes() {
+    run_test(
+        "Update the caf\u{E9} menu\n\nIt has cr\u{E8}me br\u{FB}l\u{E9}e now.\n",
+        None,
+    );
+}
+
+#[test]
+fn replacement_characters_in_comments_pass() {
+    run_test("Update the menu\n\n# On branch caf\u{FFFD}\n", None);
+}
+
+#[test]
+fn replacement_character_in_the_subject_fails() {
+    let message = "Update the caf\u{FFFD} menu\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::ReplacementCharacters,
+            &message.into(),
+            Some(vec![("Replacement character".to_string(), 14, 3)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn the_first_replacement_character_in_the_body_is_labelled() {
+    let message = "Update the menu\n\nIt has cr\u{FFFD}me br\u{FFFD}l\u{FFFD}e now.\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::ReplacementCharacters,
+            &message.into(),
+            Some(vec![("Replacement character".to_string(), 26, 3)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "Update the caf\u{FFFD} menu\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "ReplacementCharacters
+
+  x Your commit message has replacement characters in it
+   ,----
+ 1 | Update the caf\u{FFFD} menu
+   :               |
+   :               `-- Replacement character
+   `----
+  help: A `\u{FFFD}` is what's left when text is read with the wrong encoding, so
+        some of the message has been lost. This usually means an editor or a
+        tool in a hook isn't using UTF-8.
+        
+        You can fix this by retyping the damaged text, and checking the
+        encoding your editor and hooks use
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(message: String) -> TestResult {
+    if message.contains('\u{FFFD}') {
+        return TestResult::discard();
+    }
+    let result = lint(&CommitMessage::from(message));
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(before: String, after: String) -> TestResult {
+    if before.contains('\n') || before.contains('#') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Update {before}\u{FFFD}{after}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
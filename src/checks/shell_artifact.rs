@@ -0,0 +1,99 @@
+//! Check for shell quoting mistakes left behind in the subject
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{paragraphs, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "shell-artifact-in-subject";
+/// Description of the problem
+pub const ERROR: &str = "Your subject looks like it has part of a shell command in it";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "When the quotes around a `git commit -m` message don't match up, \
+                            the shell can pass the next option, or a stray quote, to git as part \
+                            of the subject.\n\nYou can fix this by amending the commit with the \
+                            subject you meant to write";
+
+const MESSAGE_OPTIONS: [&str; 2] = ["-m", "--message"];
+
+fn words(subject: &str) -> impl Iterator<Item = (usize, &str)> {
+    subject
+        .split_inclusive(char::is_whitespace)
+        .scan(0, |offset, word| {
+            let start = *offset;
+            *offset += word.len();
+            Some((start, word.trim_end()))
+        })
+        .filter(|(_, word)| !word.is_empty())
+}
+
+/// Find a `-m` or `--message` option, on its own or followed by a quote or
+/// `=`
+fn find_option(subject: &str) -> Option<(String, usize, usize)> {
+    words(subject).find_map(|(offset, word)| {
+        MESSAGE_OPTIONS.iter().find_map(|option| {
+            let rest = word.strip_prefix(option)?;
+            (rest.is_empty() || rest.starts_with(['"', '\'', '=']))
+                .then(|| ("Shell option".to_string(), offset, option.len()))
+        })
+    })
+}
+
+/// Find a double quote at the start or end of the subject that has no pair
+fn find_unbalanced_quote(subject: &str) -> Option<(String, usize, usize)> {
+    if subject.matches('"').count().is_multiple_of(2) {
+        return None;
+    }
+
+    let offset = if subject.starts_with('"') {
+        0
+    } else if subject.ends_with('"') {
+        subject.len() - 1
+    } else {
+        return None;
+    };
+
+    Some(("Unbalanced quote".to_string(), offset, 1))
+}
+
+/// Lint the commit message, returning a [`Problem`] labelling a `-m` option
+/// or a stray quote in the subject
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_comment_char(commit_message, commit_message.get_comment_char())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+///
+/// This is conservative to avoid flagging subjects that are fine. Only `-m`
+/// and `--message` as whole words, and a double quote with no pair at the
+/// very start or end of the subject, are flagged.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    let commit_text = text_above_scissors(commit_message);
+    let (subject_offset, subject) = paragraphs(&commit_text, comment_char)
+        .into_iter()
+        .flatten()
+        .next()?;
+    let subject = subject.trim_end();
+    let (label, offset, length) =
+        find_option(subject).or_else(|| find_unbalanced_quote(subject))?;
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::ShellArtifactInSubject,
+        commit_message,
+        Some(vec![(label, subject_offset + offset, length)]),
+        None,
+    ))
+}
@@ -0,0 +1,168 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::shell_artifact::{lint, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+#[test]
+fn normal_subjects_pass() {
+    run_test("Fix the login bug\n", None);
+}
+
+#[test]
+fn options_inside_words_pass() {
+    run_test("Add a -more flag and the --messages command\n", None);
+}
+
+#[test]
+fn balanced_quotes_pass() {
+    run_test("Revert \"Revert \"Add login\"\"\n", None);
+    run_test("\"Quote\" the subject\n", None);
+}
+
+#[test]
+fn quotes_inside_the_subject_pass() {
+    run_test("Add a 12\" screen size\n", None);
+}
+
+#[test]
+fn a_message_option_fails() {
+    let message = "Fix bug -m more\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::ShellArtifactInSubject,
+            &message.into(),
+            Some(vec![("Shell option".to_string(), 8, 2)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn a_quoted_long_message_option_fails() {
+    let message = "# A comment\nFix bug --message=\"more\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::ShellArtifactInSubject,
+            &message.into(),
+            Some(vec![("Shell option".to_string(), 20, 9)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn an_unbalanced_quote_at_the_end_fails() {
+    let message = "Fix bug\"\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::ShellArtifactInSubject,
+            &message.into(),
+            Some(vec![("Unbalanced quote".to_string(), 7, 1)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn an_unbalanced_quote_at_the_start_fails() {
+    let message = "\"Fix bug\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::ShellArtifactInSubject,
+            &message.into(),
+            Some(vec![("Unbalanced quote".to_string(), 0, 1)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn the_body_is_not_checked() {
+    run_test("Fix bug\n\nRun it with -m \"message\" to see\n", None);
+}
+
+#[test]
+fn formatting() {
+    let message = "Fix bug -m more\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "ShellArtifactInSubject
+
+  x Your subject looks like it has part of a shell command in it
+   ,----
+ 1 | Fix bug -m more
+   :         ^|
+   :          `-- Shell option
+   `----
+  help: When the quotes around a `git commit -m` message don't match up, the
+        shell can pass the next option, or a stray quote, to git as part of
+        the subject.
+        
+        You can fix this by amending the commit with the subject you meant to
+        write
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(words: Vec<String>) -> TestResult {
+    let words: Vec<String> = words
+        .iter()
+        .map(|word| word.chars().filter(|c| c.is_alphanumeric()).collect())
+        .filter(|word: &String| !word.is_empty())
+        .collect();
+    let message = CommitMessage::from(format!("Fix {}\n", words.join(" ")));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(before: String, after: String) -> TestResult {
+    if before.contains(['\n', '\r']) || after.contains(['\n', '\r']) {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Fix {before} -m {after}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
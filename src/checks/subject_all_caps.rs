@@ -0,0 +1,78 @@
+//! Check for a subject written in all capital letters
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::not_conventional_commit::conventional_prefix_length,
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "subject-all-caps";
+/// Description of the problem
+pub const ERROR: &str = "Your subject is in all capital letters";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "A subject in all capital letters reads as shouting, and looks out \
+                            of place next to the other subjects in the log or a \
+                            changelog.\n\nYou can fix this by writing the subject in sentence \
+                            case, keeping capitals for acronyms";
+
+/// Configuration for how many letters a subject needs before it's shouting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubjectAllCapsConfig {
+    /// The fewest letters a subject needs before we complain, so subjects
+    /// that are only an acronym, like `CI`, aren't flagged
+    pub min_letters: usize,
+}
+
+impl Default for SubjectAllCapsConfig {
+    fn default() -> Self {
+        Self { min_letters: 4 }
+    }
+}
+
+fn find_shouting(subject: &str, config: &SubjectAllCapsConfig) -> Option<(usize, usize)> {
+    let start = conventional_prefix_length(subject).unwrap_or_default();
+    let description = subject[start..].trim();
+    let letters: Vec<char> = description
+        .chars()
+        .filter(|character| character.is_uppercase() || character.is_lowercase())
+        .collect();
+
+    if letters.len() < config.min_letters || letters.iter().any(|letter| !letter.is_uppercase()) {
+        return None;
+    }
+
+    Some((
+        subject.len() - subject[start..].trim_start().len(),
+        description.len(),
+    ))
+}
+
+/// Lint the commit message, returning a [`Problem`] if the subject is in all
+/// capital letters
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &SubjectAllCapsConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if the subject is in all capital letters
+///
+/// Any conventional commit prefix is ignored, as are letters without a case,
+/// like those in Chinese.
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &SubjectAllCapsConfig,
+) -> Option<Problem> {
+    let subject = commit_message.get_subject().to_string();
+    let (offset, length) = find_shouting(&subject, config)?;
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::SubjectAllCaps,
+        commit_message,
+        Some(vec![("Shouting".to_string(), offset, length)]),
+        Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+    ))
+}
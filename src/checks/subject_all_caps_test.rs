@@ -0,0 +1,129 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::subject_all_caps::{lint, lint_with_config, SubjectAllCapsConfig, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+#[test]
+fn sentence_case_passes() {
+    run_test("Fix the LOGIN bug\n\nSome body content\n", None);
+}
+
+#[test]
+fn short_acronym_passes() {
+    run_test("CI\n", None);
+}
+
+#[test]
+fn shouting_fails() {
+    let message = "FIX THE LOGIN BUG\n\nSome body content\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectAllCaps,
+            &message.into(),
+            Some(vec![("Shouting".to_string(), 0, 17)]),
+            Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn conventional_prefix_is_ignored() {
+    let message = "fix(auth): FIX THE LOGIN BUG\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectAllCaps,
+            &message.into(),
+            Some(vec![("Shouting".to_string(), 11, 17)]),
+            Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+        )),
+    );
+    run_test("fix(auth): Fix the LOGIN bug\n", None);
+}
+
+#[test]
+fn minimum_letters_can_be_configured() {
+    let message = CommitMessage::from("FIX CI\n");
+
+    assert!(lint_with_config(&message, &SubjectAllCapsConfig { min_letters: 5 }).is_some());
+    assert!(lint_with_config(&message, &SubjectAllCapsConfig { min_letters: 6 }).is_none());
+}
+
+#[test]
+fn formatting() {
+    let message = "FIX THE LOGIN BUG\n\nSome body content\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "SubjectAllCaps \
+                    (https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines)
+
+  x Your subject is in all capital letters
+   ,-[1:1]
+ 1 | FIX THE LOGIN BUG
+   : ^^^^^^^^|^^^^^^^^
+   :         `-- Shouting
+ 2 | 
+   `----
+  help: A subject in all capital letters reads as shouting, and looks out of
+        place next to the other subjects in the log or a changelog.
+        
+        You can fix this by writing the subject in sentence case, keeping
+        capitals for acronyms
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(subject: String) -> TestResult {
+    if subject.contains('\n') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Fix {subject}"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(subject: String) -> TestResult {
+    let subject = subject.to_uppercase();
+    if subject.contains('\n') || subject.chars().any(char::is_lowercase) {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("SHOUTING {subject}"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -0,0 +1,138 @@
+//! Check the subject is written in the configured case style
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::{
+        comments::subject_offset_in_text,
+        not_conventional_commit::conventional_prefix_length,
+    },
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "subject-case-style";
+/// Description of the problem
+pub const ERROR: &str = "Your subject isn't in the expected case style";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Subjects that all use the same case style read well together in \
+                            the log and in changelogs.\n\nYou can fix this by capitalising only \
+                            the first word of the subject, keeping capitals for acronyms and \
+                            names";
+
+const SENTENCE_ENDINGS: [char; 4] = ['.', ':', '!', '?'];
+const QUOTES: [char; 3] = ['`', '\'', '"'];
+
+/// The case style a subject should be written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaseStyle {
+    /// Only the first word is capitalised, like `Add login page`
+    SentenceCase,
+    /// The first word is capitalised, and any others may be, like
+    /// `Add Login Page`
+    AnyCapitalized,
+}
+
+/// Configuration for which case style subjects should be written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubjectCaseStyleConfig {
+    /// The style to expect
+    pub style: CaseStyle,
+}
+
+impl Default for SubjectCaseStyleConfig {
+    fn default() -> Self {
+        Self {
+            style: CaseStyle::SentenceCase,
+        }
+    }
+}
+
+/// Check if a word looks like it's only capitalised for title case
+///
+/// Acronyms like `API`, names with capitals inside like `GitHub`, and words
+/// with digits or symbols, like `Cargo.toml`, are left alone.
+fn is_title_cased(word: &str) -> bool {
+    let word = word
+        .trim_start_matches('(')
+        .trim_end_matches(|c: char| c.is_ascii_punctuation());
+    let mut characters = word.chars();
+
+    word.chars().count() > 1
+        && word.chars().all(char::is_alphabetic)
+        && characters.next().is_some_and(char::is_uppercase)
+        && characters.all(char::is_lowercase)
+}
+
+fn words(subject: &str) -> impl Iterator<Item = (usize, &str)> {
+    subject
+        .split_inclusive(char::is_whitespace)
+        .scan(0, |offset, word| {
+            let start = *offset;
+            *offset += word.len();
+            Some((start, word.trim_end()))
+        })
+        .filter(|(_, word)| !word.is_empty())
+}
+
+fn find_problem(subject: &str, config: &SubjectCaseStyleConfig) -> Option<(String, usize, usize)> {
+    let start = conventional_prefix_length(subject).unwrap_or_default();
+    let mut words = words(&subject[start..]).map(|(offset, word)| (offset + start, word));
+
+    let (offset, first_word) = words.next()?;
+    if first_word.starts_with(char::is_lowercase) {
+        return Some((
+            "Not capitalised".to_string(),
+            offset,
+            first_word.chars().next().map_or(1, char::len_utf8),
+        ));
+    }
+    if config.style == CaseStyle::AnyCapitalized {
+        return None;
+    }
+
+    let mut previous = first_word;
+    words.find_map(|(offset, word)| {
+        let new_sentence = previous.ends_with(SENTENCE_ENDINGS);
+        previous = word;
+        (!new_sentence && !word.starts_with(QUOTES) && is_title_cased(word))
+            .then(|| ("Not sentence case".to_string(), offset, word.len()))
+    })
+}
+
+/// Lint the commit message, returning a [`Problem`] if the subject isn't in
+/// sentence case
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &SubjectCaseStyleConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] labelling the first word that doesn't fit the configured style
+///
+/// Both styles need the first word to be capitalised. Sentence case also
+/// flags later words that are capitalised, which is a heuristic: acronyms,
+/// names with capitals inside them, quoted words and words starting a new
+/// sentence are skipped, but a name like `Linux` will be flagged. Any
+/// conventional commit prefix is ignored.
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &SubjectCaseStyleConfig,
+) -> Option<Problem> {
+    let subject = commit_message.get_subject().to_string();
+    let subject = subject.split('\n').next().unwrap_or_default();
+    let (label, offset, length) = find_problem(subject, config)?;
+    let offset = subject_offset_in_text(
+        &String::from(commit_message.clone()),
+        commit_message.get_comment_char(),
+        offset,
+    );
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::SubjectCaseStyle,
+        commit_message,
+        Some(vec![(label, offset, length)]),
+        Some("https://cbea.ms/git-commit/#capitalize".to_string()),
+    ))
+}
@@ -0,0 +1,189 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::subject_case_style::{
+    lint,
+    lint_with_config,
+    CaseStyle,
+    SubjectCaseStyleConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+#[test]
+fn sentence_case_passes() {
+    run_test("Add login page\n", None);
+}
+
+#[test]
+fn acronyms_and_names_pass() {
+    run_test("Add API docs for GitHub and iOS users\n", None);
+}
+
+#[test]
+fn quoted_words_pass() {
+    run_test("Rename `Foo` to \"Bar\"\n", None);
+}
+
+#[test]
+fn new_sentences_pass() {
+    run_test("Add login page. Remove the old one\n", None);
+}
+
+#[test]
+fn conventional_prefix_is_ignored() {
+    run_test("feat(auth): Add login page\n", None);
+}
+
+#[test]
+fn comment_only_message_passes() {
+    run_test("# Some Comment\n", None);
+}
+
+#[test]
+fn title_case_fails() {
+    let message = "Add Login Page\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectCaseStyle,
+            &message.into(),
+            Some(vec![("Not sentence case".to_string(), 4, 5)]),
+            Some("https://cbea.ms/git-commit/#capitalize".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn lowercase_first_word_fails() {
+    let message = "feat: add login page\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectCaseStyle,
+            &message.into(),
+            Some(vec![("Not capitalised".to_string(), 6, 1)]),
+            Some("https://cbea.ms/git-commit/#capitalize".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn comments_before_the_subject_are_skipped_over() {
+    let message = "# İ\nıx login page\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectCaseStyle,
+            &message.into(),
+            Some(vec![("Not capitalised".to_string(), 5, 2)]),
+            Some("https://cbea.ms/git-commit/#capitalize".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn any_capitalized_allows_title_case() {
+    let config = SubjectCaseStyleConfig {
+        style: CaseStyle::AnyCapitalized,
+    };
+
+    assert!(lint_with_config(&CommitMessage::from("Add Login Page\n"), &config).is_none());
+    assert!(lint_with_config(&CommitMessage::from("add login page\n"), &config).is_some());
+}
+
+#[test]
+fn formatting() {
+    let message = "Add Login Page\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "SubjectCaseStyle (https://cbea.ms/git-commit/#capitalize)
+
+  x Your subject isn't in the expected case style
+   ,----
+ 1 | Add Login Page
+   :     ^^|^^
+   :       `-- Not sentence case
+   `----
+  help: Subjects that all use the same case style read well together in the
+        log and in changelogs.
+        
+        You can fix this by capitalising only the first word of the subject,
+        keeping capitals for acronyms and names
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(words: Vec<String>) -> TestResult {
+    let words: Vec<String> = words
+        .iter()
+        .map(|word| word.chars().filter(|c| c.is_lowercase()).collect())
+        .collect();
+    let message = CommitMessage::from(format!("Add {}\n", words.join(" ")));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(words: Vec<String>) -> TestResult {
+    let words: Vec<String> = words
+        .iter()
+        .map(|word| word.chars().filter(|c| c.is_lowercase()).collect())
+        .collect();
+    let message = CommitMessage::from(format!("Add {} Login\n", words.join(" ")));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn labels_are_on_whole_characters(text: String) -> TestResult {
+    let message = CommitMessage::from(text);
+    let Some(problem) = lint(&message) else {
+        return TestResult::discard();
+    };
+    let commit_text = String::from(message);
+    TestResult::from_bool(problem.structured_labels().iter().all(|label| {
+        commit_text
+            .get(label.offset..label.offset + label.length)
+            .is_some_and(|word| {
+                label.text != "Not capitalised" || word.starts_with(char::is_lowercase)
+            })
+    }))
+}
@@ -0,0 +1,120 @@
+//! Check for the subject repeated on a line of the body
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{paragraphs, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "subject-echoed-in-body";
+/// Description of the problem
+pub const ERROR: &str = "Your commit has the subject repeated in the body";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "A copy of the subject in the body is usually left over from \
+                            pasting, and tells the reader nothing they haven't already \
+                            read.\n\nYou can fix this by removing the repeated line, or replacing \
+                            it with an explanation of why the change was made";
+
+/// Configuration for how the subject is matched against the body
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SubjectEchoedInBodyConfig {
+    /// Match lines that only differ from the subject in case
+    pub ignore_case: bool,
+}
+
+/// Lint the commit message, returning a [`Problem`] labelling every line of
+/// the body that's the same as the subject
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &SubjectEchoedInBodyConfig::default())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    lint_with_options(
+        commit_message,
+        comment_char,
+        &SubjectEchoedInBodyConfig::default(),
+    )
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] labelling every line of the body that's the same as the
+/// subject
+///
+/// Whitespace at the start and end of the lines is ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::checks::subject_echoed_in_body::{lint_with_config, SubjectEchoedInBodyConfig};
+///
+/// let message = CommitMessage::from("Add login\n\nSome body\n\nadd LOGIN\n");
+/// assert!(lint_with_config(&message, &SubjectEchoedInBodyConfig::default()).is_none());
+/// assert!(lint_with_config(&message, &SubjectEchoedInBodyConfig { ignore_case: true }).is_some());
+/// ```
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &SubjectEchoedInBodyConfig,
+) -> Option<Problem> {
+    lint_with_options(commit_message, commit_message.get_comment_char(), config)
+}
+
+fn lint_with_options(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+    config: &SubjectEchoedInBodyConfig,
+) -> Option<Problem> {
+    let commit_text = text_above_scissors(commit_message);
+    let paragraphs = paragraphs(&commit_text, comment_char);
+    let (_, subject) = paragraphs.first()?.first()?;
+    let subject = subject.trim();
+    if subject.is_empty() {
+        return None;
+    }
+
+    let labels: Vec<(String, usize, usize)> = paragraphs
+        .iter()
+        .skip(1)
+        .flatten()
+        .filter_map(|(offset, line)| {
+            let trimmed = line.trim();
+            let is_echo = if config.ignore_case {
+                trimmed.to_lowercase() == subject.to_lowercase()
+            } else {
+                trimmed == subject
+            };
+
+            is_echo.then(|| {
+                let start = line.len() - line.trim_start().len();
+                (
+                    "Repeats the subject".to_string(),
+                    offset + start,
+                    trimmed.len(),
+                )
+            })
+        })
+        .collect();
+    if labels.is_empty() {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::SubjectEchoedInBody,
+        commit_message,
+        Some(labels),
+        None,
+    ))
+}
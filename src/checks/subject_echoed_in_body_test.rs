@@ -0,0 +1,175 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::subject_echoed_in_body::{
+    lint,
+    lint_with_config,
+    SubjectEchoedInBodyConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+#[test]
+fn subject_only_passes() {
+    run_test("Add login\n", None);
+}
+
+#[test]
+fn a_body_without_the_subject_passes() {
+    run_test(
+        "Add login
+
+Add a login form to the home page, so people can get to their
+account without going through the settings.
+",
+        None,
+    );
+}
+
+#[test]
+fn a_body_mentioning_the_subject_passes() {
+    run_test("Add login\n\nWe Add login to the home page\n", None);
+}
+
+#[test]
+fn the_subject_on_line_four_fails() {
+    let message = "Add login
+
+Add a login form to the home page.
+Add login
+";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectEchoedInBody,
+            &message.into(),
+            Some(vec![("Repeats the subject".to_string(), 46, 9)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn every_echo_is_labelled() {
+    let message = "Add login\n\n  Add login  \n\nSome body\n\nAdd login\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectEchoedInBody,
+            &message.into(),
+            Some(vec![
+                ("Repeats the subject".to_string(), 13, 9),
+                ("Repeats the subject".to_string(), 37, 9),
+            ]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn comments_are_not_checked() {
+    run_test("Add login\n\nSome body\n# Add login\n", None);
+}
+
+#[test]
+fn case_is_only_ignored_when_configured() {
+    let message = CommitMessage::from("Add login\n\nSome body\n\nadd Login\n");
+
+    assert_eq!(
+        lint_with_config(&message, &SubjectEchoedInBodyConfig::default()),
+        None
+    );
+    assert_eq!(
+        lint_with_config(&message, &SubjectEchoedInBodyConfig { ignore_case: true }),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectEchoedInBody,
+            &message,
+            Some(vec![("Repeats the subject".to_string(), 22, 9)]),
+            None,
+        ))
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "Add login
+
+Add a login form to the home page.
+Add login
+";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "SubjectEchoedInBody
+
+  x Your commit has the subject repeated in the body
+   ,-[4:1]
+ 3 | Add a login form to the home page.
+ 4 | Add login
+   : ^^^^|^^^^
+   :     `-- Repeats the subject
+   `----
+  help: A copy of the subject in the body is usually left over from pasting,
+        and tells the reader nothing they haven't already read.
+        
+        You can fix this by removing the repeated line, or replacing it with
+        an explanation of why the change was made
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(body: String) -> TestResult {
+    if body.contains("Add login") {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Add login\n\nSome body content {body}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(body: String) -> TestResult {
+    if body.contains(['\n', '\r']) {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "Add login\n\nSome body content {body}\nAdd login\n"
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
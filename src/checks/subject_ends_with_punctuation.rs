@@ -0,0 +1,86 @@
+//! Check for punctuation at the end of the subject
+
+use std::collections::HashSet;
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::subject_offset_in_text,
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "subject-line-ends-with-punctuation";
+/// Description of the problem
+pub const ERROR: &str = "Your commit message ends with punctuation";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "It's important to keep your commits short, because we only have a \
+                            limited number of characters to use (72) before the subject line is \
+                            truncated. Punctuation isn't normally in subject lines, and takes up \
+                            an extra character, so we shouldn't use it at the end of commit \
+                            message subjects.\n\nYou can fix this by removing the punctuation";
+
+/// Configuration for which characters a subject may not end with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubjectEndsWithPunctuationConfig {
+    /// The characters that may not end the subject
+    pub disallowed: HashSet<char>,
+}
+
+impl Default for SubjectEndsWithPunctuationConfig {
+    fn default() -> Self {
+        Self {
+            disallowed: HashSet::from(['.', '!', '?']),
+        }
+    }
+}
+
+fn find_punctuation(
+    commit_message: &CommitMessage<'_>,
+    config: &SubjectEndsWithPunctuationConfig,
+) -> Option<(usize, char)> {
+    let (offset, punctuation) = commit_message
+        .get_subject()
+        .to_string()
+        .trim_end()
+        .char_indices()
+        .next_back()
+        .filter(|(_, ch)| config.disallowed.contains(ch))?;
+
+    Some((
+        subject_offset_in_text(
+            &String::from(commit_message.clone()),
+            commit_message.get_comment_char(),
+            offset,
+        ),
+        punctuation,
+    ))
+}
+
+/// Lint the commit message, returning a [`Problem`] if the subject ends with
+/// a period, exclamation mark or question mark
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &SubjectEndsWithPunctuationConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if the subject ends with one of the disallowed characters
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &SubjectEndsWithPunctuationConfig,
+) -> Option<Problem> {
+    let (offset, punctuation) = find_punctuation(commit_message, config)?;
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::SubjectEndsWithPunctuation,
+        commit_message,
+        Some(vec![(
+            format!("Unneeded `{punctuation}`"),
+            offset,
+            punctuation.len_utf8(),
+        )]),
+        Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+    ))
+}
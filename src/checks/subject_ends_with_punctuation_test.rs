@@ -0,0 +1,206 @@
+use std::{collections::HashSet, option::Option::None};
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::subject_ends_with_punctuation::{
+    lint,
+    lint_with_config,
+    SubjectEndsWithPunctuationConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+#[test]
+fn subject_does_not_end_with_punctuation() {
+    run_test(
+        "Subject Line
+",
+        None,
+    );
+}
+
+#[test]
+fn subject_ends_with_period() {
+    let message = "Subject Line.
+";
+    run_test(message, Some(&expected_problem(message, '.', 12)));
+}
+
+#[test]
+fn subject_ends_with_exclamation_mark() {
+    let message = "Subject Line!
+";
+    run_test(message, Some(&expected_problem(message, '!', 12)));
+}
+
+#[test]
+fn subject_ends_with_question_mark() {
+    let message = "Subject Line? ";
+    run_test(message, Some(&expected_problem(message, '?', 12)));
+}
+
+#[test]
+fn semicolon_and_ellipsis_are_allowed_by_default() {
+    run_test("Subject Line;", None);
+    run_test("Subject Line\u{2026}", None);
+}
+
+#[test]
+fn subject_ends_with_configured_semicolon() {
+    let message = "Subject Line;";
+    let config = SubjectEndsWithPunctuationConfig {
+        disallowed: HashSet::from([';']),
+    };
+    let actual = lint_with_config(&CommitMessage::from(message), &config);
+    assert_eq!(actual, Some(expected_problem(message, ';', 12)));
+}
+
+#[test]
+fn subject_ends_with_configured_ellipsis() {
+    let message = "Subject Line\u{2026}";
+    let config = SubjectEndsWithPunctuationConfig {
+        disallowed: HashSet::from(['\u{2026}']),
+    };
+    let actual = lint_with_config(&CommitMessage::from(message), &config);
+    assert_eq!(actual, Some(expected_problem(message, '\u{2026}', 12)));
+}
+
+#[test]
+fn only_configured_characters_are_disallowed() {
+    let config = SubjectEndsWithPunctuationConfig {
+        disallowed: HashSet::from([';']),
+    };
+    let actual = lint_with_config(&CommitMessage::from("Subject Line."), &config);
+    assert_eq!(actual, None);
+}
+
+#[test]
+fn comments_before_the_subject_are_skipped_over() {
+    let message = "# A comment
+Subject Line!
+";
+    run_test(message, Some(&expected_problem(message, '!', 24)));
+}
+
+#[test]
+fn formatting() {
+    let message = "An example commit!
+
+This is an example commit
+";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "SubjectEndsWithPunctuation (https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines)
+
+  x Your commit message ends with punctuation
+   ,-[1:18]
+ 1 | An example commit!
+   :                  |
+   :                  `-- Unneeded `!`
+ 2 | 
+   `----
+  help: It's important to keep your commits short, because we only have a
+        limited number of characters to use (72) before the subject line is
+        truncated. Punctuation isn't normally in subject lines, and takes up
+        an extra character, so we shouldn't use it at the end of commit
+        message subjects.
+        
+        You can fix this by removing the punctuation
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn expected_problem(message: &str, punctuation: char, offset: usize) -> Problem {
+    Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::SubjectEndsWithPunctuation,
+        &message.into(),
+        Some(vec![(
+            format!("Unneeded `{punctuation}`"),
+            offset,
+            punctuation.len_utf8(),
+        )]),
+        Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+    )
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(subject: String, body: Option<String>) -> TestResult {
+    if subject.trim_end().ends_with(['.', '!', '?']) {
+        return TestResult::discard();
+    }
+    if subject.contains('\n') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "{}{}",
+        subject,
+        body.map(|x| format!("\n\n{x}")).unwrap_or_default()
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(subject: String, body: Option<String>) -> TestResult {
+    if subject.contains('\n') {
+        return TestResult::discard();
+    }
+    if subject.starts_with('#') {
+        return TestResult::discard();
+    }
+    if !subject.trim_end().ends_with(['.', '!', '?']) {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "{}{}\n# bla",
+        subject,
+        body.map(|x| format!("\n\n{x}")).unwrap_or_default()
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn labels_are_on_the_punctuation(text: String) -> TestResult {
+    let message = CommitMessage::from(text.clone());
+    let Some(problem) = lint(&message) else {
+        return TestResult::discard();
+    };
+    let commit_text = String::from(message);
+    TestResult::from_bool(problem.structured_labels().iter().all(|label| {
+        commit_text
+            .get(label.offset..label.offset + label.length)
+            .is_some_and(|punctuation| [".", "!", "?"].contains(&punctuation))
+    }))
+}
@@ -0,0 +1,144 @@
+//! Check for filler words in the subject
+
+use std::collections::HashSet;
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{paragraphs, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "subject-filler-words";
+/// Description of the problem
+pub const ERROR: &str = "Your commit has a filler word in the subject";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Words like \"just\" or \"simply\" take up space in the subject \
+                            without telling the reader anything about the change.\n\nYou can fix \
+                            this by removing the filler words, or replacing them with something \
+                            more specific";
+
+/// Configuration for which words count as filler
+///
+/// When there are no words the lint never fails.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SubjectFillerWordsConfig {
+    /// The filler words, like `just` or `stuff`, matched in any case
+    pub words: HashSet<String>,
+}
+
+/// Find the words in the subject, and where they start
+fn words(subject: &str) -> impl Iterator<Item = (usize, &str)> {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '\'';
+
+    subject
+        .char_indices()
+        .filter(move |(index, c)| {
+            is_word_char(*c)
+                && subject[..*index]
+                    .chars()
+                    .next_back()
+                    .is_none_or(|previous| !is_word_char(previous))
+        })
+        .map(move |(start, _)| {
+            let length = subject[start..]
+                .find(|c: char| !is_word_char(c))
+                .unwrap_or(subject.len() - start);
+            (start, &subject[start..start + length])
+        })
+}
+
+/// Lint the commit message, returning a [`Problem`] labelling every filler
+/// word in the subject
+///
+/// There are no filler words by default, so this never fails. Use
+/// [`lint_with_config`] to give it some.
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &SubjectFillerWordsConfig::default())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    lint_with_options(
+        commit_message,
+        comment_char,
+        &SubjectFillerWordsConfig::default(),
+    )
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] labelling every filler word in the subject
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashSet;
+///
+/// use mit_commit::CommitMessage;
+/// use mit_lint::checks::subject_filler_words::{lint_with_config, SubjectFillerWordsConfig};
+///
+/// let config = SubjectFillerWordsConfig {
+///     words: HashSet::from(["just".to_string(), "stuff".to_string()]),
+/// };
+/// assert!(lint_with_config(&CommitMessage::from("Add login\n"), &config).is_none());
+/// assert!(lint_with_config(&CommitMessage::from("Just fix the stuff\n"), &config).is_some());
+/// ```
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &SubjectFillerWordsConfig,
+) -> Option<Problem> {
+    lint_with_options(commit_message, commit_message.get_comment_char(), config)
+}
+
+pub(crate) fn lint_with_options(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+    config: &SubjectFillerWordsConfig,
+) -> Option<Problem> {
+    if config.words.is_empty() {
+        return None;
+    }
+
+    let filler: HashSet<String> = config
+        .words
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect();
+    let commit_text = text_above_scissors(commit_message);
+    let (subject_offset, subject) = paragraphs(&commit_text, comment_char)
+        .into_iter()
+        .flatten()
+        .next()?;
+
+    let labels: Vec<(String, usize, usize)> = words(subject)
+        .filter(|(_, word)| filler.contains(&word.to_lowercase()))
+        .map(|(start, word)| {
+            (
+                "Filler word".to_string(),
+                subject_offset + start,
+                word.len(),
+            )
+        })
+        .collect();
+    if labels.is_empty() {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::SubjectFillerWords,
+        commit_message,
+        Some(labels),
+        None,
+    ))
+}
@@ -0,0 +1,132 @@
+use std::{collections::HashSet, option::Option::None};
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::subject_filler_words::{
+    lint,
+    lint_with_config,
+    SubjectFillerWordsConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+fn config() -> SubjectFillerWordsConfig {
+    SubjectFillerWordsConfig {
+        words: HashSet::from([
+            "just".to_string(),
+            "simply".to_string(),
+            "Stuff".to_string(),
+        ]),
+    }
+}
+
+#[test]
+fn nothing_is_filler_by_default() {
+    assert_eq!(lint(&CommitMessage::from("Just fix the stuff\n")), None);
+}
+
+#[test]
+fn a_clean_subject_passes() {
+    run_test("Fix the login redirect\n", None);
+}
+
+#[test]
+fn words_containing_filler_pass() {
+    run_test("Adjust the stuffing\n", None);
+}
+
+#[test]
+fn every_filler_word_is_labelled() {
+    let message = "Just fix the stuff\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectFillerWords,
+            &message.into(),
+            Some(vec![
+                ("Filler word".to_string(), 0, 4),
+                ("Filler word".to_string(), 13, 5),
+            ]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn the_body_is_not_checked() {
+    run_test("Fix the login redirect\n\nIt was just broken\n", None);
+}
+
+#[test]
+fn formatting() {
+    let message = "Just fix the stuff\n";
+    let problem = lint_with_config(&CommitMessage::from(message.to_string()), &config());
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "SubjectFillerWords
+
+  x Your commit has a filler word in the subject
+   ,----
+ 1 | Just fix the stuff
+   : ^^|^         ^^|^^
+   :   |            `-- Filler word
+   :   `-- Filler word
+   `----
+  help: Words like \"just\" or \"simply\" take up space in the subject without
+        telling the reader anything about the change.
+        
+        You can fix this by removing the filler words, or replacing them with
+        something more specific
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint_with_config(&CommitMessage::from(message), &config());
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(subject: String) -> TestResult {
+    let subject: String = subject.chars().filter(|c| c.is_alphanumeric()).collect();
+    if ["just", "simply", "stuff"].contains(&subject.to_lowercase().as_str()) {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Fix {subject}\n"));
+    let result = lint_with_config(&message, &config());
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(subject: String) -> TestResult {
+    if subject.contains(['\n', '\r']) {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Fix {subject} simply\n"));
+    let result = lint_with_config(&message, &config());
+    TestResult::from_bool(result.is_some())
+}
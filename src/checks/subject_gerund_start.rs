@@ -0,0 +1,125 @@
+//! Check for a subject starting with a verb ending in "ing"
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::{
+        comments::subject_offset_in_text,
+        not_conventional_commit::conventional_prefix_length,
+    },
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "subject-gerund-start";
+/// Description of the problem
+pub const ERROR: &str = "Your subject starts with a verb ending in \"ing\"";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "It's important to write the subject in the imperative mood, as \
+                            if you were giving a command, because it should complete the \
+                            sentence \"If applied, this commit will...\". This matches the \
+                            messages git writes itself, like \"Merge branch\" or \"Revert\".\n\nYou \
+                            can fix this by starting the subject with the verb in the imperative \
+                            mood";
+
+/// Words ending in "ing" that are not present continuous verbs
+const ALLOWED_WORDS: [&str; 26] = [
+    "anything",
+    "bring",
+    "ceiling",
+    "cling",
+    "ding",
+    "during",
+    "evening",
+    "everything",
+    "fling",
+    "king",
+    "morning",
+    "nothing",
+    "offspring",
+    "ping",
+    "ring",
+    "sibling",
+    "sing",
+    "sling",
+    "something",
+    "spring",
+    "sting",
+    "string",
+    "swing",
+    "thing",
+    "wing",
+    "wring",
+];
+
+/// Stem endings that usually had an "e" removed before adding "ing"
+const SILENT_E_ENDINGS: [&str; 16] = [
+    "v", "at", "us", "iz", "ak", "rg", "nc", "dl", "bl", "gl", "kl", "tl", "pl", "ur", "ag", "ar",
+];
+
+fn find_gerund(subject: &str) -> Option<(usize, &str)> {
+    let start = conventional_prefix_length(subject).unwrap_or_default();
+    let rest = subject[start..].trim_start();
+    let offset = subject.len() - rest.len();
+    let word = rest
+        .split(|character: char| !character.is_alphabetic())
+        .next()
+        .filter(|word| !word.is_empty())?;
+
+    let lower_case = word.to_lowercase();
+    if lower_case.len() < 5
+        || !lower_case.ends_with("ing")
+        || ALLOWED_WORDS.contains(&lower_case.as_str())
+    {
+        return None;
+    }
+
+    Some((offset, word))
+}
+
+/// Guess the base form of a verb ending in "ing"
+///
+/// This is a best guess from the spelling, so it won't always be right.
+fn base_form(word: &str) -> String {
+    let stem = &word[..word.len() - 3];
+    let mut characters = stem.chars().rev();
+    let last = characters.next();
+    let second_last = characters.next();
+
+    if stem.len() > 3 && last == second_last && last.is_some_and(|last| "bdgmnprt".contains(last)) {
+        return stem[..stem.len() - 1].to_string();
+    }
+
+    if SILENT_E_ENDINGS
+        .iter()
+        .any(|ending| stem.to_lowercase().ends_with(ending))
+    {
+        return format!("{stem}e");
+    }
+
+    stem.to_string()
+}
+
+/// Lint the commit message, returning a [`Problem`] if the subject starts
+/// with a verb ending in "ing"
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    let subject = commit_message.get_subject().to_string();
+    let (offset, word) = find_gerund(&subject)?;
+    let offset = subject_offset_in_text(
+        &String::from(commit_message.clone()),
+        commit_message.get_comment_char(),
+        offset,
+    );
+
+    Some(Problem::new(
+        ERROR.into(),
+        format!(
+            "{HELP_MESSAGE}, like \"{}\" instead of \"{word}\"",
+            base_form(word)
+        ),
+        Code::SubjectGerundStart,
+        commit_message,
+        Some(vec![("Not imperative".to_string(), offset, word.len())]),
+        Some("https://cbea.ms/git-commit/#imperative".to_string()),
+    ))
+}
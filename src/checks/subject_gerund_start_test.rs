@@ -0,0 +1,167 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::subject_gerund_start::{lint, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+#[test]
+fn imperative_subject_passes() {
+    run_test("Add an example commit\n\nSome body content\n", None);
+}
+
+#[test]
+fn allowed_words_pass() {
+    run_test("String parsing tweaks\n", None);
+    run_test("Spring cleaning\n", None);
+    run_test("Nothing to see here\n", None);
+}
+
+#[test]
+fn gerund_in_the_middle_passes() {
+    run_test("Add parsing for trailers\n", None);
+}
+
+#[test]
+fn gerund_subject_fails() {
+    let message = "Adding an example commit\n\nSome body content\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            format!("{HELP_MESSAGE}, like \"Add\" instead of \"Adding\""),
+            Code::SubjectGerundStart,
+            &message.into(),
+            Some(vec![("Not imperative".to_string(), 0, 6)]),
+            Some("https://cbea.ms/git-commit/#imperative".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn gerund_after_conventional_prefix_fails() {
+    let message = "fix(parser): Using the wrong index\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            format!("{HELP_MESSAGE}, like \"Use\" instead of \"Using\""),
+            Code::SubjectGerundStart,
+            &message.into(),
+            Some(vec![("Not imperative".to_string(), 13, 5)]),
+            Some("https://cbea.ms/git-commit/#imperative".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn lines_before_the_subject_are_skipped_over() {
+    let message = "@@ -1 +1 @@\nAdding x\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            format!("{HELP_MESSAGE}, like \"Add\" instead of \"Adding\""),
+            Code::SubjectGerundStart,
+            &message.into(),
+            Some(vec![("Not imperative".to_string(), 12, 6)]),
+            Some("https://cbea.ms/git-commit/#imperative".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn base_form_suggestions() {
+    for (subject, base) in [
+        ("Running the tests", "Run"),
+        ("Updating the docs", "Update"),
+        ("Fixing the build", "Fix"),
+        ("Removing a dependency", "Remove"),
+    ] {
+        let actual = lint(&CommitMessage::from(subject)).unwrap();
+        assert!(
+            actual.tip().contains(&format!("like \"{base}\"")),
+            "Expected {:?} to suggest {:?}, found {:?}",
+            subject,
+            base,
+            actual.tip()
+        );
+    }
+}
+
+#[test]
+fn formatting() {
+    let message = "Adding an example commit\n\nSome body content\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "SubjectGerundStart (https://cbea.ms/git-commit/#imperative)
+
+  x Your subject starts with a verb ending in \"ing\"
+   ,-[1:1]
+ 1 | Adding an example commit
+   : ^^^|^^
+   :    `-- Not imperative
+ 2 | 
+   `----
+  help: It's important to write the subject in the imperative mood, as if you
+        were giving a command, because it should complete the sentence \"If
+        applied, this commit will...\". This matches the messages git writes
+        itself, like \"Merge branch\" or \"Revert\".
+        
+        You can fix this by starting the subject with the verb in the
+        imperative mood, like \"Add\" instead of \"Adding\"
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(rest: String) -> TestResult {
+    if rest.contains('\n') || rest.starts_with('#') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Add {rest}"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(stem: String, rest: String) -> TestResult {
+    if stem.is_empty()
+        || !stem
+            .chars()
+            .all(|character| character.is_ascii_alphabetic())
+        || rest.contains('\n')
+    {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Re{stem}ing {rest}"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -0,0 +1,126 @@
+//! Check for a subject that starts with an emoji
+
+use mit_commit::CommitMessage;
+
+use crate::model::{Code, Problem};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "subject-leading-emoji";
+/// Description of the problem
+pub const ERROR: &str = "Your subject starts with an emoji";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Emoji at the start of a subject are decoration, they push the \
+                            description along and don't show up properly in every terminal. \
+                            If your team uses emoji log, use the not-emoji-log lint instead of \
+                            this one.\n\nYou can fix this by removing the emoji from the start \
+                            of the subject";
+
+const ZERO_WIDTH_JOINER: char = '\u{200d}';
+const COMBINING_KEYCAP: char = '\u{20e3}';
+
+fn is_pictograph(character: char) -> bool {
+    matches!(
+        u32::from(character),
+        0x203C
+            | 0x2049
+            | 0x2122
+            | 0x2139
+            | 0x2194..=0x21AA
+            | 0x231A..=0x23FF
+            | 0x24C2
+            | 0x25AA..=0x25FE
+            | 0x2600..=0x27BF
+            | 0x2934..=0x2935
+            | 0x2B05..=0x2B55
+            | 0x3030
+            | 0x303D
+            | 0x3297
+            | 0x3299
+            | 0x1_F000..=0x1_FAFF
+    )
+}
+
+fn is_regional_indicator(character: char) -> bool {
+    matches!(u32::from(character), 0x1_F1E6..=0x1_F1FF)
+}
+
+fn is_emoji_modifier(character: char) -> bool {
+    matches!(
+        u32::from(character),
+        // Variation selectors
+        0xFE00..=0xFE0F
+            // Skin tones
+            | 0x1_F3FB..=0x1_F3FF
+            // Tags, used in subdivision flags
+            | 0xE_0020..=0xE_007F
+    ) || character == COMBINING_KEYCAP
+}
+
+/// Get the length in bytes of the emoji at the start of the subject
+///
+/// The emoji is taken along with anything combined with it, so the whole of
+/// a flag, a skin toned emoji, a keycap like `1️⃣`, or a sequence joined with
+/// zero width joiners like `👩‍💻` counts as one. Only the first of several
+/// emoji in a row is included.
+fn leading_emoji_length(subject: &str) -> usize {
+    let mut characters = subject.char_indices().peekable();
+    let Some((_, first)) = characters.next() else {
+        return 0;
+    };
+    let is_keycap = first.is_ascii_digit() || first == '#' || first == '*';
+    if !is_pictograph(first) && !is_keycap {
+        return 0;
+    }
+
+    let mut length = first.len_utf8();
+    if is_regional_indicator(first) {
+        if let Some((index, second)) =
+            characters.next_if(|(_, second)| is_regional_indicator(*second))
+        {
+            length = index + second.len_utf8();
+        }
+    }
+
+    let mut has_keycap = false;
+    while let Some((index, character)) = characters.next() {
+        if is_emoji_modifier(character) {
+            has_keycap = has_keycap || character == COMBINING_KEYCAP;
+            length = index + character.len_utf8();
+        } else if character == ZERO_WIDTH_JOINER && !is_keycap {
+            match characters.next_if(|(_, joined)| is_pictograph(*joined)) {
+                Some((index, joined)) => length = index + joined.len_utf8(),
+                None => break,
+            }
+        } else {
+            break;
+        }
+    }
+
+    if is_keycap && !has_keycap {
+        0
+    } else {
+        length
+    }
+}
+
+/// Lint the commit message, returning a [`Problem`] if the subject starts
+/// with an emoji
+///
+/// This is the opposite of the emoji log style, so teams should pick one of
+/// this and [`Lint::NotEmojiLog`](crate::Lint::NotEmojiLog).
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    let commit_text = String::from(commit_message.clone());
+    let length = leading_emoji_length(commit_text.lines().next().unwrap_or_default());
+    if length == 0 {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::SubjectLeadingEmoji,
+        commit_message,
+        Some(vec![("Leading emoji".to_string(), 0, length)]),
+        Some("https://cbea.ms/git-commit/#limit-50".to_string()),
+    ))
+}
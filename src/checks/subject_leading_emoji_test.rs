@@ -0,0 +1,144 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::subject_leading_emoji::{lint, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+#[test]
+fn plain_subject_passes() {
+    run_test("Fix bug\n\nSome body content\n", None);
+}
+
+#[test]
+fn emoji_later_in_the_subject_passes() {
+    run_test("Fix bug \u{1f41b}\n\nSome body content\n", None);
+}
+
+#[test]
+fn numbers_without_a_keycap_pass() {
+    run_test("2 bugs fixed\n\nSome body content\n", None);
+}
+
+#[test]
+fn leading_emoji_fails() {
+    let message = "\u{1f41b} fix bug\n\nSome body content\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectLeadingEmoji,
+            &message.into(),
+            Some(vec![("Leading emoji".to_string(), 0, 4)]),
+            Some("https://cbea.ms/git-commit/#limit-50".to_string()),
+        )),
+    );
+}
+
+// Combining sequences are labelled as a single emoji, so the label never
+// splits one in half
+#[test]
+fn combining_sequences_are_labelled_whole() {
+    for (emoji, expected_length) in [
+        // Variation selector
+        ("\u{203c}\u{fe0f}", 6),
+        // Skin tone
+        ("\u{1f44d}\u{1f3fd}", 8),
+        // Flag
+        ("\u{1f1ec}\u{1f1e7}", 8),
+        // Keycap
+        ("1\u{fe0f}\u{20e3}", 7),
+        // Zero width joiner
+        ("\u{1f469}\u{200d}\u{1f4bb}", 11),
+        // Only the first of two separate emoji
+        ("\u{1f41b}\u{1f41b}", 4),
+    ] {
+        let message = format!("{emoji} fix bug\n");
+        let actual = lint(&CommitMessage::from(message.clone()));
+        assert_eq!(
+            actual,
+            Some(Problem::new(
+                ERROR.into(),
+                HELP_MESSAGE.into(),
+                Code::SubjectLeadingEmoji,
+                &message.into(),
+                Some(vec![("Leading emoji".to_string(), 0, expected_length)]),
+                Some("https://cbea.ms/git-commit/#limit-50".to_string()),
+            )),
+            "Expected {:?} to be labelled whole",
+            emoji
+        );
+    }
+}
+
+#[test]
+fn formatting() {
+    let message = "\u{1f41b} fix bug\n\nSome body content\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "SubjectLeadingEmoji (https://cbea.ms/git-commit/#limit-50)
+
+  x Your subject starts with an emoji
+   ,-[1:1]
+ 1 | \u{1f41b} fix bug
+   : ^|
+   :  `-- Leading emoji
+ 2 | 
+   `----
+  help: Emoji at the start of a subject are decoration, they push the
+        description along and don't show up properly in every terminal. If
+        your team uses emoji log, use the not-emoji-log lint instead of this
+        one.
+        
+        You can fix this by removing the emoji from the start of the subject
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(subject: String) -> TestResult {
+    if subject.contains('\n') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Fix {subject}\n\nSome body content\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(subject: String) -> TestResult {
+    if subject.contains('\n') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("\u{1f41b} {subject}\n\nSome body content\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -1,3 +1,5 @@
+//! Check for a period at the end of the subject
+
 use std::option::Option::None;
 
 use mit_commit::CommitMessage;
@@ -26,6 +28,8 @@ fn has_problem(commit_message: &CommitMessage<'_>) -> bool {
         .is_some()
 }
 
+/// Lint the commit message, returning a [`Problem`] if the subject ends with a
+/// period
 pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
     if has_problem(commit_message) {
         let subject = commit_message.get_subject().to_string();
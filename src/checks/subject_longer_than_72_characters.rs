@@ -1,8 +1,13 @@
+//! Check for a long subject line
+
 use std::option::Option::None;
 
 use mit_commit::CommitMessage;
 
-use crate::model::{Code, Problem};
+use crate::{
+    checks::not_conventional_commit::conventional_prefix_length,
+    model::{Code, Problem},
+};
 
 /// Canonical lint ID
 pub const CONFIG: &str = "subject-longer-than-72-characters";
@@ -15,11 +20,57 @@ pub const HELP_MESSAGE: &str = "It's important to keep the subject of the commit
                             characters or under";
 /// Description of the problem
 pub const ERROR: &str = "Your subject is longer than 72 characters";
+/// Advice on how to correct the problem when the subject has too many bytes
+pub const BYTE_LIMIT_HELP_MESSAGE: &str = "Some tools cut the subject off after a number of \
+                                       bytes rather than characters, so a subject with lots of \
+                                       multibyte characters, like emoji, can be truncated even \
+                                       when it looks short.\n\nPlease use fewer multibyte \
+                                       characters, or shorten the subject";
+/// Description of the problem when the subject has too many bytes
+pub const BYTE_LIMIT_ERROR: &str = "Your subject is longer than the byte limit";
 
 const LIMIT: usize = 72;
 
+/// Configuration for how the subject is measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SubjectLengthConfig {
+    /// Don't count a conventional commit prefix, like `feat(scope)!: `,
+    /// towards the limit
+    pub exclude_conventional_prefix: bool,
+    /// The most bytes the subject may have, for tools that truncate by bytes
+    ///
+    /// This is checked as well as the 72 character limit, and always counts
+    /// the whole subject, including any conventional commit prefix.
+    pub byte_limit: Option<usize>,
+}
+
+/// Lint the commit message, returning a [`Problem`] if the subject is longer
+/// than 72 characters
 pub fn lint(commit: &CommitMessage<'_>) -> Option<Problem> {
-    let subject_till_newline = subject_length(commit);
+    lint_with_config(commit, &SubjectLengthConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if the subject is longer than 72 characters
+///
+/// When the conventional commit prefix is excluded, only the description
+/// after it is measured, and the label starts 72 characters into the
+/// description.
+///
+/// If the subject is within 72 characters but over the byte limit, the
+/// [`Problem`] labels the bytes past the limit as "Too many bytes" instead.
+pub fn lint_with_config(
+    commit: &CommitMessage<'_>,
+    config: &SubjectLengthConfig,
+) -> Option<Problem> {
+    let subject = commit.get_subject().to_string();
+    let subject = subject.split('\n').next().unwrap_or_default();
+    let start = if config.exclude_conventional_prefix {
+        conventional_prefix_length(subject).unwrap_or_default()
+    } else {
+        0
+    };
+    let subject_till_newline = subject[start..].chars().count();
     if subject_till_newline > LIMIT {
         Some(Problem::new(
             ERROR.into(),
@@ -28,26 +79,46 @@ pub fn lint(commit: &CommitMessage<'_>) -> Option<Problem> {
             commit,
             Some(vec![(
                 "Too long".to_string(),
-                LIMIT,
+                start + LIMIT,
                 subject_till_newline - LIMIT,
             )]),
             Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".parse().unwrap()),
         ))
     } else {
-        None
+        config
+            .byte_limit
+            .and_then(|byte_limit| byte_limit_problem(commit, subject, byte_limit))
     }
 }
 
-fn subject_length(commit: &CommitMessage<'_>) -> usize {
-    commit
-        .get_subject()
-        .chars()
-        .position(is_newline)
-        .unwrap_or_else(|| commit.get_subject().chars().count())
-}
+fn byte_limit_problem(
+    commit: &CommitMessage<'_>,
+    subject: &str,
+    byte_limit: usize,
+) -> Option<Problem> {
+    if subject.len() <= byte_limit {
+        return None;
+    }
 
-const fn is_newline(character: char) -> bool {
-    character == '\n'
+    let offset = subject
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .take_while(|offset| *offset <= byte_limit)
+        .last()
+        .unwrap_or_default();
+
+    Some(Problem::new(
+        BYTE_LIMIT_ERROR.into(),
+        BYTE_LIMIT_HELP_MESSAGE.into(),
+        Code::SubjectLongerThan72Characters,
+        commit,
+        Some(vec![(
+            "Too many bytes".to_string(),
+            offset,
+            subject.len() - offset,
+        )]),
+        None,
+    ))
 }
 
 #[cfg(test)]
@@ -55,7 +126,7 @@ mod tests {
     #![allow(clippy::wildcard_imports)]
 
     use super::*;
-    use crate::model::{Code, Problem};
+    use crate::model::{Code, Label, Problem};
 
     #[test]
     fn shorter_than_72_characters() {
@@ -372,6 +443,149 @@ index 5a83784..ebaee48 100644
         test_subject_longer_than_72_characters(&format!("{}\n\n{message}", "x".repeat(72)), None);
     }
 
+    #[test]
+    fn a_long_conventional_subject_fails_by_default() {
+        let message = format!("feat(scope): {}", "x".repeat(72));
+        test_subject_longer_than_72_characters(
+            &message.clone(),
+            Some(&Problem::new(
+                ERROR.into(),
+                HELP_MESSAGE.into(),
+                Code::SubjectLongerThan72Characters,
+                &message.into(),
+                Some(vec![("Too long".to_string(), 72_usize, 13_usize)]),
+                Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+            )),
+        );
+    }
+
+    #[test]
+    fn the_conventional_prefix_can_be_excluded() {
+        let config = SubjectLengthConfig {
+            exclude_conventional_prefix: true,
+            ..SubjectLengthConfig::default()
+        };
+        let message = CommitMessage::from(format!("feat(scope)!: {}\n", "x".repeat(72)));
+
+        assert_eq!(lint_with_config(&message, &config), None);
+    }
+
+    #[test]
+    fn the_label_skips_an_excluded_conventional_prefix() {
+        let config = SubjectLengthConfig {
+            exclude_conventional_prefix: true,
+            ..SubjectLengthConfig::default()
+        };
+        let message = format!("feat(scope): {}\n", "x".repeat(75));
+
+        assert_eq!(
+            lint_with_config(&CommitMessage::from(message.clone()), &config),
+            Some(Problem::new(
+                ERROR.into(),
+                HELP_MESSAGE.into(),
+                Code::SubjectLongerThan72Characters,
+                &message.into(),
+                Some(vec![("Too long".to_string(), 85_usize, 3_usize)]),
+                Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+            ))
+        );
+    }
+
+    #[test]
+    fn subjects_without_a_prefix_are_measured_in_full_when_excluding() {
+        let config = SubjectLengthConfig {
+            exclude_conventional_prefix: true,
+            ..SubjectLengthConfig::default()
+        };
+        let message = CommitMessage::from("x".repeat(73));
+
+        assert!(lint_with_config(&message, &config).is_some());
+    }
+
+    #[test]
+    fn there_is_no_byte_limit_by_default() {
+        let message = CommitMessage::from(format!("{}\n", "\u{1F600}".repeat(60)));
+
+        assert_eq!(lint(&message), None);
+    }
+
+    #[test]
+    fn multibyte_subjects_can_fail_the_byte_limit() {
+        let config = SubjectLengthConfig {
+            byte_limit: Some(200),
+            ..SubjectLengthConfig::default()
+        };
+        let message = format!("{}\n", "\u{1F600}".repeat(60));
+
+        assert_eq!(
+            lint_with_config(&CommitMessage::from(message.clone()), &config),
+            Some(Problem::new(
+                BYTE_LIMIT_ERROR.into(),
+                BYTE_LIMIT_HELP_MESSAGE.into(),
+                Code::SubjectLongerThan72Characters,
+                &message.into(),
+                Some(vec![("Too many bytes".to_string(), 200_usize, 40_usize)]),
+                None,
+            ))
+        );
+    }
+
+    #[test]
+    fn the_byte_limit_label_starts_on_a_character_boundary() {
+        let config = SubjectLengthConfig {
+            byte_limit: Some(10),
+            ..SubjectLengthConfig::default()
+        };
+        let message = format!("{}\n", "\u{00E4}".repeat(6));
+
+        assert_eq!(
+            lint_with_config(&CommitMessage::from(message.clone()), &config)
+                .unwrap()
+                .structured_labels(),
+            vec![Label {
+                text: "Too many bytes".to_string(),
+                offset: 10,
+                length: 2,
+            }]
+        );
+        let config = SubjectLengthConfig {
+            byte_limit: Some(11),
+            ..SubjectLengthConfig::default()
+        };
+        assert_eq!(
+            lint_with_config(&CommitMessage::from(message), &config)
+                .unwrap()
+                .structured_labels(),
+            vec![Label {
+                text: "Too many bytes".to_string(),
+                offset: 10,
+                length: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn subjects_within_the_byte_limit_pass() {
+        let config = SubjectLengthConfig {
+            byte_limit: Some(240),
+            ..SubjectLengthConfig::default()
+        };
+        let message = CommitMessage::from(format!("{}\n", "\u{1F600}".repeat(60)));
+
+        assert_eq!(lint_with_config(&message, &config), None);
+    }
+
+    #[test]
+    fn the_character_limit_is_reported_before_the_byte_limit() {
+        let config = SubjectLengthConfig {
+            byte_limit: Some(10),
+            ..SubjectLengthConfig::default()
+        };
+        let message = CommitMessage::from("x".repeat(73));
+
+        assert_eq!(lint_with_config(&message, &config), lint(&message));
+    }
+
     #[test]
     fn formatting() {
         let message = "x".repeat(73);
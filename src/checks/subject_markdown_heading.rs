@@ -0,0 +1,61 @@
+//! Check for a subject written as a markdown heading
+
+use mit_commit::CommitMessage;
+
+use crate::model::{Code, Problem};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "subject-markdown-heading";
+/// Description of the problem
+pub const ERROR: &str = "Your subject is written as a markdown heading";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Git doesn't render markdown, so the heading markers show up as \
+                            they are in the log. Worse, `#` is usually the comment character, \
+                            so the subject may be thrown away entirely when the comment \
+                            character changes.\n\nYou can fix this by removing the `#` \
+                            characters from the start of the subject";
+
+fn heading_marker_length(commit_message: &CommitMessage<'_>, comment_char: Option<char>) -> usize {
+    let commit_text = String::from(commit_message.clone());
+    let subject = commit_text.lines().next().unwrap_or_default();
+    if comment_char.is_some_and(|comment_char| subject.starts_with(comment_char)) {
+        return 0;
+    }
+
+    let markers = subject.chars().take_while(|x| *x == '#').count();
+    if markers > 0 && subject[markers..].starts_with(' ') {
+        markers
+    } else {
+        0
+    }
+}
+
+/// Lint the commit message, returning a [`Problem`] if the subject starts
+/// with markdown heading markers
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_comment_char(commit_message, commit_message.get_comment_char())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// When the subject itself starts with the comment character it's a comment
+/// rather than a heading, so isn't a problem.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    let markers = heading_marker_length(commit_message, comment_char);
+    if markers == 0 {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::SubjectMarkdownHeading,
+        commit_message,
+        Some(vec![("Heading marker".to_string(), 0, markers)]),
+        Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+    ))
+}
@@ -0,0 +1,130 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::subject_markdown_heading::{lint, lint_with_comment_char, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+#[test]
+fn normal_subject_passes() {
+    run_test("An example commit\n\nSome body content\n", None);
+}
+
+#[test]
+fn hash_without_a_space_passes() {
+    run_test("#42 An example commit\n\n; A comment\n", None);
+}
+
+#[test]
+fn heading_with_a_different_comment_char_fails() {
+    let message = "# An example commit\n\nSome body content\n; A comment\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectMarkdownHeading,
+            &message.into(),
+            Some(vec![("Heading marker".to_string(), 0, 1)]),
+            Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn deeper_headings_label_every_marker() {
+    let message = "### An example commit\n";
+    let actual = lint_with_comment_char(&CommitMessage::from(message), Some(';'));
+    assert_eq!(
+        actual,
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectMarkdownHeading,
+            &message.into(),
+            Some(vec![("Heading marker".to_string(), 0, 3)]),
+            Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+        ))
+    );
+}
+
+#[test]
+fn subject_that_is_a_comment_passes() {
+    let message = CommitMessage::from("# An example commit\n\nSome body content\n");
+    assert_eq!(lint_with_comment_char(&message, Some('#')), None);
+}
+
+#[test]
+fn formatting() {
+    let message = "# An example commit\n\nSome body content\n; A comment\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "SubjectMarkdownHeading (https://git-scm.com/docs/git-commit#_discussion)
+
+  x Your subject is written as a markdown heading
+   ,-[1:1]
+ 1 | # An example commit
+   : |
+   : `-- Heading marker
+ 2 | 
+   `----
+  help: Git doesn't render markdown, so the heading markers show up as they
+        are in the log. Worse, `#` is usually the comment character, so the
+        subject may be thrown away entirely when the comment character
+        changes.
+        
+        You can fix this by removing the `#` characters from the start of the
+        subject
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(subject: String) -> TestResult {
+    if subject.contains('\n') || subject.starts_with('#') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("{subject}\n\nSome body content\n"));
+    let result = lint_with_comment_char(&message, Some(';'));
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(level: u8, subject: String) -> TestResult {
+    if subject.contains('\n') || level == 0 {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "{} {subject}\n\nSome body content\n",
+        "#".repeat(usize::from(level % 6) + 1)
+    ));
+    let result = lint_with_comment_char(&message, Some(';'));
+    TestResult::from_bool(result.is_some())
+}
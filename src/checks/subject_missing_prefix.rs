@@ -0,0 +1,78 @@
+//! Check for a subject that doesn't start with a required prefix
+
+use mit_commit::CommitMessage;
+use regex::Regex;
+
+use crate::{
+    checks::comments::is_empty_message,
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "subject-missing-prefix";
+/// Description of the problem
+pub const ERROR: &str = "Your subject is missing the required prefix";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "This repository asks for every subject to start with a prefix, \
+                            usually a ticket reference, so the log can be tied back to the \
+                            work it was for.\n\nYou can fix this by adding the prefix to the \
+                            start of the subject";
+
+/// Configuration for the prefix a subject must start with
+///
+/// When there's no pattern the lint never fails.
+#[derive(Debug, Clone, Default)]
+pub struct SubjectMissingPrefixConfig {
+    /// The pattern the start of the subject must match, like
+    /// `\[[A-Z]+-[0-9]+\] ` for `[ABC-123] `
+    pub pattern: Option<Regex>,
+}
+
+/// Lint the commit message, returning a [`Problem`] if the subject doesn't
+/// start with the required prefix
+///
+/// There's no prefix configured by default, so this never fails. Use
+/// [`lint_with_config`], or set
+/// [`LintConfig::subject_missing_prefix`](crate::LintConfig::subject_missing_prefix),
+/// to give it one.
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &SubjectMissingPrefixConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if the subject doesn't start with a match for the pattern
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &SubjectMissingPrefixConfig,
+) -> Option<Problem> {
+    let pattern = config.pattern.as_ref()?;
+    if is_empty_message(commit_message, commit_message.get_comment_char()) {
+        return None;
+    }
+
+    let commit_text = String::from(commit_message.clone());
+    let subject = commit_text.lines().next().unwrap_or_default();
+    if pattern
+        .find(subject)
+        .is_some_and(|found| found.start() == 0)
+    {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::SubjectMissingPrefix,
+        commit_message,
+        Some(vec![(
+            "Missing prefix".to_string(),
+            0,
+            subject
+                .split_whitespace()
+                .next()
+                .map(str::len)
+                .unwrap_or_default(),
+        )]),
+        None,
+    ))
+}
@@ -0,0 +1,134 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+use regex::Regex;
+
+use super::subject_missing_prefix::{
+    lint,
+    lint_with_config,
+    SubjectMissingPrefixConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+fn ticket_config() -> SubjectMissingPrefixConfig {
+    SubjectMissingPrefixConfig {
+        pattern: Some(Regex::new(r"\[[A-Z]+-[0-9]+\] ").unwrap()),
+    }
+}
+
+#[test]
+fn no_pattern_passes() {
+    assert_eq!(
+        lint(&CommitMessage::from("Add login\n\nSome body content\n")),
+        None
+    );
+}
+
+#[test]
+fn matching_prefix_passes() {
+    run_test("[ABC-123] Add login\n\nSome body content\n", None);
+}
+
+#[test]
+fn prefix_later_in_the_subject_fails() {
+    let message = "Add login [ABC-123] \n\nSome body content\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectMissingPrefix,
+            &message.into(),
+            Some(vec![("Missing prefix".to_string(), 0, 3)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn missing_prefix_fails() {
+    let message = "Add login\n\nSome body content\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectMissingPrefix,
+            &message.into(),
+            Some(vec![("Missing prefix".to_string(), 0, 3)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "Add login\n\nSome body content\n";
+    let problem = lint_with_config(&CommitMessage::from(message.to_string()), &ticket_config());
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "SubjectMissingPrefix
+
+  x Your subject is missing the required prefix
+   ,-[1:1]
+ 1 | Add login
+   : ^|^
+   :  `-- Missing prefix
+ 2 | 
+   `----
+  help: This repository asks for every subject to start with a prefix, usually
+        a ticket reference, so the log can be tied back to the work it was
+        for.
+        
+        You can fix this by adding the prefix to the start of the subject
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint_with_config(&CommitMessage::from(message), &ticket_config());
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(ticket: u16, subject: String) -> TestResult {
+    if subject.contains('\n') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("[ABC-{ticket}] {subject}\n\nSome body content\n"));
+    let result = lint_with_config(&message, &ticket_config());
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(subject: String) -> TestResult {
+    if subject.contains('\n') || subject.starts_with('[') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Re{subject}\n\nSome body content\n"));
+    let result = lint_with_config(&message, &ticket_config());
+    TestResult::from_bool(result.is_some())
+}
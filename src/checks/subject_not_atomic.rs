@@ -0,0 +1,136 @@
+//! Check for a subject that lists more than one change
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::{
+        comments::subject_offset_in_text,
+        not_conventional_commit::conventional_prefix_length,
+    },
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "subject-not-atomic";
+/// Description of the problem
+pub const ERROR: &str = "Your subject lists more than one change";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "A subject like `Add login and fix logout` is usually a sign that \
+                            the commit does more than one thing. Small commits that each do one \
+                            thing are easier to review, revert, and bisect.\n\nYou can fix this \
+                            by splitting the commit up, or describing the one thing the changes \
+                            achieve together";
+
+const DEFAULT_MARKERS: [&str; 3] = ["and", "&", ","];
+const TRAILING_PUNCTUATION: [char; 2] = [',', ';'];
+
+/// Configuration for what joins two changes together in a subject
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SubjectNotAtomicConfig {
+    /// The markers that join two changes, like `and`, `&` or `,`
+    ///
+    /// Markers made of letters are matched as whole words, ignoring case.
+    pub markers: Vec<String>,
+    /// Only count a marker when there's a space either side of it, so names
+    /// like `A&B` aren't flagged
+    ///
+    /// Punctuation that sits against the word before it, like `,` and `;`,
+    /// only needs a space after it.
+    pub require_spaces: bool,
+}
+
+impl Default for SubjectNotAtomicConfig {
+    fn default() -> Self {
+        Self {
+            markers: DEFAULT_MARKERS.iter().map(ToString::to_string).collect(),
+            require_spaces: true,
+        }
+    }
+}
+
+fn is_marker_at(subject: &str, start: usize, end: usize, config: &SubjectNotAtomicConfig) -> bool {
+    let marker = &subject[start..end];
+    let before = subject[..start].chars().next_back();
+    let after = subject[end..].chars().next();
+
+    if marker.starts_with(char::is_alphanumeric) && before.is_some_and(char::is_alphanumeric) {
+        return false;
+    }
+    if marker.ends_with(char::is_alphanumeric) && after.is_some_and(char::is_alphanumeric) {
+        return false;
+    }
+    if !config.require_spaces {
+        return true;
+    }
+
+    let space_before =
+        marker.starts_with(TRAILING_PUNCTUATION) || before.is_some_and(char::is_whitespace);
+    space_before && after.is_some_and(char::is_whitespace)
+}
+
+/// Get the end of `marker` if the subject has it at `start`, ignoring case
+fn marker_end(subject: &str, start: usize, marker: &str) -> Option<usize> {
+    let length: usize = subject[start..]
+        .chars()
+        .take(marker.chars().count())
+        .map(char::len_utf8)
+        .sum();
+    let candidate = &subject[start..start + length];
+
+    candidate
+        .chars()
+        .flat_map(char::to_lowercase)
+        .eq(marker.chars().flat_map(char::to_lowercase))
+        .then_some(start + length)
+}
+
+fn find_marker(subject: &str, config: &SubjectNotAtomicConfig) -> Option<(usize, usize)> {
+    let start = conventional_prefix_length(subject).unwrap_or_default();
+
+    subject
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .filter(|offset| *offset >= start)
+        .find_map(|offset| {
+            config
+                .markers
+                .iter()
+                .filter(|marker| !marker.is_empty())
+                .filter_map(|marker| marker_end(subject, offset, marker))
+                .find(|end| is_marker_at(subject, offset, *end, config))
+                .map(|end| (offset, end - offset))
+        })
+}
+
+/// Lint the commit message, returning a [`Problem`] if the subject joins
+/// changes together with `and`, `&` or `,`
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &SubjectNotAtomicConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] labelling the first marker in the subject
+///
+/// Any conventional commit prefix is ignored, so a scope like `(api,cli)`
+/// isn't flagged.
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &SubjectNotAtomicConfig,
+) -> Option<Problem> {
+    let subject = commit_message.get_subject().to_string();
+    let (offset, length) = find_marker(&subject, config)?;
+    let offset = subject_offset_in_text(
+        &String::from(commit_message.clone()),
+        commit_message.get_comment_char(),
+        offset,
+    );
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::SubjectNotAtomic,
+        commit_message,
+        Some(vec![("More than one change".to_string(), offset, length)]),
+        Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+    ))
+}
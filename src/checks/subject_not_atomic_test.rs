@@ -0,0 +1,211 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::subject_not_atomic::{
+    lint,
+    lint_with_config,
+    SubjectNotAtomicConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+#[test]
+fn single_change_passes() {
+    run_test("Add login\n", None);
+}
+
+#[test]
+fn and_inside_a_word_passes() {
+    run_test("Handle the android login\n", None);
+}
+
+#[test]
+fn product_names_pass() {
+    run_test("Update the AT&T integration\n", None);
+}
+
+#[test]
+fn numbers_with_commas_pass() {
+    run_test("Raise the limit to 1,000\n", None);
+}
+
+#[test]
+fn conventional_scopes_pass() {
+    run_test("feat(api,cli): Add login\n", None);
+}
+
+#[test]
+fn and_fails() {
+    let message = "Add login and fix logout\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectNotAtomic,
+            &message.into(),
+            Some(vec![("More than one change".to_string(), 10, 3)]),
+            Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn the_first_marker_is_labelled() {
+    let message = "Add login, fix logout & tidy up AND test\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectNotAtomic,
+            &message.into(),
+            Some(vec![("More than one change".to_string(), 9, 1)]),
+            Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn comments_before_the_subject_are_skipped_over() {
+    let message = "# Ünïcode\nÄdd login and fix logout\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectNotAtomic,
+            &message.into(),
+            Some(vec![("More than one change".to_string(), 23, 3)]),
+            Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn markers_ignore_case() {
+    assert!(lint(&CommitMessage::from("Add login AND fix logout\n")).is_some());
+}
+
+#[test]
+fn markers_can_be_configured() {
+    let message = CommitMessage::from("Add login plus fix logout\n");
+    let config = SubjectNotAtomicConfig {
+        markers: vec!["plus".to_string()],
+        ..SubjectNotAtomicConfig::default()
+    };
+
+    assert!(lint(&message).is_none());
+    assert!(lint_with_config(&message, &config).is_some());
+}
+
+#[test]
+fn spaces_can_be_optional() {
+    let message = CommitMessage::from("Update the AT&T integration\n");
+    let config = SubjectNotAtomicConfig {
+        require_spaces: false,
+        ..SubjectNotAtomicConfig::default()
+    };
+
+    assert_eq!(
+        lint_with_config(&message, &config),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectNotAtomic,
+            &message,
+            Some(vec![("More than one change".to_string(), 13, 1)]),
+            Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+        ))
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "Add login and fix logout\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "SubjectNotAtomic (https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines)
+
+  x Your subject lists more than one change
+   ,----
+ 1 | Add login and fix logout
+   :           ^|^
+   :            `-- More than one change
+   `----
+  help: A subject like `Add login and fix logout` is usually a sign that the
+        commit does more than one thing. Small commits that each do one thing
+        are easier to review, revert, and bisect.
+        
+        You can fix this by splitting the commit up, or describing the one
+        thing the changes achieve together
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(words: Vec<String>) -> TestResult {
+    let words: Vec<String> = words
+        .iter()
+        .map(|word| word.chars().filter(|c| c.is_alphanumeric()).collect())
+        .filter(|word: &String| !word.is_empty() && word.to_lowercase() != "and")
+        .collect();
+    let message = CommitMessage::from(format!("Add {}\n", words.join(" ")));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(first: String, second: String, marker: usize) -> TestResult {
+    if first.contains('\n') || second.contains('\n') {
+        return TestResult::discard();
+    }
+    let marker = [" and ", " & ", ", "][marker % 3];
+    let message = CommitMessage::from(format!("Add {first}{marker}fix {second}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn labels_are_on_the_marker(text: String) -> TestResult {
+    let message = CommitMessage::from(text);
+    let Some(problem) = lint(&message) else {
+        return TestResult::discard();
+    };
+    let commit_text = String::from(message);
+    TestResult::from_bool(problem.structured_labels().iter().all(|label| {
+        commit_text
+            .get(label.offset..label.offset + label.length)
+            .is_some_and(|marker| ["and", "&", ","].contains(&marker.to_lowercase().as_str()))
+    }))
+}
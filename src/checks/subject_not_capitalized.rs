@@ -1,3 +1,5 @@
+//! Check for a non-capitalised subject
+
 use std::option::Option::None;
 
 use mit_commit::CommitMessage;
@@ -23,6 +25,8 @@ fn has_problem(commit_message: &CommitMessage<'_>) -> bool {
         .unwrap_or(false)
 }
 
+/// Lint the commit message, returning a [`Problem`] if the subject doesn't
+/// start with a capital letter
 pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
     if has_problem(commit_message) {
         Some(Problem::new(
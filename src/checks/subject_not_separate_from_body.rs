@@ -1,3 +1,5 @@
+//! Check for a subject that isn't separated from the body
+
 use std::option::Option::None;
 
 use mit_commit::CommitMessage;
@@ -21,6 +23,11 @@ fn has_problem(commit_message: &CommitMessage<'_>) -> bool {
     subject.lines().count() > 1
 }
 
+/// Lint the commit message, returning a [`Problem`] if there is no blank line
+/// between the subject and the body
+///
+/// A subject with no body passes, as does a subject followed straight away by
+/// a comment. The label covers the line after the subject.
 pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
     if has_problem(commit_message) {
         let commit_text = String::from(commit_message.clone());
@@ -142,6 +142,53 @@ It has even more lines
         );
 }
 
+#[test]
+fn subject_without_a_body_passes() {
+    test_subject_not_separate_from_body("Subject", None);
+}
+
+#[test]
+fn subject_followed_directly_by_a_body_fails() {
+    test_subject_not_separate_from_body(
+        "Subject\nBody",
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectNotSeparateFromBody,
+            &"Subject\nBody".into(),
+            Some(vec![("Missing blank line".to_string(), 8_usize, 4_usize)]),
+            Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn subject_separated_from_the_body_passes() {
+    test_subject_not_separate_from_body("Subject\n\nBody", None);
+}
+
+#[test]
+fn subject_followed_directly_by_a_comment_passes() {
+    test_subject_not_separate_from_body("Subject\n# comment", None);
+    test_subject_not_separate_from_body("Subject\n# comment\n", None);
+}
+
+#[test]
+fn the_label_covers_the_line_after_the_subject() {
+    let message = "Süße Äpfel\nBody\nMore body\n";
+    test_subject_not_separate_from_body(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectNotSeparateFromBody,
+            &message.into(),
+            Some(vec![("Missing blank line".to_string(), 14_usize, 4_usize)]),
+            Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+        )),
+    );
+}
+
 #[test]
 fn formatting() {
     let message = "An example commit
@@ -0,0 +1,105 @@
+//! Check for a subject that is only a ticket reference
+
+use mit_commit::CommitMessage;
+use regex::Regex;
+
+use crate::{
+    checks::comments::subject_offset_in_text,
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "subject-only-ticket";
+/// Description of the problem
+pub const ERROR: &str = "Your subject is only a ticket reference";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "It's important to describe the change in the subject, because \
+                            it's the first thing people see in the git log. A ticket reference \
+                            on its own means people have to go and look it up to find out what \
+                            the commit does.\n\nYou can fix this by adding a short description \
+                            of the change after the ticket reference";
+
+/// The patterns for ticket references that are checked by default
+pub const DEFAULT_PATTERNS: [&str; 4] = [
+    r"[A-Z]{2,}-[0-9]+",
+    r"#[0-9]+",
+    r"GH-[0-9]+",
+    r"\[#[0-9]+\]",
+];
+
+lazy_static! {
+    static ref DEFAULT_CONFIG: SubjectOnlyTicketConfig = SubjectOnlyTicketConfig::default();
+}
+
+/// Configuration for what counts as a ticket reference
+#[derive(Debug, Clone)]
+pub struct SubjectOnlyTicketConfig {
+    /// Patterns for a single ticket reference
+    ///
+    /// A subject fails if every word in it matches one of these in full.
+    pub patterns: Vec<Regex>,
+}
+
+impl Default for SubjectOnlyTicketConfig {
+    fn default() -> Self {
+        Self {
+            patterns: DEFAULT_PATTERNS
+                .iter()
+                .map(|pattern| Regex::new(pattern).unwrap())
+                .collect(),
+        }
+    }
+}
+
+fn is_ticket(word: &str, config: &SubjectOnlyTicketConfig) -> bool {
+    config.patterns.iter().any(|pattern| {
+        pattern
+            .find(word)
+            .is_some_and(|found| found.start() == 0 && found.end() == word.len())
+    })
+}
+
+fn has_problem(subject: &str, config: &SubjectOnlyTicketConfig) -> bool {
+    let mut words = subject
+        .split(|ch: char| ch.is_whitespace() || ch == ',')
+        .filter(|word| !word.is_empty())
+        .peekable();
+
+    words.peek().is_some() && words.all(|word| is_ticket(word, config))
+}
+
+/// Lint the commit message, returning a [`Problem`] if the subject is only a
+/// ticket reference
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &DEFAULT_CONFIG)
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if the subject is only a ticket reference
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &SubjectOnlyTicketConfig,
+) -> Option<Problem> {
+    let subject = commit_message.get_subject().to_string();
+    let trimmed = subject.trim();
+    if !has_problem(trimmed, config) {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::SubjectOnlyTicket,
+        commit_message,
+        Some(vec![(
+            "No description".to_string(),
+            subject_offset_in_text(
+                &String::from(commit_message.clone()),
+                commit_message.get_comment_char(),
+                subject.len() - subject.trim_start().len(),
+            ),
+            trimmed.len(),
+        )]),
+        Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+    ))
+}
@@ -0,0 +1,165 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+use regex::Regex;
+
+use super::subject_only_ticket::{
+    lint,
+    lint_with_config,
+    SubjectOnlyTicketConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+#[test]
+fn jira_key_only_fails() {
+    let message = "JIRA-123
+";
+    run_test(message, Some(&expected_problem(message, 0, 8)));
+}
+
+#[test]
+fn github_id_only_fails() {
+    let message = "#42
+
+; A comment
+";
+    run_test(message, Some(&expected_problem(message, 0, 3)));
+}
+
+#[test]
+fn several_tickets_fail() {
+    let message = " JIRA-123, GH-42 [#12345678] ";
+    run_test(message, Some(&expected_problem(message, 1, 27)));
+}
+
+#[test]
+fn comments_before_the_subject_are_skipped_over() {
+    let message = "# A comment
+JIRA-123
+";
+    run_test(message, Some(&expected_problem(message, 12, 8)));
+}
+
+#[test]
+fn ticket_with_description_passes() {
+    run_test("JIRA-123 Fix login", None);
+}
+
+#[test]
+fn description_without_ticket_passes() {
+    run_test("Fix login", None);
+}
+
+#[test]
+fn empty_subject_passes() {
+    run_test("", None);
+}
+
+#[test]
+fn patterns_can_be_configured() {
+    let config = SubjectOnlyTicketConfig {
+        patterns: vec![Regex::new("TICKET[0-9]+").unwrap()],
+    };
+
+    assert!(lint_with_config(&CommitMessage::from("TICKET123"), &config).is_some());
+    assert!(lint_with_config(&CommitMessage::from("JIRA-123"), &config).is_none());
+}
+
+#[test]
+fn formatting() {
+    let message = "JIRA-123
+
+This is an example commit
+";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "SubjectOnlyTicket (https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines)
+
+  x Your subject is only a ticket reference
+   ,-[1:1]
+ 1 | JIRA-123
+   : ^^^^|^^^
+   :     `-- No description
+ 2 | 
+   `----
+  help: It's important to describe the change in the subject, because it's the
+        first thing people see in the git log. A ticket reference on its own
+        means people have to go and look it up to find out what the commit
+        does.
+        
+        You can fix this by adding a short description of the change after the
+        ticket reference
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn expected_problem(message: &str, offset: usize, length: usize) -> Problem {
+    Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::SubjectOnlyTicket,
+        &message.into(),
+        Some(vec![("No description".to_string(), offset, length)]),
+        Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+    )
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(ticket: u32, description: String) -> TestResult {
+    if description.contains('\n')
+        || !description
+            .chars()
+            .any(|ch| ch.is_alphabetic() && ch.is_lowercase())
+    {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("JIRA-{ticket} {description}"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(tickets: Vec<u32>) -> TestResult {
+    if tickets.is_empty() {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "{}\n\nSome body",
+        tickets
+            .iter()
+            .map(|ticket| format!("GH-{ticket}"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -0,0 +1,92 @@
+//! Check for whitespace at the start or end of the subject
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{paragraphs, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "subject-whitespace";
+/// Description of the problem
+pub const ERROR: &str = "Your subject has whitespace at the start or end";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Whitespace around the subject is invisible in most editors, but \
+                            it pushes the subject out of line in the log and can stop other \
+                            checks on the subject from working.\n\nYou can fix this by removing \
+                            the spaces or tabs from the start and end of the subject";
+
+/// Get the offset and text of the subject line
+fn subject_line(commit_text: &str, comment_char: Option<char>) -> Option<(usize, &str)> {
+    paragraphs(commit_text, comment_char)
+        .into_iter()
+        .flatten()
+        .next()
+}
+
+/// Lint the commit message, returning a [`Problem`] labelling any whitespace
+/// at the start or end of the subject
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_comment_char(commit_message, commit_message.get_comment_char())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    let commit_text = text_above_scissors(commit_message);
+    let (offset, subject) = subject_line(&commit_text, comment_char)?;
+    let leading = subject.len() - subject.trim_start().len();
+    let trailing = subject.len() - subject.trim_end().len();
+
+    let mut labels = vec![];
+    if leading > 0 {
+        labels.push(("Leading whitespace".to_string(), offset, leading));
+    }
+    if trailing > 0 {
+        labels.push((
+            "Trailing whitespace".to_string(),
+            offset + subject.len() - trailing,
+            trailing,
+        ));
+    }
+    if labels.is_empty() {
+        return None;
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::SubjectWhitespace,
+        commit_message,
+        Some(labels),
+        None,
+    ))
+}
+
+/// Trim the whitespace from the start and end of the subject
+///
+/// The rest of the message is left as it is.
+#[must_use]
+pub fn fix(commit_message: &CommitMessage<'_>) -> CommitMessage<'static> {
+    let commit_text = String::from(commit_message.clone());
+    let comment_char = commit_message.get_comment_char();
+    let above_scissors = text_above_scissors(commit_message);
+
+    match subject_line(&above_scissors, comment_char) {
+        Some((offset, subject)) => CommitMessage::from(format!(
+            "{}{}{}",
+            &commit_text[..offset],
+            subject.trim(),
+            &commit_text[offset + subject.len()..]
+        )),
+        None => CommitMessage::from(commit_text),
+    }
+}
@@ -0,0 +1,172 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::subject_whitespace::{fix, lint, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Lint, Problem};
+
+#[test]
+fn trimmed_subject_passes() {
+    run_test("Add login\n\n  An indented body\n", None);
+}
+
+#[test]
+fn leading_whitespace_fails() {
+    let message = "  Add login\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectWhitespace,
+            &message.into(),
+            Some(vec![("Leading whitespace".to_string(), 0, 2)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn trailing_whitespace_fails() {
+    let message = "Add login \t\n\nSome body content\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectWhitespace,
+            &message.into(),
+            Some(vec![("Trailing whitespace".to_string(), 9, 2)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn leading_and_trailing_whitespace_are_both_labelled() {
+    let message = "# A comment\n Add login  \n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::SubjectWhitespace,
+            &message.into(),
+            Some(vec![
+                ("Leading whitespace".to_string(), 12, 1),
+                ("Trailing whitespace".to_string(), 22, 2),
+            ]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn empty_messages_pass() {
+    run_test("   \n", None);
+}
+
+#[test]
+fn fix_trims_the_subject() {
+    let message = CommitMessage::from("# A comment\n  Add login  \n\n  An indented body \n");
+
+    assert_eq!(
+        String::from(fix(&message)),
+        "# A comment\nAdd login\n\n  An indented body \n"
+    );
+}
+
+#[test]
+fn fix_is_idempotent() {
+    let message = CommitMessage::from("  Add login  \n\nSome body content\n");
+    let fixed = fix(&message);
+
+    assert_eq!(fix(&fixed), fixed);
+    assert_eq!(lint(&fixed), None);
+}
+
+#[test]
+fn fix_is_available_from_the_lint() {
+    let message = CommitMessage::from("  Add login\n");
+
+    assert_eq!(
+        Lint::SubjectWhitespace.fix(&message),
+        Some(CommitMessage::from("Add login\n"))
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "  Add login \n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "SubjectWhitespace
+
+  x Your subject has whitespace at the start or end
+   ,----
+ 1 |   Add login 
+   : ^|         |
+   :  |         `-- Trailing whitespace
+   :  `-- Leading whitespace
+   `----
+  help: Whitespace around the subject is invisible in most editors, but it
+        pushes the subject out of line in the log and can stop other checks on
+        the subject from working.
+        
+        You can fix this by removing the spaces or tabs from the start and end
+        of the subject
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(subject: String) -> TestResult {
+    let subject = subject.trim();
+    if subject.contains('\n') || subject.starts_with('#') {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("{subject}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn fixed_messages_pass(subject: String, padding: usize) -> TestResult {
+    if subject.contains('\n') || subject.trim().is_empty() || subject.trim().starts_with('#') {
+        return TestResult::discard();
+    }
+    let padding = " ".repeat(padding % 4 + 1);
+    let message = CommitMessage::from(format!("{padding}{subject}{padding}\n"));
+    if lint(&message).is_none() {
+        return TestResult::failed();
+    }
+    let result = lint(&fix(&message));
+    TestResult::from_bool(result.is_none())
+}
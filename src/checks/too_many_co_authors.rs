@@ -0,0 +1,101 @@
+//! Check for too many co-authors
+
+use std::option::Option::None;
+
+use mit_commit::CommitMessage;
+
+use crate::model::{Code, Problem};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "too-many-co-authors";
+/// Description of the problem
+pub const ERROR: &str = "Your commit message has too many co-authors";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "A long list of co-authors is often left over from a template or \
+                            an earlier commit, and credits people who didn't work on this \
+                            change.\n\nYou can fix this by removing the `Co-authored-by` trailers \
+                            for anyone who didn't work on it";
+
+const CO_AUTHOR_KEY: &str = "Co-authored-by";
+
+/// Configuration for how many co-authors a commit may have
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TooManyCoAuthorsConfig {
+    /// The maximum number of `Co-authored-by` trailers allowed
+    pub max: usize,
+}
+
+impl Default for TooManyCoAuthorsConfig {
+    fn default() -> Self {
+        Self { max: 3 }
+    }
+}
+
+/// Lint the commit message, returning a [`Problem`] if there are more than 3
+/// co-authors
+pub fn lint(commit: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit, &TooManyCoAuthorsConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] labelling the first co-author over the configured maximum
+///
+/// The token is matched ignoring case, like git does.
+pub fn lint_with_config(
+    commit: &CommitMessage<'_>,
+    config: &TooManyCoAuthorsConfig,
+) -> Option<Problem> {
+    let trailers: Vec<(String, bool)> = commit
+        .get_trailers()
+        .iter()
+        .cloned()
+        .map(|trailer| {
+            let is_co_author = trailer.get_key().eq_ignore_ascii_case(CO_AUTHOR_KEY);
+            (String::from(trailer).trim_end().to_string(), is_co_author)
+        })
+        .collect();
+    if trailers
+        .iter()
+        .filter(|(_, is_co_author)| *is_co_author)
+        .count()
+        <= config.max
+    {
+        return None;
+    }
+
+    let commit_text = String::from(commit.clone());
+    let mut matched = 0;
+    let mut co_authors = 0;
+    let mut offset = 0;
+    let mut first_extra_co_author = None;
+    for line in commit_text.split_inclusive('\n') {
+        let line_offset = offset;
+        offset += line.len();
+        let line = line.trim_end();
+
+        if let Some((trailer, is_co_author)) = trailers.get(matched) {
+            if trailer == line {
+                matched += 1;
+                co_authors += usize::from(*is_co_author);
+                if co_authors > config.max {
+                    first_extra_co_author = Some((line_offset, line.len()));
+                    break;
+                }
+            }
+        }
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::TooManyCoAuthors,
+        commit,
+        first_extra_co_author.map(|(offset, length)| {
+            vec![(format!("More than {} co-authors", config.max), offset, length)]
+        }),
+        Some(
+            "https://docs.github.com/en/pull-requests/committing-changes-to-your-project/creating-and-editing-commits/creating-a-commit-with-multiple-authors"
+                .to_string(),
+        ),
+    ))
+}
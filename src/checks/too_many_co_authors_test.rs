@@ -0,0 +1,197 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::too_many_co_authors::{
+    lint,
+    lint_with_config,
+    TooManyCoAuthorsConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+const URL: &str = "https://docs.github.com/en/pull-requests/committing-changes-to-your-project/creating-and-editing-commits/creating-a-commit-with-multiple-authors";
+
+#[test]
+fn commit_without_trailers() {
+    run_test(
+        "An example commit
+
+This is an example commit without any trailers
+",
+        None,
+    );
+}
+
+#[test]
+fn other_trailers_are_not_counted() {
+    run_test(
+        "An example commit
+
+This is an example commit
+
+Co-authored-by: Billie Thompson <billie@example.com>
+Relates-to: #1
+Relates-to: #2
+Relates-to: #3
+Signed-off-by: Billie Thompson <billie@example.com>
+",
+        None,
+    );
+}
+
+#[test]
+fn two_co_authors_pass_a_limit_of_two() {
+    let message = CommitMessage::from(
+        "An example commit
+
+This is an example commit
+
+Co-authored-by: Billie Thompson <billie@example.com>
+Co-authored-by: Someone Else <someone@example.com>
+",
+    );
+
+    assert_eq!(
+        lint_with_config(&message, &TooManyCoAuthorsConfig { max: 2 }),
+        None
+    );
+}
+
+#[test]
+fn three_co_authors_fail_a_limit_of_two() {
+    let message = CommitMessage::from(
+        "An example commit
+
+This is an example commit
+
+Co-authored-by: Billie Thompson <billie@example.com>
+Relates-to: #1
+co-authored-by: Someone Else <someone@example.com>
+Co-authored-by: Anyone Else <anyone@example.com>
+",
+    );
+
+    assert_eq!(
+        lint_with_config(&message, &TooManyCoAuthorsConfig { max: 2 }),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::TooManyCoAuthors,
+            &message,
+            Some(vec![("More than 2 co-authors".to_string(), 165, 48)]),
+            Some(URL.to_string()),
+        ))
+    );
+}
+
+#[test]
+fn co_authors_after_the_scissors_are_not_counted() {
+    run_test(
+        "An example commit
+
+This is an example commit
+
+Co-authored-by: A <a@example.com>
+Co-authored-by: B <b@example.com>
+Co-authored-by: C <c@example.com>
+# ------------------------ >8 ------------------------
+# Do not modify or remove the line above.
+# Everything below it will be ignored.
+Co-authored-by: D <d@example.com>
+",
+        None,
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "An example commit
+
+This is an example commit
+
+Co-authored-by: A <a@example.com>
+Co-authored-by: B <b@example.com>
+Co-authored-by: C <c@example.com>
+Co-authored-by: D <d@example.com>
+";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "TooManyCoAuthors (https://docs.github.com/en/pull-requests/committing-changes-to-your-project/creating-and-editing-commits/creating-a-commit-with-multiple-authors)
+
+  x Your commit message has too many co-authors
+   ,-[8:1]
+ 7 | Co-authored-by: C <c@example.com>
+ 8 | Co-authored-by: D <d@example.com>
+   : ^^^^^^^^^^^^^^^^|^^^^^^^^^^^^^^^^
+   :                 `-- More than 3 co-authors
+   `----
+  help: A long list of co-authors is often left over from a template or an
+        earlier commit, and credits people who didn't work on this change.
+        
+        You can fix this by removing the `Co-authored-by` trailers for anyone
+        who didn't work on it
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn co_authors(ids: &[u16]) -> String {
+    ids.iter()
+        .map(|id| format!("Co-authored-by: Someone {id} <someone{id}@example.com>"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(ids: Vec<u16>) -> TestResult {
+    if ids.len() > 3 {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body\n\n{}",
+        co_authors(&ids)
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(ids: Vec<u16>) -> TestResult {
+    if ids.len() <= 3 {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body\n\n{}",
+        co_authors(&ids)
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
@@ -0,0 +1,81 @@
+//! Check for too many trailers
+
+use std::option::Option::None;
+
+use miette::SourceOffset;
+use mit_commit::CommitMessage;
+
+use crate::model::{Code, Problem};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "too-many-trailers";
+/// Description of the problem
+pub const ERROR: &str = "Your commit message has too many trailers";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Keeping the number of trailers small keeps the footer of the \
+                            commit tidy and easy to read.\n\nYou can fix this by removing any \
+                            trailers that aren't needed";
+
+/// Configuration for how many trailers a commit may have
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TooManyTrailersConfig {
+    /// The maximum number of trailers allowed
+    pub max: usize,
+}
+
+impl Default for TooManyTrailersConfig {
+    fn default() -> Self {
+        Self { max: 5 }
+    }
+}
+
+/// Lint the commit message, returning a [`Problem`] if there are more than 5
+/// trailers
+pub fn lint(commit: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit, &TooManyTrailersConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if there are more trailers than the configured maximum
+pub fn lint_with_config(
+    commit: &CommitMessage<'_>,
+    config: &TooManyTrailersConfig,
+) -> Option<Problem> {
+    let trailers = commit.get_trailers();
+    if trailers.len() <= config.max {
+        return None;
+    }
+
+    let commit_text = String::from(commit.clone());
+    let trailer_lines: Vec<String> = trailers
+        .iter()
+        .cloned()
+        .map(|trailer| String::from(trailer).trim_end().to_string())
+        .collect();
+    let mut matched = 0;
+    let first_extra_trailer = commit_text.lines().enumerate().find(|(_, line)| {
+        if trailer_lines
+            .get(matched)
+            .is_some_and(|trailer| trailer == line.trim_end())
+        {
+            matched += 1;
+        }
+
+        matched > config.max
+    });
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::TooManyTrailers,
+        commit,
+        first_extra_trailer.map(|(line_index, line)| {
+            vec![(
+                format!("More than {} trailers", config.max),
+                SourceOffset::from_location(&commit_text, line_index + 1, 1).offset(),
+                line.len(),
+            )]
+        }),
+        Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+    ))
+}
@@ -0,0 +1,206 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::too_many_trailers::{
+    lint,
+    lint_with_config,
+    TooManyTrailersConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+#[test]
+fn commit_without_trailers() {
+    run_test(
+        "An example commit
+
+This is an example commit without any trailers
+",
+        None,
+    );
+}
+
+#[test]
+fn at_the_limit_passes() {
+    run_test(
+        "An example commit
+
+This is an example commit
+
+Relates-to: #1
+Relates-to: #2
+Relates-to: #3
+Relates-to: #4
+Relates-to: #5
+",
+        None,
+    );
+}
+
+#[test]
+fn over_the_limit_fails() {
+    let message = "An example commit
+
+This is an example commit
+
+Relates-to: #1
+Relates-to: #2
+Relates-to: #3
+Signed-off-by: Billie Thompson <email@example.com>
+Co-authored-by: Billie Thompson <email@example.com>
+Relates-to: #4
+Relates-to: #5
+";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::TooManyTrailers,
+            &message.into(),
+            Some(vec![("More than 5 trailers".to_string(), 194, 14)]),
+            Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn trailers_after_the_scissors_are_not_counted() {
+    run_test(
+        "An example commit
+
+This is an example commit
+
+Relates-to: #1
+Relates-to: #2
+Relates-to: #3
+Relates-to: #4
+Relates-to: #5
+# ------------------------ >8 ------------------------
+# Do not modify or remove the line above.
+# Everything below it will be ignored.
+Relates-to: #6
+Relates-to: #7
+",
+        None,
+    );
+}
+
+#[test]
+fn max_can_be_configured() {
+    let message = CommitMessage::from(
+        "An example commit
+
+This is an example commit
+
+Relates-to: #1
+Relates-to: #2
+",
+    );
+
+    assert!(lint_with_config(&message, &TooManyTrailersConfig { max: 2 }).is_none());
+    assert_eq!(
+        lint_with_config(&message, &TooManyTrailersConfig { max: 1 }),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::TooManyTrailers,
+            &message,
+            Some(vec![("More than 1 trailers".to_string(), 61, 14)]),
+            Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+        ))
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "An example commit
+
+This is an example commit
+
+Relates-to: #1
+Relates-to: #2
+Relates-to: #3
+Relates-to: #4
+Relates-to: #5
+Relates-to: #6
+";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "TooManyTrailers (https://git-scm.com/docs/git-interpret-trailers)
+
+  x Your commit message has too many trailers
+    ,-[10:1]
+  9 | Relates-to: #5
+ 10 | Relates-to: #6
+    : ^^^^^^^|^^^^^^
+    :        `-- More than 5 trailers
+    `----
+  help: Keeping the number of trailers small keeps the footer of the commit
+        tidy and easy to read.
+        
+        You can fix this by removing any trailers that aren't needed
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(ids: Vec<u16>) -> TestResult {
+    if ids.len() > 5 {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body\n\n{}",
+        ids.iter()
+            .map(|id| format!("Relates-to: #{id}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(ids: Vec<u16>) -> TestResult {
+    if ids.len() <= 5 {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body\n\n{}",
+        ids.iter()
+            .map(|id| format!("Relates-to: #{id}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    ));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
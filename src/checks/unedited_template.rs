@@ -0,0 +1,89 @@
+//! Check for a commit message that is still the unedited commit template
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::uncommented_text,
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "unedited-template";
+/// Description of the problem
+pub const ERROR: &str = "Your commit message is the unedited commit template";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "The commit template is there to remind you what to write, it \
+                            doesn't say anything about this commit.\n\nYou can fix this by \
+                            replacing the template with a description of your change";
+
+/// Configuration for the commit template to compare against
+///
+/// This is usually the contents of the file in git's `commit.template`
+/// setting. When it's empty the lint never fails.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct UneditedTemplateConfig {
+    /// The contents of the commit template
+    pub template: String,
+}
+
+fn normalise(text: &str, comment_char: Option<char>) -> String {
+    text.lines()
+        .filter(|line| comment_char.is_none_or(|comment_char| !line.starts_with(comment_char)))
+        .flat_map(str::split_whitespace)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Lint the commit message, returning a [`Problem`] if it is the unedited
+/// commit template
+///
+/// There's no template configured by default, so this never fails. Use
+/// [`lint_with_config`] to give it one.
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &UneditedTemplateConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] if it is the unedited commit template
+///
+/// Comments and differences in whitespace are ignored when comparing the
+/// message with the template.
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &UneditedTemplateConfig,
+) -> Option<Problem> {
+    // Fall back to git's default comment character, as a message with no
+    // comments gives us nothing to guess from
+    let comment_char = commit_message.get_comment_char().or(Some('#'));
+    let template = normalise(&config.template, comment_char);
+    if template.is_empty()
+        || template != normalise(&uncommented_text(commit_message, comment_char), None)
+    {
+        return None;
+    }
+
+    let commit_text = String::from(commit_message.clone());
+    let mut offset = 0;
+    let mut subject = None;
+    for line in commit_text.split_inclusive('\n') {
+        let content = line.trim_end();
+        let is_comment = comment_char.is_some_and(|comment_char| line.starts_with(comment_char));
+        if !is_comment && !content.trim().is_empty() {
+            subject = Some((offset, content.len()));
+            break;
+        }
+        offset += line.len();
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::UneditedTemplate,
+        commit_message,
+        subject.map(|(offset, length)| vec![("Unedited template".to_string(), offset, length)]),
+        Some(
+            "https://git-scm.com/docs/git-config#Documentation/git-config.txt-committemplate"
+                .to_string(),
+        ),
+    ))
+}
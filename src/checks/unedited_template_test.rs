@@ -0,0 +1,180 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::unedited_template::{
+    lint,
+    lint_with_config,
+    UneditedTemplateConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+const TEMPLATE: &str = "Subject
+
+Why this change is needed
+
+# Lines starting with '#' are ignored
+";
+
+#[test]
+fn no_template_passes() {
+    run_test("Subject\n\nWhy this change is needed\n", None);
+}
+
+#[test]
+fn edited_template_passes() {
+    run_test_with_template(
+        "Fix the parser
+
+It was dropping trailers with a blank value
+
+# Lines starting with '#' are ignored
+",
+        None,
+    );
+}
+
+#[test]
+fn unchanged_template_fails() {
+    let message = "Subject
+
+Why this change is needed
+
+# Lines starting with '#' are ignored
+# On branch main
+";
+    run_test_with_template(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::UneditedTemplate,
+            &message.into(),
+            Some(vec![("Unedited template".to_string(), 0, 7)]),
+            Some(
+                "https://git-scm.com/docs/git-config#Documentation/git-config.txt-committemplate"
+                    .to_string(),
+            ),
+        )),
+    );
+}
+
+#[test]
+fn whitespace_differences_are_ignored() {
+    let message = "# A comment before the subject
+  Subject
+
+Why this change   is needed
+
+
+";
+    run_test_with_template(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::UneditedTemplate,
+            &message.into(),
+            Some(vec![("Unedited template".to_string(), 31, 9)]),
+            Some(
+                "https://git-scm.com/docs/git-config#Documentation/git-config.txt-committemplate"
+                    .to_string(),
+            ),
+        )),
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "Subject\n\nWhy this change is needed\n";
+    let problem = lint_with_config(
+        &CommitMessage::from(message.to_string()),
+        &UneditedTemplateConfig {
+            template: TEMPLATE.into(),
+        },
+    );
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "UneditedTemplate \
+                    (https://git-scm.com/docs/git-config#Documentation/git-config.txt-committemplate)
+
+  x Your commit message is the unedited commit template
+   ,-[1:1]
+ 1 | Subject
+   : ^^^|^^^
+   :    `-- Unedited template
+ 2 | 
+   `----
+  help: The commit template is there to remind you what to write, it doesn't
+        say anything about this commit.
+        
+        You can fix this by replacing the template with a description of your
+        change
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn run_test_with_template(message: &str, expected: Option<&Problem>) {
+    let actual = &lint_with_config(
+        &CommitMessage::from(message),
+        &UneditedTemplateConfig {
+            template: TEMPLATE.into(),
+        },
+    );
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(message: String) -> TestResult {
+    let result = lint(&CommitMessage::from(message));
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(subject: String, body: String) -> TestResult {
+    if subject.trim().is_empty()
+        || subject.contains('\n')
+        || subject.starts_with('#')
+        || body.lines().any(|line| line.starts_with('#'))
+    {
+        return TestResult::discard();
+    }
+    let template = format!("{subject}\n\n{body}\n# A comment\n");
+    let result = lint_with_config(
+        &CommitMessage::from(template.clone()),
+        &UneditedTemplateConfig { template },
+    );
+    TestResult::from_bool(result.is_some())
+}
@@ -0,0 +1,87 @@
+//! Check for trailers with a token that isn't allowed
+
+use std::collections::HashSet;
+
+use mit_commit::CommitMessage;
+
+use crate::model::{Code, Problem};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "unknown-footer-token";
+/// Description of the problem
+pub const ERROR: &str = "Your commit message has a trailer that isn't allowed";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Sticking to a known set of trailers keeps the footer of the \
+                            commit consistent, so tools like changelog generators can rely on \
+                            it.\n\nYou can fix this by using one of the allowed trailers, or \
+                            removing the trailer";
+
+/// Configuration for which trailer tokens are allowed
+///
+/// When there are no tokens the lint never fails.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UnknownFooterTokenConfig {
+    /// The allowed tokens, like `Refs` or `Signed-off-by`
+    pub allowed: HashSet<String>,
+}
+
+/// Lint the commit message, returning a [`Problem`] if it has a trailer that
+/// isn't allowed
+///
+/// There are no tokens allowed by default, so this never fails. Use
+/// [`lint_with_config`] to give it some.
+pub fn lint(commit: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit, &UnknownFooterTokenConfig::default())
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] labelling every trailer with a token that isn't allowed
+pub fn lint_with_config(
+    commit: &CommitMessage<'_>,
+    config: &UnknownFooterTokenConfig,
+) -> Option<Problem> {
+    if config.allowed.is_empty() {
+        return None;
+    }
+
+    let trailers: Vec<(String, bool)> = commit
+        .get_trailers()
+        .iter()
+        .cloned()
+        .map(|trailer| {
+            let allowed = config.allowed.contains(&trailer.get_key());
+            (String::from(trailer).trim_end().to_string(), allowed)
+        })
+        .collect();
+    if trailers.iter().all(|(_, allowed)| *allowed) {
+        return None;
+    }
+
+    let commit_text = String::from(commit.clone());
+    let mut matched = 0;
+    let mut offset = 0;
+    let mut labels = vec![];
+    for line in commit_text.split_inclusive('\n') {
+        let line_offset = offset;
+        offset += line.len();
+        let line = line.trim_end();
+
+        if let Some((trailer, allowed)) = trailers.get(matched) {
+            if trailer == line {
+                matched += 1;
+                if !allowed {
+                    labels.push(("Unknown token".to_string(), line_offset, line.len()));
+                }
+            }
+        }
+    }
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::UnknownFooterToken,
+        commit,
+        Some(labels),
+        Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+    ))
+}
@@ -0,0 +1,159 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::unknown_footer_token::{
+    lint,
+    lint_with_config,
+    UnknownFooterTokenConfig,
+    ERROR,
+    HELP_MESSAGE,
+};
+use crate::model::{Code, Problem};
+
+fn changelog_config() -> UnknownFooterTokenConfig {
+    UnknownFooterTokenConfig {
+        allowed: [
+            "Refs",
+            "Closes",
+            "Co-authored-by",
+            "Signed-off-by",
+            "BREAKING CHANGE",
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect(),
+    }
+}
+
+#[test]
+fn no_allowed_tokens_passes() {
+    assert_eq!(
+        lint(&CommitMessage::from(
+            "An example commit\n\nSome body content\n\nFixes: #1\n"
+        )),
+        None
+    );
+}
+
+#[test]
+fn allowed_tokens_pass() {
+    run_test(
+        "An example commit
+
+Some body content
+
+Refs: #1
+BREAKING CHANGE: The API has changed
+Co-authored-by: Someone Else <someone@example.com>
+Signed-off-by: Someone <someone@example.com>
+",
+        None,
+    );
+}
+
+#[test]
+fn unknown_token_fails() {
+    let message = "An example commit
+
+Some body content
+
+Refs: #1
+Fixes: #2
+";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::UnknownFooterToken,
+            &message.into(),
+            Some(vec![("Unknown token".to_string(), 47, 9)]),
+            Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+        )),
+    );
+}
+
+#[test]
+fn formatting() {
+    let message = "An example commit
+
+Some body content
+
+Refs: #1
+Fixes: #2
+";
+    let problem = lint_with_config(
+        &CommitMessage::from(message.to_string()),
+        &changelog_config(),
+    );
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "UnknownFooterToken (https://git-scm.com/docs/git-interpret-trailers)
+
+  x Your commit message has a trailer that isn't allowed
+   ,-[6:1]
+ 5 | Refs: #1
+ 6 | Fixes: #2
+   : ^^^^|^^^^
+   :     `-- Unknown token
+   `----
+  help: Sticking to a known set of trailers keeps the footer of the commit
+        consistent, so tools like changelog generators can rely on it.
+        
+        You can fix this by using one of the allowed trailers, or removing the
+        trailer
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint_with_config(&CommitMessage::from(message), &changelog_config());
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(token: usize, value: String) -> TestResult {
+    if value.trim().is_empty() || value.contains('\n') {
+        return TestResult::discard();
+    }
+    let token = ["Refs", "Closes", "Co-authored-by", "Signed-off-by"][token % 4];
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body content\n\n{token}: Value {value}\n"
+    ));
+    let result = lint_with_config(&message, &changelog_config());
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(token: String) -> TestResult {
+    if !token.chars().all(|x| x.is_ascii_alphabetic()) {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body content\n\nX{token}: Some value\n"
+    ));
+    let result = lint_with_config(&message, &changelog_config());
+    TestResult::from_bool(result.is_some())
+}
@@ -0,0 +1,142 @@
+//! Check for subjects marking the commit as a work in progress
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::comments::{paragraphs, text_above_scissors},
+    model::{Code, Problem},
+};
+
+/// Canonical lint ID
+pub const CONFIG: &str = "work-in-progress";
+/// Description of the problem
+pub const ERROR: &str = "Your commit is marked as a work in progress";
+/// Advice on how to correct the problem
+pub const HELP_MESSAGE: &str = "Commits marked as a work in progress, or as a `fixup!` or \
+                            `squash!` of another commit, are meant to be tidied up before they're \
+                            shared. Left in the history, they make it harder to follow.\n\nYou \
+                            can fix this by squashing the commit into the one it belongs with, \
+                            or rewording it to describe the change";
+
+/// The markers `git commit --fixup` and `--squash` put at the start of the
+/// subject, for `git rebase --autosquash` to find
+const AUTOSQUASH_MARKERS: [&str; 3] = ["fixup!", "squash!", "amend!"];
+
+const WIP_MARKERS: [&str; 2] = ["[WIP]", "WIP"];
+
+/// Configuration for which work in progress markers are allowed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WorkInProgressConfig {
+    /// Allow `fixup!`, `squash!` and `amend!` subjects that name the commit
+    /// they belong with, like `fixup! Add login`, for teams that tidy up
+    /// with `git rebase --autosquash`
+    ///
+    /// A marker on its own, like `fixup!`, still fails, as there's nothing
+    /// for it to be squashed into.
+    pub allow_autosquash: bool,
+}
+
+/// Find a `fixup!` style marker at the start of the subject, and the subject
+/// of the commit it's for
+fn autosquash_marker(subject: &str) -> Option<(&str, &str)> {
+    AUTOSQUASH_MARKERS.iter().find_map(|marker| {
+        subject
+            .strip_prefix(marker)
+            .map(|target| (*marker, target.trim()))
+    })
+}
+
+/// Find a `WIP` marker at the start of the subject, in any case
+fn wip_marker(subject: &str) -> Option<usize> {
+    WIP_MARKERS.iter().find_map(|marker| {
+        let start = subject.get(..marker.len())?;
+        let is_whole_word = subject[marker.len()..]
+            .chars()
+            .next()
+            .is_none_or(|next| !next.is_alphanumeric());
+
+        (start.eq_ignore_ascii_case(marker) && is_whole_word).then_some(marker.len())
+    })
+}
+
+fn find_marker(subject: &str, config: &WorkInProgressConfig) -> Option<(String, usize, usize)> {
+    if let Some((marker, target)) = autosquash_marker(subject) {
+        return if !config.allow_autosquash {
+            Some(("Work in progress".to_string(), 0, marker.len()))
+        } else if target.is_empty() {
+            Some(("No commit to squash into".to_string(), 0, marker.len()))
+        } else {
+            None
+        };
+    }
+
+    wip_marker(subject).map(|length| ("Work in progress".to_string(), 0, length))
+}
+
+/// Lint the commit message, returning a [`Problem`] labelling a `WIP`,
+/// `fixup!`, `squash!`, or `amend!` marker at the start of the subject
+pub fn lint(commit_message: &CommitMessage<'_>) -> Option<Problem> {
+    lint_with_config(commit_message, &WorkInProgressConfig::default())
+}
+
+/// Lint the commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// Use this when you know the comment character git is using, rather than
+/// relying on the one guessed from the message. With `None`, comment lines
+/// are linted like any other line.
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+) -> Option<Problem> {
+    lint_with_options(
+        commit_message,
+        comment_char,
+        &WorkInProgressConfig::default(),
+    )
+}
+
+/// Lint the commit message with the given configuration, returning a
+/// [`Problem`] labelling the work in progress marker in the subject
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::checks::work_in_progress::{lint_with_config, WorkInProgressConfig};
+///
+/// let config = WorkInProgressConfig {
+///     allow_autosquash: true,
+/// };
+/// assert!(lint_with_config(&CommitMessage::from("fixup! Add login\n"), &config).is_none());
+/// assert!(lint_with_config(&CommitMessage::from("fixup!\n"), &config).is_some());
+/// assert!(lint_with_config(&CommitMessage::from("WIP: Add login\n"), &config).is_some());
+/// ```
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    config: &WorkInProgressConfig,
+) -> Option<Problem> {
+    lint_with_options(commit_message, commit_message.get_comment_char(), config)
+}
+
+fn lint_with_options(
+    commit_message: &CommitMessage<'_>,
+    comment_char: Option<char>,
+    config: &WorkInProgressConfig,
+) -> Option<Problem> {
+    let commit_text = text_above_scissors(commit_message);
+    let (subject_offset, subject) = paragraphs(&commit_text, comment_char)
+        .into_iter()
+        .flatten()
+        .next()?;
+    let (label, offset, length) = find_marker(subject.trim_end(), config)?;
+
+    Some(Problem::new(
+        ERROR.into(),
+        HELP_MESSAGE.into(),
+        Code::WorkInProgress,
+        commit_message,
+        Some(vec![(label, subject_offset + offset, length)]),
+        None,
+    ))
+}
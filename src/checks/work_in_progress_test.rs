@@ -0,0 +1,200 @@
+use std::option::Option::None;
+
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+use quickcheck::TestResult;
+
+use super::work_in_progress::{lint, lint_with_config, WorkInProgressConfig, ERROR, HELP_MESSAGE};
+use crate::model::{Code, Problem};
+
+#[test]
+fn normal_subjects_pass() {
+    run_test("Add login\n", None);
+}
+
+#[test]
+fn words_starting_with_wip_pass() {
+    run_test("Wipe the cache on logout\n", None);
+}
+
+#[test]
+fn markers_after_the_start_pass() {
+    run_test("Explain fixup! commits in the guide\n", None);
+}
+
+#[test]
+fn wip_fails() {
+    let message = "WIP: Add login\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::WorkInProgress,
+            &message.into(),
+            Some(vec![("Work in progress".to_string(), 0, 3)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn bracketed_lowercase_wip_fails() {
+    let message = "[wip] Add login\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::WorkInProgress,
+            &message.into(),
+            Some(vec![("Work in progress".to_string(), 0, 5)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn fixup_fails_by_default() {
+    let message = "fixup! Add login\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::WorkInProgress,
+            &message.into(),
+            Some(vec![("Work in progress".to_string(), 0, 6)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn squash_fails_by_default() {
+    let message = "squash! Add login\n";
+    run_test(
+        message,
+        Some(&Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::WorkInProgress,
+            &message.into(),
+            Some(vec![("Work in progress".to_string(), 0, 7)]),
+            None,
+        )),
+    );
+}
+
+#[test]
+fn fixup_with_a_subject_passes_when_autosquash_is_allowed() {
+    let config = WorkInProgressConfig {
+        allow_autosquash: true,
+    };
+
+    assert_eq!(
+        lint_with_config(&CommitMessage::from("fixup! Add login\n"), &config),
+        None
+    );
+    assert_eq!(
+        lint_with_config(&CommitMessage::from("squash! Add login\n"), &config),
+        None
+    );
+}
+
+#[test]
+fn bare_fixup_fails_when_autosquash_is_allowed() {
+    let config = WorkInProgressConfig {
+        allow_autosquash: true,
+    };
+    let message = "fixup!\n";
+
+    assert_eq!(
+        lint_with_config(&CommitMessage::from(message), &config),
+        Some(Problem::new(
+            ERROR.into(),
+            HELP_MESSAGE.into(),
+            Code::WorkInProgress,
+            &message.into(),
+            Some(vec![("No commit to squash into".to_string(), 0, 6)]),
+            None,
+        ))
+    );
+}
+
+#[test]
+fn wip_still_fails_when_autosquash_is_allowed() {
+    let config = WorkInProgressConfig {
+        allow_autosquash: true,
+    };
+
+    assert!(lint_with_config(&CommitMessage::from("WIP\n"), &config).is_some());
+}
+
+#[test]
+fn formatting() {
+    let message = "fixup! Add login\n";
+    let problem = lint(&CommitMessage::from(message.to_string()));
+    let actual = fmt_report(&Report::new(problem.unwrap()));
+    let expected = "WorkInProgress
+
+  x Your commit is marked as a work in progress
+   ,----
+ 1 | fixup! Add login
+   : ^^^|^^
+   :    `-- Work in progress
+   `----
+  help: Commits marked as a work in progress, or as a `fixup!` or `squash!` of
+        another commit, are meant to be tidied up before they're shared. Left
+        in the history, they make it harder to follow.
+        
+        You can fix this by squashing the commit into the one it belongs with,
+        or rewording it to describe the change
+"
+    .to_string();
+    assert_eq!(
+        actual, expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
+
+fn run_test(message: &str, expected: Option<&Problem>) {
+    let actual = &lint(&CommitMessage::from(message));
+    assert_eq!(
+        actual.as_ref(),
+        expected,
+        "Message {message:?} should have returned {expected:?}, found {actual:?}"
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn success_check(subject: String) -> TestResult {
+    if subject.contains(['\n', '\r']) {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("Add {subject}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn failure_check(subject: String) -> TestResult {
+    if subject.contains(['\n', '\r']) {
+        return TestResult::discard();
+    }
+    let message = CommitMessage::from(format!("fixup! {subject}\n"));
+    let result = lint(&message);
+    TestResult::from_bool(result.is_some())
+}
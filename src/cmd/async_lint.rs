@@ -46,7 +46,7 @@ use crate::model::{Lints, Problem};
 /// );
 /// ```
 pub async fn async_lint(commit_message: &CommitMessage<'_>, lints: Lints) -> Vec<Problem> {
-    stream::iter(lints.into_iter())
+    stream::iter(lints)
         .filter_map(|lint| future::ready(lint.lint(commit_message)))
         .collect::<Vec<Problem>>()
         .await
@@ -0,0 +1,30 @@
+use crate::model::Lints;
+
+/// Describe which lints will run
+///
+/// Lists every available lint, marking whether it is on or off in the given
+/// set. Useful for debugging configuration before linting anything.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_lint::{explain, Lint, Lints};
+///
+/// let actual = explain(&Lints::from(vec![Lint::NotEmojiLog]));
+/// assert!(actual.contains("[on]  not-emoji-log"));
+/// assert!(actual.contains("[off] duplicated-trailers"));
+/// ```
+#[must_use]
+pub fn explain(lints: &Lints) -> String {
+    lints
+        .explain()
+        .into_iter()
+        .map(|(lint, enabled)| {
+            if enabled {
+                format!("[on]  {lint}\n")
+            } else {
+                format!("[off] {lint}\n")
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,36 @@
+use crate::{
+    cmd::explain::explain,
+    model::{Lint, Lints},
+};
+
+#[test]
+fn every_lint_is_listed_with_whether_it_is_on() {
+    let lints = Lints::from(vec![
+        Lint::DuplicatedTrailers,
+        Lint::SubjectNotSeparateFromBody,
+    ]);
+
+    let actual = explain(&lints);
+
+    for lint in Lint::all_lints() {
+        let expected = if lints.clone().into_iter().any(|x| x == lint) {
+            format!("[on]  {}\n", lint.name())
+        } else {
+            format!("[off] {}\n", lint.name())
+        };
+        assert!(
+            actual.contains(&expected),
+            "Expected {:?} to contain {:?}",
+            actual,
+            expected
+        );
+    }
+    assert_eq!(actual.lines().count(), Lint::all_lints().count());
+}
+
+#[test]
+fn nothing_is_on_for_an_empty_set() {
+    let actual = explain(&Lints::from(vec![]));
+
+    assert!(actual.lines().all(|line| line.starts_with("[off] ")));
+}
@@ -0,0 +1,72 @@
+use futures::{future, stream, StreamExt};
+use mit_commit::CommitMessage;
+
+use crate::model::{Lint, Lints};
+
+/// Apply the fix for one lint, if it finds a problem and can fix it
+fn fix_one<'a>(lint: Lint, commit_message: CommitMessage<'a>) -> CommitMessage<'a> {
+    if lint.lint(&commit_message).is_none() {
+        return commit_message;
+    }
+
+    lint.fix(&commit_message).unwrap_or(commit_message)
+}
+
+/// Fix every problem that can be fixed automatically
+///
+/// Each lint that finds a problem and has a fix, see [`Lint::fix`], is fixed
+/// in turn, in the order the lints are declared in [`Lint`]. Each fix sees
+/// the message as the fixes before it left it. Problems without a fix are
+/// left as they are, so lint the result to find what's left.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{fix_all, lint, Lint, Lints};
+///
+/// let lints = Lints::new(
+///     vec![Lint::SubjectWhitespace, Lint::MissingFinalNewline]
+///         .into_iter()
+///         .collect(),
+/// );
+/// let fixed = fix_all(&CommitMessage::from("  Add login"), lints.clone());
+///
+/// assert_eq!(fixed, CommitMessage::from("Add login\n"));
+/// assert!(lint(&fixed, lints).is_empty());
+/// ```
+#[must_use]
+pub fn fix_all<'a>(commit_message: &CommitMessage<'a>, lints: Lints) -> CommitMessage<'a> {
+    lints
+        .into_iter()
+        .fold(commit_message.clone(), |fixed, lint| fix_one(lint, fixed))
+}
+
+/// Fix every problem that can be fixed automatically
+///
+/// The result is the same as [`fix_all`], but the fixes are applied as a
+/// future, so they can be run off the thread handling the UI.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{fix_all, fix_all_async, Lints};
+/// use tokio::runtime::Runtime;
+///
+/// let message = CommitMessage::from("  Add login");
+/// let rt = Runtime::new().unwrap();
+/// let actual = rt.block_on(async { fix_all_async(&message, Lints::available().clone()).await });
+///
+/// assert_eq!(actual, fix_all(&message, Lints::available().clone()));
+/// ```
+pub async fn fix_all_async<'a>(
+    commit_message: &CommitMessage<'a>,
+    lints: Lints,
+) -> CommitMessage<'a> {
+    stream::iter(lints)
+        .fold(commit_message.clone(), |fixed, lint| {
+            future::ready(fix_one(lint, fixed))
+        })
+        .await
+}
@@ -0,0 +1,71 @@
+use mit_commit::CommitMessage;
+
+use crate::{
+    cmd::{
+        fix_all::{fix_all, fix_all_async},
+        lint::lint,
+    },
+    model::{Lint, Lints},
+};
+
+fn several_fixable_problems() -> CommitMessage<'static> {
+    CommitMessage::from(format!(
+        "  Add login  \n\n{}",
+        "Some body content that goes on and on past the width of the body. ".repeat(3)
+    ))
+}
+
+fn fixable_lints() -> Lints {
+    Lints::from(vec![
+        Lint::BodyWiderThan72Characters,
+        Lint::SubjectWhitespace,
+        Lint::MissingFinalNewline,
+    ])
+}
+
+#[test]
+fn every_fixable_problem_is_fixed() {
+    let message = several_fixable_problems();
+    assert_eq!(lint(&message, fixable_lints()).len(), 3);
+
+    let fixed = fix_all(&message, fixable_lints());
+
+    assert_eq!(lint(&fixed, fixable_lints()), vec![]);
+}
+
+#[test]
+fn problems_without_a_fix_are_left_alone() {
+    let message = CommitMessage::from("  add login  \n");
+    let lints = Lints::from(vec![Lint::SubjectWhitespace, Lint::SubjectNotCapitalized]);
+
+    let fixed = fix_all(&message, lints.clone());
+
+    assert_eq!(fixed, CommitMessage::from("add login\n"));
+    assert_eq!(lint(&fixed, lints).len(), 1);
+}
+
+#[test]
+fn messages_without_problems_are_unchanged() {
+    let message = CommitMessage::from("Add login\n\nSome body content\n");
+
+    assert_eq!(fix_all(&message, Lints::available().clone()), message);
+}
+
+#[test]
+fn only_enabled_lints_are_fixed() {
+    let message = CommitMessage::from("  Add login  ");
+
+    let fixed = fix_all(&message, Lints::from(vec![Lint::MissingFinalNewline]));
+
+    assert_eq!(fixed, CommitMessage::from("  Add login  \n"));
+}
+
+#[tokio::test]
+async fn the_async_fixes_are_the_same_as_the_sync_ones() {
+    let message = several_fixable_problems();
+
+    let actual = fix_all_async(&message, fixable_lints()).await;
+
+    assert_eq!(actual, fix_all(&message, fixable_lints()));
+    assert_ne!(actual, message);
+}
@@ -1,9 +1,14 @@
 use mit_commit::CommitMessage;
 
-use crate::model::{Lints, Problem};
+use crate::model::{CustomLint, LintConfig, Lints, Problem};
 
 /// Lint a commit message
 ///
+/// The problems are in the order the lints are declared in
+/// [`Lint`](crate::Lint), which is the order of
+/// [`Lint::all_lints`](crate::Lint::all_lints), no matter what order the lints
+/// were enabled in. New lints are added to the end, so this order is stable.
+///
 /// # Examples
 ///
 /// ```rust
@@ -37,6 +42,9 @@ use crate::model::{Lints, Problem};
 /// ```
 #[must_use]
 pub fn lint(commit_message: &CommitMessage<'_>, lints: Lints) -> Vec<Problem> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("lint_commit", lints = ?lints.clone().names()).entered();
+
     lints
         .into_iter()
         .collect::<Vec<_>>()
@@ -44,3 +52,164 @@ pub fn lint(commit_message: &CommitMessage<'_>, lints: Lints) -> Vec<Problem> {
         .filter_map(|lint| lint.lint(commit_message))
         .collect::<Vec<Problem>>()
 }
+
+/// Lint a commit message, treating lines starting with `comment_char` as
+/// comments
+///
+/// The comment character is normally guessed from the message, which can be
+/// wrong when git's `core.commentChar` is set to something other than `#`.
+/// Pass the character git is using here to make sure lines like `#642` are
+/// linted as content rather than skipped as comments.
+///
+/// The problems are in the same order as [`lint`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "regex-lints")]
+/// # {
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{lint_with_comment_char, Lint, Lints};
+/// let actual = lint_with_comment_char(
+///     &CommitMessage::from("An example commit\n\n; A comment\n#642\n"),
+///     Lints::new(vec![Lint::GitHubIdMissing].into_iter().collect()),
+///     ';',
+/// );
+/// assert!(actual.is_empty());
+/// # }
+/// ```
+#[must_use]
+pub fn lint_with_comment_char(
+    commit_message: &CommitMessage<'_>,
+    lints: Lints,
+    comment_char: char,
+) -> Vec<Problem> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("lint_commit", lints = ?lints.clone().names()).entered();
+
+    lints
+        .into_iter()
+        .filter_map(|lint| lint.lint_with_comment_char(commit_message, comment_char))
+        .collect::<Vec<Problem>>()
+}
+
+/// Lint a commit message with the given [`LintConfig`]
+///
+/// Lints in [`LintConfig::skip_on_merge`] aren't run on merge commits, so a
+/// long `Merge pull request ...` subject can be let through while the same
+/// lint still runs on everything else. The problems are in the same order as
+/// [`lint`].
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "regex-lints")]
+/// # {
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{lint_with_config, Lint, LintConfig, Lints};
+/// let message = CommitMessage::from("An example commit\n\n# Relates-to: JRA-123\n");
+/// let lints = Lints::new(vec![Lint::JiraIssueKeyMissing].into_iter().collect());
+///
+/// assert_eq!(
+///     lint_with_config(&message, lints.clone(), &LintConfig::default()).len(),
+///     1
+/// );
+/// assert!(lint_with_config(
+///     &message,
+///     lints,
+///     &LintConfig {
+///         include_comments: true,
+///         ..LintConfig::default()
+///     }
+/// )
+/// .is_empty());
+/// # }
+/// ```
+#[must_use]
+pub fn lint_with_config(
+    commit_message: &CommitMessage<'_>,
+    lints: Lints,
+    config: &LintConfig,
+) -> Vec<Problem> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("lint_commit", lints = ?lints.clone().names()).entered();
+
+    lints
+        .into_iter()
+        .filter_map(|lint| lint.lint_with_config(commit_message, config))
+        .collect::<Vec<Problem>>()
+}
+
+/// Lint a commit message with the built-in lints, then with your own
+///
+/// The problems from the built-in lints come first, in the same order as
+/// [`lint`], followed by the problems from the custom lints in the order they
+/// were given.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{lint_with_custom, Code, CustomLint, Lint, Lints, Problem, ProblemBuilder};
+///
+/// struct NoLogin;
+///
+/// impl CustomLint for NoLogin {
+///     fn code(&self) -> Code {
+///         Code::NotConventionalCommit
+///     }
+///
+///     fn check(&self, commit: &CommitMessage<'_>) -> Option<Problem> {
+///         String::from(commit.get_subject())
+///             .contains("login")
+///             .then(|| {
+///                 ProblemBuilder::new(
+///                     "Your commit mentions logging in".to_string(),
+///                     "Describe the change without mentioning login".to_string(),
+///                     self.code(),
+///                     commit,
+///                 )
+///                 .build()
+///             })
+///     }
+/// }
+///
+/// let actual = lint_with_custom(
+///     &CommitMessage::from("fix the login form\n"),
+///     Lints::new(vec![Lint::SubjectNotCapitalized].into_iter().collect()),
+///     &[&NoLogin],
+/// );
+/// assert_eq!(
+///     actual.iter().map(Problem::code).collect::<Vec<_>>(),
+///     vec![&Code::SubjectNotCapitalized, &Code::NotConventionalCommit]
+/// );
+/// ```
+#[must_use]
+pub fn lint_with_custom(
+    commit_message: &CommitMessage<'_>,
+    lints: Lints,
+    custom: &[&dyn CustomLint],
+) -> Vec<Problem> {
+    let mut problems = lint(commit_message, lints);
+    problems.extend(custom.iter().filter_map(|lint| lint.check(commit_message)));
+    problems
+}
+
+/// Lint a commit message from a string
+///
+/// This saves building a [`CommitMessage`] when you only have the text of the
+/// message.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_lint::{lint_str, Code, Lints};
+///
+/// let actual = lint_str("x".repeat(73).as_str(), &Lints::default_enabled());
+/// assert_eq!(actual.len(), 1);
+/// assert_eq!(actual[0].code(), &Code::SubjectLongerThan72Characters);
+/// ```
+#[must_use]
+pub fn lint_str(message: &str, lints: &Lints) -> Vec<Problem> {
+    lint(&CommitMessage::from(message), lints.clone())
+}
@@ -0,0 +1,56 @@
+use mit_commit::CommitMessage;
+
+use crate::{cmd::lint::lint, model::Lints, report::GraphicalOptions};
+
+/// Lint a commit message and render any problems as one graphical report
+///
+/// Returns `None` when the message has no problems. Otherwise each problem is
+/// rendered with the given options, in the same order as [`lint`], with a
+/// blank line between them, like
+/// [`render_all_graphical`](crate::report::render_all_graphical).
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{lint_and_render, report::GraphicalOptions, Lints};
+///
+/// let options = GraphicalOptions::default();
+/// let lints = Lints::default_enabled();
+///
+/// assert_eq!(
+///     lint_and_render(
+///         &CommitMessage::from("An example commit\n"),
+///         lints.clone(),
+///         &options
+///     ),
+///     None
+/// );
+///
+/// let actual = lint_and_render(
+///     &CommitMessage::from("An example commit\nWith a body\n"),
+///     lints,
+///     &options,
+/// )
+/// .unwrap();
+/// assert!(actual.contains("SubjectNotSeparateFromBody"));
+/// ```
+#[must_use]
+pub fn lint_and_render(
+    commit_message: &CommitMessage<'_>,
+    lints: Lints,
+    options: &GraphicalOptions,
+) -> Option<String> {
+    let problems = lint(commit_message, lints);
+    if problems.is_empty() {
+        return None;
+    }
+
+    Some(
+        problems
+            .iter()
+            .map(|problem| options.render(problem))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
@@ -0,0 +1,46 @@
+use mit_commit::CommitMessage;
+
+use crate::{
+    cmd::{lint::lint, lint_and_render::lint_and_render},
+    model::{Code, Lints},
+    report::{render_all_graphical, GraphicalOptions},
+};
+
+#[test]
+fn nothing_is_rendered_for_a_clean_message() {
+    let actual = lint_and_render(
+        &CommitMessage::from("An example commit\n"),
+        Lints::default_enabled(),
+        &GraphicalOptions::default(),
+    );
+
+    assert_eq!(actual, None);
+}
+
+#[test]
+fn a_failing_message_is_rendered_with_its_code() {
+    let actual = lint_and_render(
+        &CommitMessage::from("an example commit\n"),
+        Lints::available().clone(),
+        &GraphicalOptions::default(),
+    )
+    .unwrap();
+
+    assert!(!actual.is_empty());
+    assert!(
+        actual.contains(&format!("{:?}", Code::SubjectNotCapitalized)),
+        "Expected the code in {:?}",
+        actual
+    );
+}
+
+#[test]
+fn every_problem_is_rendered_like_render_all_graphical() {
+    let message = CommitMessage::from("an example commit.");
+    let lints = Lints::available().clone();
+
+    let actual = lint_and_render(&message, lints.clone(), &GraphicalOptions::default());
+    let expected = render_all_graphical(&lint(&message, lints), 80, false);
+
+    assert_eq!(actual, Some(expected));
+}
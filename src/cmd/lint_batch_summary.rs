@@ -0,0 +1,105 @@
+use std::cmp::Reverse;
+
+use miette::Diagnostic;
+use mit_commit::CommitMessage;
+
+use crate::{
+    cmd::lint::lint,
+    model::{Lints, Problem},
+};
+
+/// A summary of the problems found in one commit of a batch
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BatchEntry {
+    sha: String,
+    problem_count: usize,
+    worst: Option<Problem>,
+}
+
+impl BatchEntry {
+    /// The sha of the commit
+    #[must_use]
+    pub fn sha(&self) -> &str {
+        &self.sha
+    }
+
+    /// How many problems were found
+    #[must_use]
+    pub const fn problem_count(&self) -> usize {
+        self.problem_count
+    }
+
+    /// The most severe problem found, if there were any
+    ///
+    /// When several problems are equally severe, the one with the lowest
+    /// [`Code`](crate::Code) is picked, so the result is the same every time.
+    #[must_use]
+    pub const fn worst(&self) -> Option<&Problem> {
+        self.worst.as_ref()
+    }
+
+    /// Check if the commit passed
+    #[must_use]
+    pub const fn is_ok(&self) -> bool {
+        self.problem_count == 0
+    }
+}
+
+/// Lint a batch of commits, summarising the result for each one
+///
+/// Useful in a server-side hook, like `pre-receive`, where many commits are
+/// checked at once and only a short report is wanted.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{lint_batch_summary, Code, Lint, Lints};
+///
+/// let summary = lint_batch_summary(
+///     &[
+///         (
+///             "abc123".to_string(),
+///             CommitMessage::from("An example commit"),
+///         ),
+///         (
+///             "def456".to_string(),
+///             CommitMessage::from("an example commit"),
+///         ),
+///     ],
+///     &Lints::from(vec![Lint::SubjectNotCapitalized]),
+/// );
+/// assert!(summary[0].is_ok());
+/// assert_eq!(summary[1].sha(), "def456");
+/// assert_eq!(
+///     summary[1].worst().map(|problem| *problem.code()),
+///     Some(Code::SubjectNotCapitalized)
+/// );
+/// ```
+#[must_use]
+pub fn lint_batch_summary(
+    messages: &[(String, CommitMessage<'_>)],
+    lints: &Lints,
+) -> Vec<BatchEntry> {
+    messages
+        .iter()
+        .map(|(sha, commit_message)| {
+            let problems = lint(commit_message, lints.clone());
+
+            BatchEntry {
+                sha: sha.clone(),
+                problem_count: problems.len(),
+                worst: worst(problems),
+            }
+        })
+        .collect()
+}
+
+fn worst(problems: Vec<Problem>) -> Option<Problem> {
+    problems.into_iter().min_by_key(|problem| {
+        (
+            Reverse(problem.severity().unwrap_or_default()),
+            *problem.code() as i32,
+        )
+    })
+}
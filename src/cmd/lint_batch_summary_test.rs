@@ -0,0 +1,57 @@
+use mit_commit::CommitMessage;
+
+use crate::{
+    cmd::lint_batch_summary::lint_batch_summary,
+    model::{Code, Lint, Lints},
+};
+
+#[test]
+fn each_commit_is_summarised_in_order() {
+    let summary = lint_batch_summary(
+        &[
+            (
+                "1111111".to_string(),
+                CommitMessage::from("An example commit\n\nSome body content\n"),
+            ),
+            (
+                "2222222".to_string(),
+                CommitMessage::from("an example commit.\n\nSome body content\n"),
+            ),
+            (
+                "3333333".to_string(),
+                CommitMessage::from(format!("{}\n", "x".repeat(73))),
+            ),
+        ],
+        &Lints::from(vec![
+            Lint::SubjectNotCapitalized,
+            Lint::SubjectEndsWithPeriod,
+            Lint::SubjectLongerThan72Characters,
+        ]),
+    );
+
+    let actual: Vec<_> = summary
+        .iter()
+        .map(|entry| {
+            (
+                entry.sha(),
+                entry.problem_count(),
+                entry.worst().map(|problem| *problem.code()),
+            )
+        })
+        .collect();
+    assert_eq!(
+        actual,
+        vec![
+            ("1111111", 0, None),
+            ("2222222", 2, Some(Code::SubjectNotCapitalized)),
+            ("3333333", 2, Some(Code::SubjectLongerThan72Characters)),
+        ]
+    );
+    assert!(summary[0].is_ok());
+    assert!(!summary[1].is_ok());
+}
+
+#[test]
+fn an_empty_batch_has_an_empty_summary() {
+    assert!(lint_batch_summary(&[], Lints::available()).is_empty());
+}
@@ -0,0 +1,122 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, VecDeque},
+    hash::{Hash, Hasher},
+};
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    cmd::lint::lint,
+    model::{Lints, Problem},
+};
+
+/// A cache of lint results
+///
+/// Useful when linting the same messages again and again, like in a watch
+/// loop. Results are keyed by a hash of the commit message and the lints that
+/// were run. Once the cache is full the oldest result is dropped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintCache {
+    capacity: usize,
+    results: HashMap<u64, Vec<Problem>>,
+    order: VecDeque<u64>,
+}
+
+impl LintCache {
+    /// Create a cache holding at most `capacity` results
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::LintCache;
+    ///
+    /// let cache = LintCache::new(100);
+    /// assert!(cache.is_empty());
+    /// ```
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            results: HashMap::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Lint a commit message, reusing the previous result if these lints
+    /// have already been run on this message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{LintCache, Lints};
+    ///
+    /// let mut cache = LintCache::new(100);
+    /// let message = CommitMessage::from("An example commit message");
+    ///
+    /// let first = cache.lint(&message, Lints::available().clone());
+    /// let second = cache.lint(&message, Lints::available().clone());
+    /// assert_eq!(first, second);
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    pub fn lint(&mut self, commit_message: &CommitMessage<'_>, lints: Lints) -> Vec<Problem> {
+        let key = Self::key(commit_message, &lints);
+        if let Some(problems) = self.results.get(&key) {
+            return problems.clone();
+        }
+
+        let problems = lint(commit_message, lints);
+        if self.capacity == 0 {
+            return problems;
+        }
+
+        if self.results.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.results.remove(&oldest);
+            }
+        }
+        self.order.push_back(key);
+        self.results.insert(key, problems.clone());
+
+        problems
+    }
+
+    /// Check if there is a result for these lints on this message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{LintCache, Lints};
+    ///
+    /// let mut cache = LintCache::new(100);
+    /// let message = CommitMessage::from("An example commit message");
+    /// assert!(!cache.contains(&message, Lints::available()));
+    ///
+    /// let _ = cache.lint(&message, Lints::available().clone());
+    /// assert!(cache.contains(&message, Lints::available()));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, commit_message: &CommitMessage<'_>, lints: &Lints) -> bool {
+        self.results.contains_key(&Self::key(commit_message, lints))
+    }
+
+    /// The number of results in the cache
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.results.len()
+    }
+
+    /// Check if the cache has no results in it
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.results.is_empty()
+    }
+
+    fn key(commit_message: &CommitMessage<'_>, lints: &Lints) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        String::from(commit_message).hash(&mut hasher);
+        lints.hash(&mut hasher);
+        hasher.finish()
+    }
+}
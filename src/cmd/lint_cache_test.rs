@@ -0,0 +1,79 @@
+use mit_commit::CommitMessage;
+
+use crate::{
+    cmd::{lint::lint, lint_cache::LintCache},
+    model::{Lint, Lints},
+};
+
+#[test]
+fn a_second_call_with_the_same_inputs_is_cached() {
+    let mut cache = LintCache::new(10);
+    let message = CommitMessage::from("an example commit.");
+    let lints = Lints::available().clone();
+
+    let first = cache.lint(&message, lints.clone());
+    assert!(cache.contains(&message, &lints));
+    let second = cache.lint(&message, lints.clone());
+
+    assert_eq!(first, lint(&message, lints));
+    assert_eq!(first, second);
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn changing_the_message_is_a_different_result() {
+    let mut cache = LintCache::new(10);
+    let lints = Lints::available().clone();
+    let first_message = CommitMessage::from("an example commit.");
+    let second_message = CommitMessage::from("An example commit");
+
+    let first = cache.lint(&first_message, lints.clone());
+    assert!(!cache.contains(&second_message, &lints));
+    let second = cache.lint(&second_message, lints.clone());
+
+    assert_ne!(first, second);
+    assert_eq!(second, lint(&second_message, lints));
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn changing_the_lints_is_a_different_result() {
+    let mut cache = LintCache::new(10);
+    let message = CommitMessage::from("an example commit.");
+
+    let _ = cache.lint(&message, Lints::from(vec![Lint::SubjectNotCapitalized]));
+
+    assert!(!cache.contains(&message, &Lints::from(vec![Lint::SubjectEndsWithPeriod])));
+}
+
+#[test]
+fn the_oldest_result_is_dropped_when_full() {
+    let mut cache = LintCache::new(2);
+    let lints = Lints::available().clone();
+    let messages: Vec<CommitMessage<'_>> = ["First", "Second", "Third"]
+        .iter()
+        .copied()
+        .map(CommitMessage::from)
+        .collect();
+
+    for message in &messages {
+        let _ = cache.lint(message, lints.clone());
+    }
+
+    assert_eq!(cache.len(), 2);
+    assert!(!cache.contains(&messages[0], &lints));
+    assert!(cache.contains(&messages[1], &lints));
+    assert!(cache.contains(&messages[2], &lints));
+}
+
+#[test]
+fn nothing_is_kept_without_capacity() {
+    let mut cache = LintCache::new(0);
+
+    let _ = cache.lint(
+        &CommitMessage::from("An example commit"),
+        Lints::available().clone(),
+    );
+
+    assert!(cache.is_empty());
+}
@@ -0,0 +1,69 @@
+use std::cmp::Reverse;
+
+use mit_commit::CommitMessage;
+
+use crate::model::{Lint, Lints, Problem};
+
+/// Lint a commit message, returning only the problem to fix first
+///
+/// This is the problem with the highest [`Lint::severity`], and of those,
+/// the one that starts earliest in the message. Problems without labels come
+/// after ones with them, and any remaining ties go to the lint declared first
+/// in [`Lint`].
+///
+/// Lints are run from the most to least severe, and stop as soon as the
+/// problem found can't be beaten, so a message with an error never runs the
+/// warning or advice lints.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{lint_first, Code, Lint, Lints};
+///
+/// let lints = Lints::new(
+///     vec![
+///         Lint::SubjectNotCapitalized,
+///         Lint::SubjectLongerThan72Characters,
+///     ]
+///     .into_iter()
+///     .collect(),
+/// );
+///
+/// let actual = lint_first(&CommitMessage::from("x".repeat(73)), lints.clone()).unwrap();
+/// assert_eq!(actual.code(), &Code::SubjectLongerThan72Characters);
+///
+/// assert!(lint_first(&CommitMessage::from("An example commit\n"), lints).is_none());
+/// ```
+#[must_use]
+pub fn lint_first(commit_message: &CommitMessage<'_>, lints: Lints) -> Option<Problem> {
+    let mut lints: Vec<Lint> = lints.into_iter().collect();
+    lints.sort_by_key(|lint| Reverse(lint.severity()));
+
+    let mut best: Option<(Lint, usize, Problem)> = None;
+    for lint in lints {
+        if let Some((best_lint, best_offset, _)) = &best {
+            if best_lint.severity() > lint.severity() || *best_offset == 0 {
+                break;
+            }
+        }
+
+        let Some(problem) = lint.lint(commit_message) else {
+            continue;
+        };
+        let offset = problem
+            .structured_labels()
+            .iter()
+            .map(|label| label.offset)
+            .min()
+            .unwrap_or(usize::MAX);
+        if best
+            .as_ref()
+            .is_none_or(|(_, best_offset, _)| offset < *best_offset)
+        {
+            best = Some((lint, offset, problem));
+        }
+    }
+
+    best.map(|(_, _, problem)| problem)
+}
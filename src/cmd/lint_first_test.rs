@@ -0,0 +1,85 @@
+use mit_commit::CommitMessage;
+
+use crate::{
+    cmd::lint_first::lint_first,
+    model::{Code, Lint, Lints, Problem},
+};
+
+fn several_problems() -> (CommitMessage<'static>, Lints) {
+    let message = CommitMessage::from(format!("{}.\n\nSome body\twith a tab.\n", "x".repeat(73)));
+    let lints = Lints::from(vec![
+        Lint::BodyContainsTabs,
+        Lint::NotConventionalCommit,
+        Lint::SubjectEndsWithPeriod,
+        Lint::SubjectNotCapitalized,
+        Lint::SubjectLongerThan72Characters,
+    ]);
+
+    (message, lints)
+}
+
+#[test]
+fn a_clean_message_has_no_problem() {
+    assert_eq!(
+        lint_first(
+            &CommitMessage::from("An example commit\n"),
+            Lints::default_enabled()
+        ),
+        None
+    );
+}
+
+#[test]
+fn the_earliest_error_is_returned() {
+    let (message, lints) = several_problems();
+
+    let actual = lint_first(&message, lints).unwrap();
+
+    assert_eq!(actual.code(), &Code::NotConventionalCommit);
+}
+
+#[test]
+fn errors_come_before_earlier_warnings() {
+    let (message, _) = several_problems();
+    let lints = Lints::from(vec![
+        Lint::SubjectNotCapitalized,
+        Lint::SubjectLongerThan72Characters,
+    ]);
+
+    let actual = lint_first(&message, lints).unwrap();
+
+    assert_eq!(actual.code(), &Code::SubjectLongerThan72Characters);
+}
+
+#[test]
+fn the_earliest_warning_is_returned_when_there_are_no_errors() {
+    let (message, _) = several_problems();
+    let lints = Lints::from(vec![
+        Lint::BodyContainsTabs,
+        Lint::SubjectEndsWithPeriod,
+        Lint::SubjectNotCapitalized,
+    ]);
+
+    let actual = lint_first(&message, lints).unwrap();
+
+    assert_eq!(actual.code(), &Code::SubjectNotCapitalized);
+}
+
+#[test]
+fn no_problem_found_is_more_severe() {
+    let (message, lints) = several_problems();
+
+    let actual = lint_first(&message, lints.clone()).unwrap();
+    let found: Vec<(Lint, Problem)> = lints
+        .into_iter()
+        .filter_map(|lint| lint.lint(&message).map(|problem| (lint, problem)))
+        .collect();
+    let (actual_lint, _) = found
+        .iter()
+        .find(|(_, problem)| problem == &actual)
+        .unwrap();
+
+    assert!(found
+        .iter()
+        .all(|(lint, _)| lint.severity() <= actual_lint.severity()));
+}
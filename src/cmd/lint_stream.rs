@@ -0,0 +1,47 @@
+use std::io::BufRead;
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    cmd::lint::lint,
+    model::{Lints, Problem},
+};
+
+/// Lint each message in a stream of messages separated by `delimiter`
+///
+/// Messages are read and linted one at a time as the iterator is advanced,
+/// so large hook input, like the output of `git log -z`, doesn't need to fit
+/// in memory. Each item is the index of the message in the stream, and the
+/// problems found in it. Bytes that aren't valid UTF-8 are replaced with
+/// `U+FFFD`.
+///
+/// The iterator stops at the first error reading from `reader`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_lint::{lint_stream, Code, Lint, Lints};
+///
+/// let input = "An example commit\0an example commit\0".as_bytes();
+/// let lints = Lints::from(vec![Lint::SubjectNotCapitalized]);
+///
+/// let actual: Vec<_> = lint_stream(input, b'\0', &lints)
+///     .map(|(index, problems)| (index, problems.len()))
+///     .collect();
+/// assert_eq!(actual, vec![(0, 0), (1, 1)]);
+/// ```
+pub fn lint_stream<'a, R: BufRead + 'a>(
+    reader: R,
+    delimiter: u8,
+    lints: &'a Lints,
+) -> impl Iterator<Item = (usize, Vec<Problem>)> + 'a {
+    reader
+        .split(delimiter)
+        .map_while(Result::ok)
+        .enumerate()
+        .map(move |(index, record)| {
+            let commit_message = CommitMessage::from(String::from_utf8_lossy(&record).into_owned());
+
+            (index, lint(&commit_message, lints.clone()))
+        })
+}
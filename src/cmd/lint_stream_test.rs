@@ -0,0 +1,81 @@
+use std::io::{BufReader, Cursor};
+
+use crate::{
+    cmd::lint_stream::lint_stream,
+    model::{Code, Lint, Lints},
+};
+
+fn codes(input: &[u8], lints: &Lints) -> Vec<(usize, Vec<Code>)> {
+    lint_stream(Cursor::new(input), b'\0', lints)
+        .map(|(index, problems)| {
+            (
+                index,
+                problems.iter().map(|problem| *problem.code()).collect(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn each_record_is_linted_in_order() {
+    let input = format!(
+        "An example commit\n\nSome body content\n\0an example commit.\n\0{}\n",
+        "x".repeat(73)
+    );
+    let lints = Lints::from(vec![
+        Lint::SubjectNotCapitalized,
+        Lint::SubjectEndsWithPeriod,
+        Lint::SubjectLongerThan72Characters,
+    ]);
+
+    assert_eq!(
+        codes(input.as_bytes(), &lints),
+        vec![
+            (0, vec![]),
+            (
+                1,
+                vec![Code::SubjectNotCapitalized, Code::SubjectEndsWithPeriod]
+            ),
+            (
+                2,
+                vec![
+                    Code::SubjectLongerThan72Characters,
+                    Code::SubjectNotCapitalized
+                ]
+            ),
+        ]
+    );
+}
+
+#[test]
+fn a_trailing_delimiter_does_not_add_a_record() {
+    let lints = Lints::from(vec![Lint::SubjectNotCapitalized]);
+
+    assert_eq!(codes(b"An example commit\n\0", &lints), vec![(0, vec![])]);
+}
+
+#[test]
+fn invalid_utf8_is_replaced() {
+    let lints = Lints::from(vec![Lint::ReplacementCharacters]);
+
+    assert_eq!(
+        codes(b"An example \xff commit\n", &lints),
+        vec![(0, vec![Code::ReplacementCharacters])]
+    );
+}
+
+#[test]
+fn records_are_read_lazily() {
+    let input = "An example commit\n\0an example commit\n\0".repeat(1000);
+    let lints = Lints::from(vec![Lint::SubjectNotCapitalized]);
+    let reader = BufReader::with_capacity(64, Cursor::new(input.as_bytes()));
+    let mut stream = lint_stream(reader, b'\0', &lints);
+
+    assert_eq!(stream.next().map(|(index, _)| index), Some(0));
+    assert_eq!(
+        stream
+            .nth(2)
+            .map(|(index, problems)| (index, problems.len())),
+        Some((3, 1))
+    );
+}
@@ -0,0 +1,152 @@
+use std::collections::BTreeSet;
+
+use mit_commit::CommitMessage;
+#[cfg(feature = "tracing")]
+use tracing_test::traced_test;
+
+use crate::{
+    cmd::lint::{lint, lint_with_comment_char, lint_with_config, lint_with_custom},
+    model::{Code, CustomLint, Lint, LintConfig, Lints, Problem, ProblemBuilder},
+};
+
+#[cfg(feature = "tracing")]
+#[traced_test]
+#[test]
+fn a_span_is_emitted_for_each_enabled_lint() {
+    let lints = Lints::new(
+        vec![
+            Lint::SubjectLongerThan72Characters,
+            Lint::SubjectNotCapitalized,
+            Lint::NotConventionalCommit,
+        ]
+        .into_iter()
+        .collect(),
+    );
+
+    let problems = lint(&CommitMessage::from("An example commit"), lints.clone());
+
+    assert_eq!(problems.len(), 1);
+    assert!(logs_contain("lint_commit"));
+    for lint in lints {
+        assert!(
+            logs_contain(&format!("lint{{lint=\"{}\"}}", lint.name())),
+            "Expected a span for {}",
+            lint
+        );
+    }
+    assert!(logs_contain("problem=true"));
+    assert!(logs_contain("problem=false"));
+}
+
+fn several_problems() -> (CommitMessage<'static>, Lints) {
+    let message = CommitMessage::from(format!("{}.\n\nSome body\twith a tab.\n", "x".repeat(73)));
+    let lints = Lints::from(vec![
+        Lint::BodyContainsTabs,
+        Lint::NotConventionalCommit,
+        Lint::SubjectEndsWithPeriod,
+        Lint::SubjectNotCapitalized,
+        Lint::SubjectLongerThan72Characters,
+    ]);
+
+    (message, lints)
+}
+
+fn codes(problems: &[Problem]) -> Vec<Code> {
+    problems.iter().map(|problem| *problem.code()).collect()
+}
+
+const EXPECTED_ORDER: [Code; 5] = [
+    Code::SubjectLongerThan72Characters,
+    Code::SubjectNotCapitalized,
+    Code::SubjectEndsWithPeriod,
+    Code::NotConventionalCommit,
+    Code::BodyContainsTabs,
+];
+
+#[test]
+fn problems_are_in_the_order_the_lints_are_declared() {
+    let (message, lints) = several_problems();
+
+    assert_eq!(codes(&lint(&message, lints)), EXPECTED_ORDER.to_vec());
+}
+
+#[test]
+fn problems_are_in_the_same_order_with_a_comment_char_or_config() {
+    let (message, lints) = several_problems();
+
+    assert_eq!(
+        codes(&lint_with_comment_char(&message, lints.clone(), '#')),
+        EXPECTED_ORDER.to_vec()
+    );
+    assert_eq!(
+        codes(&lint_with_config(&message, lints, &LintConfig::default())),
+        EXPECTED_ORDER.to_vec()
+    );
+}
+
+#[test]
+fn problems_follow_the_order_of_all_lints() {
+    let (message, _) = several_problems();
+    let expected: Vec<Problem> = Lint::all_lints()
+        .filter_map(|lint| lint.lint(&message))
+        .collect();
+
+    assert_eq!(lint(&message, Lints::available().clone()), expected);
+}
+
+struct SubjectMentions(&'static str, Code);
+
+impl CustomLint for SubjectMentions {
+    fn code(&self) -> Code {
+        self.1
+    }
+
+    fn check(&self, commit: &CommitMessage<'_>) -> Option<Problem> {
+        String::from(commit.get_subject())
+            .contains(self.0)
+            .then(|| {
+                ProblemBuilder::new(
+                    format!("Your subject mentions {}", self.0),
+                    "Leave it out".to_string(),
+                    self.code(),
+                    commit,
+                )
+                .build()
+            })
+    }
+}
+
+#[test]
+fn custom_lints_are_run_after_the_built_in_lints() {
+    let (message, lints) = several_problems();
+    let custom = SubjectMentions("xxx", Code::JiraIssueKeyMissing);
+
+    let actual = lint_with_custom(&message, lints, &[&custom]);
+
+    let mut expected = EXPECTED_ORDER.to_vec();
+    expected.push(Code::JiraIssueKeyMissing);
+    assert_eq!(codes(&actual), expected);
+    assert_eq!(
+        actual.last().map(Problem::error),
+        Some("Your subject mentions xxx")
+    );
+}
+
+#[test]
+fn custom_lints_are_run_in_the_order_given() {
+    let message = CommitMessage::from("Add login\n");
+    let first = SubjectMentions("Add", Code::GitHubIdMissing);
+    let second = SubjectMentions("login", Code::JiraIssueKeyMissing);
+    let passing = SubjectMentions("logout", Code::PivotalTrackerIdMissing);
+
+    let actual = lint_with_custom(
+        &message,
+        Lints::new(BTreeSet::new()),
+        &[&second, &passing, &first],
+    );
+
+    assert_eq!(
+        codes(&actual),
+        vec![Code::JiraIssueKeyMissing, Code::GitHubIdMissing]
+    );
+}
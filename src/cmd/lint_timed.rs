@@ -0,0 +1,50 @@
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use mit_commit::CommitMessage;
+
+use crate::model::{Lint, Lints, Problem};
+
+/// Lint a commit message, timing how long each lint took
+///
+/// The problems are the same as [`lint`](crate::lint) would return. The
+/// timings are best-effort wall clock measurements, useful for finding a slow
+/// lint on a big message, but they aren't stable between runs and shouldn't
+/// be relied on for anything more precise.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{lint_timed, Lint, Lints};
+/// let (problems, timings) = lint_timed(
+///     &CommitMessage::from("An example commit message"),
+///     Lints::new(
+///         vec![Lint::SubjectLongerThan72Characters]
+///             .into_iter()
+///             .collect(),
+///     ),
+/// );
+/// assert!(problems.is_empty());
+/// assert!(timings.contains_key(&Lint::SubjectLongerThan72Characters));
+/// ```
+#[must_use]
+pub fn lint_timed(
+    commit_message: &CommitMessage<'_>,
+    lints: Lints,
+) -> (Vec<Problem>, BTreeMap<Lint, Duration>) {
+    let mut timings = BTreeMap::new();
+    let problems = lints
+        .into_iter()
+        .filter_map(|lint| {
+            let start = Instant::now();
+            let problem = lint.lint(commit_message);
+            timings.insert(lint, start.elapsed());
+            problem
+        })
+        .collect::<Vec<Problem>>();
+
+    (problems, timings)
+}
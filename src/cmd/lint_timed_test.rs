@@ -0,0 +1,43 @@
+use mit_commit::CommitMessage;
+
+use crate::{
+    cmd::{lint::lint, lint_timed::lint_timed},
+    model::{Lint, Lints},
+};
+
+#[test]
+fn every_lint_is_timed() {
+    let message = CommitMessage::from("an example commit.");
+    let lints = Lints::available().clone();
+
+    let (_, timings) = lint_timed(&message, lints.clone());
+
+    assert_eq!(
+        timings.keys().copied().collect::<Vec<_>>(),
+        lints.into_iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn problems_are_the_same_as_lint() {
+    let message = CommitMessage::from("an example commit.");
+    let lints = Lints::available().clone();
+
+    let (problems, _) = lint_timed(&message, lints.clone());
+
+    assert_eq!(problems, lint(&message, lints));
+}
+
+#[test]
+fn only_enabled_lints_are_timed() {
+    let message = CommitMessage::from("an example commit.");
+    let lints = Lints::new(vec![Lint::SubjectNotCapitalized].into_iter().collect());
+
+    let (problems, timings) = lint_timed(&message, lints);
+
+    assert_eq!(problems.len(), 1);
+    assert_eq!(
+        timings.keys().copied().collect::<Vec<_>>(),
+        vec![Lint::SubjectNotCapitalized]
+    );
+}
@@ -1,5 +1,43 @@
 pub use async_lint::async_lint;
-pub use lint::lint;
+pub use explain::explain;
+pub use fix_all::{fix_all, fix_all_async};
+pub use lint::{lint, lint_str, lint_with_comment_char, lint_with_config, lint_with_custom};
+pub use lint_and_render::lint_and_render;
+pub use lint_batch_summary::{lint_batch_summary, BatchEntry};
+pub use lint_cache::LintCache;
+pub use lint_first::lint_first;
+pub use lint_stream::lint_stream;
+pub use lint_timed::lint_timed;
+pub use run::{run, LintRun};
 
 mod async_lint;
+mod explain;
+#[cfg(test)]
+mod explain_test;
+mod fix_all;
+#[cfg(test)]
+mod fix_all_test;
 mod lint;
+mod lint_and_render;
+#[cfg(test)]
+mod lint_and_render_test;
+mod lint_batch_summary;
+#[cfg(test)]
+mod lint_batch_summary_test;
+mod lint_cache;
+#[cfg(test)]
+mod lint_cache_test;
+mod lint_first;
+#[cfg(test)]
+mod lint_first_test;
+mod lint_stream;
+#[cfg(test)]
+mod lint_stream_test;
+#[cfg(test)]
+mod lint_test;
+mod lint_timed;
+#[cfg(test)]
+mod lint_timed_test;
+mod run;
+#[cfg(test)]
+mod run_test;
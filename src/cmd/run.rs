@@ -0,0 +1,89 @@
+use mit_commit::CommitMessage;
+
+use crate::{
+    cmd::lint::lint,
+    model::{Lints, Problem},
+};
+
+/// The result of linting a commit message, with the lints that ran
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct LintRun {
+    ran: Lints,
+    problems: Vec<Problem>,
+    passed: usize,
+    failed: usize,
+}
+
+impl LintRun {
+    /// The lints that ran
+    #[must_use]
+    pub const fn ran(&self) -> &Lints {
+        &self.ran
+    }
+
+    /// The problems found, in the same order as [`lint`](crate::lint)
+    #[must_use]
+    pub fn problems(&self) -> &[Problem] {
+        &self.problems
+    }
+
+    /// Take the problems found, dropping the rest of the result
+    #[must_use]
+    pub fn into_problems(self) -> Vec<Problem> {
+        self.problems
+    }
+
+    /// How many lints found nothing wrong
+    #[must_use]
+    pub const fn passed(&self) -> usize {
+        self.passed
+    }
+
+    /// How many lints found a problem
+    #[must_use]
+    pub const fn failed(&self) -> usize {
+        self.failed
+    }
+
+    /// Check if every lint passed
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
+
+/// Lint a commit message, keeping track of which lints ran
+///
+/// Use [`lint`](crate::lint) when only the problems are needed.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{run, Code, Lints};
+///
+/// let result = run(
+///     &CommitMessage::from("x".repeat(73)),
+///     Lints::default_enabled(),
+/// );
+/// assert_eq!(result.ran().iter_names().count(), 4);
+/// assert_eq!(result.passed(), 3);
+/// assert_eq!(result.failed(), 1);
+/// assert!(!result.is_clean());
+/// assert_eq!(
+///     result.problems()[0].code(),
+///     &Code::SubjectLongerThan72Characters
+/// );
+/// ```
+#[must_use]
+pub fn run(commit_message: &CommitMessage<'_>, lints: Lints) -> LintRun {
+    let problems = lint(commit_message, lints.clone());
+    let failed = problems.len();
+
+    LintRun {
+        passed: lints.iter_names().count() - failed,
+        ran: lints,
+        problems,
+        failed,
+    }
+}
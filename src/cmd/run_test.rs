@@ -0,0 +1,51 @@
+use std::collections::BTreeSet;
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    cmd::{lint::lint, run::run},
+    model::{Lint, Lints},
+};
+
+#[test]
+fn a_clean_message_passes_every_lint() {
+    let lints = Lints::from(vec![
+        Lint::SubjectNotCapitalized,
+        Lint::SubjectEndsWithPeriod,
+    ]);
+    let result = run(&CommitMessage::from("An example commit\n"), lints.clone());
+
+    assert!(result.is_clean());
+    assert_eq!(result.ran(), &lints);
+    assert_eq!(result.passed(), 2);
+    assert_eq!(result.failed(), 0);
+    assert!(result.problems().is_empty());
+}
+
+#[test]
+fn the_problems_are_the_same_as_lint() {
+    let message = CommitMessage::from(format!("{}.\n", "x".repeat(73)));
+    let lints = Lints::from(vec![
+        Lint::SubjectNotCapitalized,
+        Lint::SubjectEndsWithPeriod,
+        Lint::SubjectLongerThan72Characters,
+        Lint::DuplicatedTrailers,
+    ]);
+    let result = run(&message, lints.clone());
+
+    assert_eq!(result.passed(), 1);
+    assert_eq!(result.failed(), 3);
+    assert_eq!(result.into_problems(), lint(&message, lints));
+}
+
+#[test]
+fn nothing_runs_without_lints() {
+    let result = run(
+        &CommitMessage::from("An example commit\n"),
+        Lints::new(BTreeSet::new()),
+    );
+
+    assert!(result.is_clean());
+    assert_eq!(result.passed(), 0);
+    assert_eq!(result.failed(), 0);
+}
@@ -37,18 +37,53 @@
 #[macro_use]
 extern crate lazy_static;
 
-#[cfg(test)]
-extern crate quickcheck;
 #[cfg(test)]
 #[macro_use(quickcheck)]
 extern crate quickcheck_macros;
 
-pub use cmd::{async_lint, lint};
-pub use model::{Code, Error, Lint, LintError, Lints, Problem, CONFIG_KEY_PREFIX};
+pub use cmd::{
+    async_lint,
+    explain,
+    fix_all,
+    fix_all_async,
+    lint,
+    lint_and_render,
+    lint_batch_summary,
+    lint_first,
+    lint_str,
+    lint_stream,
+    lint_timed,
+    lint_with_comment_char,
+    lint_with_config,
+    lint_with_custom,
+    run,
+    BatchEntry,
+    LintCache,
+    LintRun,
+};
+pub use model::{
+    is_merge_commit,
+    sample_toml,
+    Category,
+    Code,
+    CustomLint,
+    Error,
+    Label,
+    Lint,
+    LintConfig,
+    LintError,
+    Lints,
+    LintsBuilder,
+    LintsDiff,
+    Problem,
+    ProblemBuilder,
+    CONFIG_KEY_PREFIX,
+};
 
-mod checks;
+pub mod checks;
 mod cmd;
 mod model;
+pub mod report;
 
 #[cfg(doctest)]
 mod test_readme {
@@ -0,0 +1,24 @@
+/// The part of a commit message a [`Lint`](crate::Lint) checks
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_lint::{Category, Lint};
+///
+/// assert_eq!(
+///     Lint::SubjectLongerThan72Characters.category(),
+///     Category::Subject
+/// );
+/// assert_eq!(Lint::DuplicatedTrailers.category(), Category::Trailers);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Category {
+    /// The first line of the message
+    Subject,
+    /// The lines between the subject and the trailers
+    Body,
+    /// The `Key: Value` lines at the end of the message
+    Trailers,
+    /// The message as a whole, like checks for an issue ID anywhere in it
+    Message,
+}
@@ -1,3 +1,4 @@
+#[cfg(any(test, feature = "quickcheck"))]
 use quickcheck::{Arbitrary, Gen};
 use strum_macros::EnumIter;
 
@@ -5,6 +6,7 @@ use strum_macros::EnumIter;
 ///
 /// Useful for exit codes and other user facing things
 #[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(i32)]
 pub enum Code {
     /// Unique ID for `InitialNotMatchedToAuthor` failure
@@ -35,8 +37,85 @@ pub enum Code {
     NotConventionalCommit,
     /// Unique ID for `NotEmojiLog` failure
     NotEmojiLog,
+    /// Unique ID for `DiffPastedInBody` failure
+    DiffPastedInBody,
+    /// Unique ID for `TooManyTrailers` failure
+    TooManyTrailers,
+    /// Unique ID for `SubjectEndsWithPunctuation` failure
+    SubjectEndsWithPunctuation,
+    /// Unique ID for `SubjectOnlyTicket` failure
+    SubjectOnlyTicket,
+    /// Unique ID for `SubjectGerundStart` failure
+    SubjectGerundStart,
+    /// Unique ID for `SubjectMarkdownHeading` failure
+    SubjectMarkdownHeading,
+    /// Unique ID for `UneditedTemplate` failure
+    UneditedTemplate,
+    /// Unique ID for `SubjectAllCaps` failure
+    SubjectAllCaps,
+    /// Unique ID for `MalformedTrailer` failure
+    MalformedTrailer,
+    /// Unique ID for `EmptyCommitMessage` failure
+    EmptyCommitMessage,
+    /// Unique ID for `BodyParagraphTooLong` failure
+    BodyParagraphTooLong,
+    /// Unique ID for `BodyLeadingTrailingBlankLines` failure
+    BodyLeadingTrailingBlankLines,
+    /// Unique ID for `MalformedRevert` failure
+    MalformedRevert,
+    /// Unique ID for `SubjectMissingPrefix` failure
+    SubjectMissingPrefix,
+    /// Unique ID for `SubjectLeadingEmoji` failure
+    SubjectLeadingEmoji,
+    /// Unique ID for `BodySeparatorLine` failure
+    BodySeparatorLine,
+    /// Unique ID for `BodyContainsTabs` failure
+    BodyContainsTabs,
+    /// Unique ID for `UnknownFooterToken` failure
+    UnknownFooterToken,
+    /// Unique ID for `BodyNotMeaningful` failure
+    BodyNotMeaningful,
+    /// Unique ID for `SubjectNotAtomic` failure
+    SubjectNotAtomic,
+    /// Unique ID for `BodyUnrelatedToSubject` failure
+    BodyUnrelatedToSubject,
+    /// Unique ID for `SubjectCaseStyle` failure
+    SubjectCaseStyle,
+    /// Unique ID for `BodySentenceNoTerminator` failure
+    BodySentenceNoTerminator,
+    /// Unique ID for `EmailInBody` failure
+    EmailInBody,
+    /// Unique ID for `ReplacementCharacters` failure
+    ReplacementCharacters,
+    /// Unique ID for `SubjectWhitespace` failure
+    SubjectWhitespace,
+    /// Unique ID for `MissingFinalNewline` failure
+    MissingFinalNewline,
+    /// Unique ID for `MissingIssueReference` failure
+    MissingIssueReference,
+    /// Unique ID for `DoubleRevert` failure
+    DoubleRevert,
+    /// Unique ID for `InconsistentListIndent` failure
+    InconsistentListIndent,
+    /// Unique ID for `ShellArtifactInSubject` failure
+    ShellArtifactInSubject,
+    /// Unique ID for `WorkInProgress` failure
+    WorkInProgress,
+    /// Unique ID for `FooterUrlTooLong` failure
+    FooterUrlTooLong,
+    /// Unique ID for `TooManyCoAuthors` failure
+    TooManyCoAuthors,
+    /// Unique ID for `SubjectEchoedInBody` failure
+    SubjectEchoedInBody,
+    /// Unique ID for `InconsistentListPunctuation` failure
+    InconsistentListPunctuation,
+    /// Unique ID for `SubjectFillerWords` failure
+    SubjectFillerWords,
+    /// Unique ID for `FooterBeforeBody` failure
+    FooterBeforeBody,
 }
 
+#[cfg(any(test, feature = "quickcheck"))]
 impl Arbitrary for Code {
     fn arbitrary(g: &mut Gen) -> Self {
         *g.choose(&Self::get_codes()).unwrap()
@@ -47,8 +126,9 @@ impl Arbitrary for Code {
     }
 }
 
+#[cfg(any(test, feature = "quickcheck"))]
 impl Code {
-    const fn get_codes() -> [Self; 14] {
+    const fn get_codes() -> [Self; 52] {
         [
             Self::InitialNotMatchedToAuthor,
             Self::UnparsableAuthorFile,
@@ -64,6 +144,44 @@ impl Code {
             Self::BodyWiderThan72Characters,
             Self::NotConventionalCommit,
             Self::NotEmojiLog,
+            Self::DiffPastedInBody,
+            Self::TooManyTrailers,
+            Self::SubjectEndsWithPunctuation,
+            Self::SubjectOnlyTicket,
+            Self::SubjectGerundStart,
+            Self::SubjectMarkdownHeading,
+            Self::UneditedTemplate,
+            Self::SubjectAllCaps,
+            Self::MalformedTrailer,
+            Self::EmptyCommitMessage,
+            Self::BodyParagraphTooLong,
+            Self::BodyLeadingTrailingBlankLines,
+            Self::MalformedRevert,
+            Self::SubjectMissingPrefix,
+            Self::SubjectLeadingEmoji,
+            Self::BodySeparatorLine,
+            Self::BodyContainsTabs,
+            Self::UnknownFooterToken,
+            Self::BodyNotMeaningful,
+            Self::SubjectNotAtomic,
+            Self::BodyUnrelatedToSubject,
+            Self::SubjectCaseStyle,
+            Self::BodySentenceNoTerminator,
+            Self::EmailInBody,
+            Self::ReplacementCharacters,
+            Self::SubjectWhitespace,
+            Self::MissingFinalNewline,
+            Self::MissingIssueReference,
+            Self::DoubleRevert,
+            Self::InconsistentListIndent,
+            Self::ShellArtifactInSubject,
+            Self::WorkInProgress,
+            Self::FooterUrlTooLong,
+            Self::TooManyCoAuthors,
+            Self::SubjectEchoedInBody,
+            Self::InconsistentListPunctuation,
+            Self::SubjectFillerWords,
+            Self::FooterBeforeBody,
         ]
     }
 }
@@ -0,0 +1,218 @@
+use std::collections::BTreeSet;
+
+use mit_commit::CommitMessage;
+
+#[cfg(feature = "regex-lints")]
+use crate::checks::{
+    missing_github_id::GitHubIdConfig,
+    missing_issue_reference::MissingIssueReferenceConfig,
+    missing_pivotal_tracker_id::PivotalTrackerIdConfig,
+    subject_missing_prefix::SubjectMissingPrefixConfig,
+    subject_only_ticket::SubjectOnlyTicketConfig,
+};
+use crate::{
+    checks::{
+        body_wider_than_72_characters::BodyWidthConfig,
+        duplicate_trailers::DuplicatedTrailersConfig,
+        not_conventional_commit::ConventionalCommitConfig,
+        not_emoji_log::NotEmojiLogConfig,
+        subject_ends_with_punctuation::SubjectEndsWithPunctuationConfig,
+        subject_filler_words::SubjectFillerWordsConfig,
+        subject_longer_than_72_characters::SubjectLengthConfig,
+        too_many_trailers::TooManyTrailersConfig,
+        unedited_template::UneditedTemplateConfig,
+        unknown_footer_token::UnknownFooterTokenConfig,
+    },
+    model::{Lint, Lints},
+};
+
+/// The start of the subjects git and forges write for merge commits
+const MERGE_PREFIXES: [&str; 6] = [
+    "Merge branch ",
+    "Merge branches ",
+    "Merge remote-tracking branch ",
+    "Merge tag ",
+    "Merge commit ",
+    "Merge pull request ",
+];
+
+/// Settings that change how lints run, rather than which lints run
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_lint::LintConfig;
+///
+/// assert!(!LintConfig::default().include_comments);
+/// assert!(LintConfig::default().skip_on_merge.names().is_empty());
+/// assert_eq!(LintConfig::default().max_labels_per_problem, None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LintConfig {
+    /// Lint comment lines as if they were part of the message
+    ///
+    /// Turn this on when comments are stripped after linting, or never
+    /// stripped at all, so a long line or a secret in a comment is still
+    /// caught. Lines below the scissors are always skipped.
+    pub include_comments: bool,
+    /// Lints that don't run on merge commits
+    ///
+    /// Use this to keep a lint on for normal commits while letting the
+    /// messages git writes for merges through. See [`is_merge_commit`] for
+    /// what counts as a merge.
+    pub skip_on_merge: Lints,
+    /// The most labels a problem can have
+    ///
+    /// A pathological commit, like one with thousands of duplicated
+    /// trailers, can otherwise give a report too big to read or render.
+    /// Labels past the limit are replaced with one label saying how many
+    /// were left out. With `None`, every label is kept.
+    pub max_labels_per_problem: Option<usize>,
+    /// Which trailers the duplicated trailers lint lets through more than
+    /// once, like `Co-authored-by`
+    pub duplicated_trailers: DuplicatedTrailersConfig,
+    /// What the conventional commit lint accepts, like the types in
+    /// [`ConventionalCommitConfig::angular`]
+    pub conventional_commit: ConventionalCommitConfig,
+    /// How many trailers the too many trailers lint allows
+    pub too_many_trailers: TooManyTrailersConfig,
+    /// Which characters the subject punctuation lint doesn't let end the
+    /// subject
+    pub subject_ends_with_punctuation: SubjectEndsWithPunctuationConfig,
+    /// What the subject only ticket lint counts as a ticket reference
+    #[cfg(feature = "regex-lints")]
+    pub subject_only_ticket: SubjectOnlyTicketConfig,
+    /// The commit template the unedited template lint compares the message
+    /// with, usually the file in git's `commit.template` setting
+    pub unedited_template: UneditedTemplateConfig,
+    /// How strictly the emoji log lint matches the prefix
+    pub not_emoji_log: NotEmojiLogConfig,
+    /// Which forms of reference the GitHub ID lint accepts
+    #[cfg(feature = "regex-lints")]
+    pub github_id: GitHubIdConfig,
+    /// The prefix the subject missing prefix lint requires, which it needs
+    /// before it can find anything
+    #[cfg(feature = "regex-lints")]
+    pub subject_missing_prefix: SubjectMissingPrefixConfig,
+    /// Which trailer tokens the unknown footer token lint allows, which it
+    /// needs before it can find anything
+    pub unknown_footer_token: UnknownFooterTokenConfig,
+    /// How the subject length lint measures the subject
+    pub subject_length: SubjectLengthConfig,
+    /// Which state words the Pivotal Tracker ID lint accepts before the ID
+    #[cfg(feature = "regex-lints")]
+    pub pivotal_tracker_id: PivotalTrackerIdConfig,
+    /// How wide the body width lint lets lines in the body be, and which
+    /// lines it skips
+    pub body_width: BodyWidthConfig,
+    /// What the issue reference lint looks for, and where in the message it
+    /// must be
+    #[cfg(feature = "regex-lints")]
+    pub missing_issue_reference: MissingIssueReferenceConfig,
+    /// Which words the filler words lint looks for in the subject, which it
+    /// needs before it can find anything
+    pub subject_filler_words: SubjectFillerWordsConfig,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            include_comments: false,
+            skip_on_merge: Lints::new(BTreeSet::new()),
+            max_labels_per_problem: None,
+            duplicated_trailers: DuplicatedTrailersConfig::default(),
+            conventional_commit: ConventionalCommitConfig::default(),
+            too_many_trailers: TooManyTrailersConfig::default(),
+            subject_ends_with_punctuation: SubjectEndsWithPunctuationConfig::default(),
+            #[cfg(feature = "regex-lints")]
+            subject_only_ticket: SubjectOnlyTicketConfig::default(),
+            unedited_template: UneditedTemplateConfig::default(),
+            not_emoji_log: NotEmojiLogConfig::default(),
+            #[cfg(feature = "regex-lints")]
+            github_id: GitHubIdConfig::default(),
+            #[cfg(feature = "regex-lints")]
+            subject_missing_prefix: SubjectMissingPrefixConfig::default(),
+            unknown_footer_token: UnknownFooterTokenConfig::default(),
+            subject_length: SubjectLengthConfig::default(),
+            #[cfg(feature = "regex-lints")]
+            pivotal_tracker_id: PivotalTrackerIdConfig::default(),
+            body_width: BodyWidthConfig::default(),
+            #[cfg(feature = "regex-lints")]
+            missing_issue_reference: MissingIssueReferenceConfig::default(),
+            subject_filler_words: SubjectFillerWordsConfig::default(),
+        }
+    }
+}
+
+impl LintConfig {
+    pub(crate) fn comment_char(&self, commit_message: &CommitMessage<'_>) -> Option<char> {
+        if self.include_comments {
+            None
+        } else {
+            commit_message.get_comment_char()
+        }
+    }
+
+    pub(crate) fn skips(&self, lint: Lint, commit_message: &CommitMessage<'_>) -> bool {
+        self.skip_on_merge.contains(lint) && is_merge_commit(commit_message)
+    }
+}
+
+/// Check if the commit message is one git, or a forge like GitHub, writes
+/// for a merge
+///
+/// This looks at the subject, so it matches messages like `Merge branch
+/// 'main' into feature` and `Merge pull request #1 from example/branch`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::is_merge_commit;
+///
+/// assert!(is_merge_commit(&CommitMessage::from(
+///     "Merge branch 'main' into feature\n"
+/// )));
+/// assert!(!is_merge_commit(&CommitMessage::from(
+///     "Merge the two config loaders\n"
+/// )));
+/// ```
+#[must_use]
+pub fn is_merge_commit(commit_message: &CommitMessage<'_>) -> bool {
+    let subject = String::from(commit_message.get_subject());
+
+    MERGE_PREFIXES
+        .iter()
+        .any(|prefix| subject.starts_with(prefix))
+}
+
+/// Get an example `[mit.lint]` config, with every lint in its default state
+///
+/// Each lint has a comment above it describing the problem it finds. Use it
+/// as a starting point for a `.mit-lint.toml`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_lint::{sample_toml, Lint, Lints};
+///
+/// let sample = sample_toml();
+/// assert!(sample
+///     .contains("# Your commit message has duplicated trailers\nduplicated-trailers = true\n"));
+/// assert_eq!(Lints::from_toml(&sample).unwrap(), Lints::default_enabled());
+/// ```
+#[must_use]
+pub fn sample_toml() -> String {
+    let lints: String = Lint::all_lints()
+        .map(|lint| {
+            format!(
+                "\n# {}\n{} = {}\n",
+                lint.title(),
+                lint.name(),
+                lint.enabled_by_default()
+            )
+        })
+        .collect();
+
+    format!("# Turn lints on with true, or off with false\n[mit.lint]\n{lints}")
+}
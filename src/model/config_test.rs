@@ -0,0 +1,662 @@
+use std::collections::HashSet;
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    checks::{
+        body_wider_than_72_characters::BodyWidthConfig,
+        duplicate_trailers::DuplicatedTrailersConfig,
+        not_conventional_commit::ConventionalCommitConfig,
+        not_emoji_log::NotEmojiLogConfig,
+        subject_ends_with_punctuation::SubjectEndsWithPunctuationConfig,
+        subject_filler_words::SubjectFillerWordsConfig,
+        subject_longer_than_72_characters::SubjectLengthConfig,
+        too_many_trailers::TooManyTrailersConfig,
+        unedited_template::UneditedTemplateConfig,
+        unknown_footer_token::UnknownFooterTokenConfig,
+    },
+    cmd::lint_with_config,
+    model::{
+        config::{is_merge_commit, sample_toml},
+        Code,
+        Lint,
+        LintConfig,
+        Lints,
+        Problem,
+    },
+};
+
+#[test]
+fn the_sample_parses_back_to_the_defaults() {
+    assert_eq!(
+        Lints::from_toml(&sample_toml()).unwrap(),
+        Lints::default_enabled()
+    );
+}
+
+#[test]
+fn the_sample_has_every_lint() {
+    let actual = sample_toml();
+
+    for lint in Lint::all_lints() {
+        assert!(
+            actual.contains(&format!(
+                "# {}\n{} = {}\n",
+                lint.title(),
+                lint.name(),
+                lint.enabled_by_default()
+            )),
+            "Expected {:?} in {:?}",
+            lint.name(),
+            actual
+        );
+    }
+}
+
+#[test]
+fn the_sample_starts_with_the_lint_table() {
+    assert!(sample_toml().starts_with(
+        "# Turn lints on with true, or off with false\n[mit.lint]\n\n# Your commit message has \
+         duplicated trailers\nduplicated-trailers = true\n"
+    ));
+}
+
+#[test]
+fn comments_are_skipped_by_default() {
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body content\n\n# {}\n",
+        "x".repeat(80)
+    ));
+
+    assert_eq!(
+        Lint::BodyWiderThan72Characters.lint_with_config(&message, &LintConfig::default()),
+        None
+    );
+}
+
+#[test]
+fn a_long_comment_is_flagged_when_comments_are_included() {
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body content\n\n# {}\n",
+        "x".repeat(80)
+    ));
+
+    let actual = Lint::BodyWiderThan72Characters.lint_with_config(
+        &message,
+        &LintConfig {
+            include_comments: true,
+            ..LintConfig::default()
+        },
+    );
+
+    assert_eq!(
+        actual.map(|problem| *problem.code()),
+        Some(Code::BodyWiderThan72Characters)
+    );
+}
+
+#[cfg(feature = "regex-lints")]
+#[test]
+fn an_issue_key_in_a_comment_only_counts_when_comments_are_included() {
+    let message = CommitMessage::from("An example commit\n\n# Relates-to: JRA-123\n");
+
+    assert!(Lint::JiraIssueKeyMissing
+        .lint_with_config(&message, &LintConfig::default())
+        .is_some());
+    assert!(Lint::JiraIssueKeyMissing
+        .lint_with_config(
+            &message,
+            &LintConfig {
+                include_comments: true,
+                ..LintConfig::default()
+            },
+        )
+        .is_none());
+}
+
+#[test]
+fn the_scissors_are_still_respected_when_comments_are_included() {
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body content\n# ------------------------ >8 \
+         ------------------------\n# Do not modify or remove the line above.\n# Everything \
+         below it will be ignored.\n{}\n",
+        "x".repeat(80)
+    ));
+
+    assert_eq!(
+        Lint::BodyWiderThan72Characters.lint_with_config(
+            &message,
+            &LintConfig {
+                include_comments: true,
+                ..LintConfig::default()
+            },
+        ),
+        None
+    );
+}
+
+#[test]
+fn merge_subjects_are_detected() {
+    for subject in [
+        "Merge branch 'main' into feature",
+        "Merge branches 'a' and 'b'",
+        "Merge remote-tracking branch 'origin/main'",
+        "Merge tag 'v1.0.0'",
+        "Merge commit 'abc123'",
+        "Merge pull request #1 from example/branch",
+    ] {
+        assert!(
+            is_merge_commit(&CommitMessage::from(format!("{subject}\n"))),
+            "Expected {:?} to be a merge",
+            subject
+        );
+    }
+}
+
+#[test]
+fn subjects_that_only_start_with_merge_are_not_merges() {
+    for subject in [
+        "Merge the config loaders",
+        "Merged branch 'main'",
+        "merge branch 'main'",
+    ] {
+        assert!(
+            !is_merge_commit(&CommitMessage::from(format!("{subject}\n"))),
+            "Expected {:?} not to be a merge",
+            subject
+        );
+    }
+}
+
+#[test]
+fn lints_are_skipped_on_merges_only_when_configured() {
+    let lints = Lints::from(vec![
+        Lint::SubjectLongerThan72Characters,
+        Lint::SubjectEndsWithPeriod,
+    ]);
+    let config = LintConfig {
+        skip_on_merge: Lints::from(vec![Lint::SubjectLongerThan72Characters]),
+        ..LintConfig::default()
+    };
+    let merge = CommitMessage::from(format!(
+        "Merge pull request #1 from example/{}.\n",
+        "x".repeat(60)
+    ));
+    let codes = |problems: Vec<Problem>| {
+        problems
+            .iter()
+            .map(|problem| *problem.code())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        codes(lint_with_config(&merge, lints.clone(), &config)),
+        vec![Code::SubjectEndsWithPeriod]
+    );
+    assert_eq!(
+        codes(lint_with_config(&merge, lints, &LintConfig::default())),
+        vec![
+            Code::SubjectLongerThan72Characters,
+            Code::SubjectEndsWithPeriod
+        ]
+    );
+}
+
+#[test]
+fn lints_skipped_on_merges_still_run_on_other_commits() {
+    let config = LintConfig {
+        skip_on_merge: Lints::from(vec![Lint::SubjectLongerThan72Characters]),
+        ..LintConfig::default()
+    };
+    let message = CommitMessage::from(format!("{}\n", "x".repeat(73)));
+
+    assert!(Lint::SubjectLongerThan72Characters
+        .lint_with_config(&message, &config)
+        .is_some());
+}
+
+#[test]
+fn labels_are_capped_only_when_configured() {
+    let message = CommitMessage::from(format!(
+        "An example commit\n\n{}",
+        "Signed-off-by: Someone <someone@example.com>\n".repeat(5)
+    ));
+
+    let uncapped = Lint::DuplicatedTrailers
+        .lint_with_config(&message, &LintConfig::default())
+        .unwrap();
+    let capped = Lint::DuplicatedTrailers
+        .lint_with_config(
+            &message,
+            &LintConfig {
+                max_labels_per_problem: Some(2),
+                ..LintConfig::default()
+            },
+        )
+        .unwrap();
+
+    assert_eq!(uncapped.structured_labels().len(), 4);
+    assert_eq!(
+        capped
+            .structured_labels()
+            .into_iter()
+            .map(|label| label.text)
+            .collect::<Vec<_>>(),
+        vec![
+            "Duplicated `Signed-off-by`".to_string(),
+            "Duplicated `Signed-off-by`".to_string(),
+            "… and 2 more".to_string()
+        ]
+    );
+}
+
+#[test]
+fn duplicated_trailers_can_be_ignored_through_the_config() {
+    let message = CommitMessage::from(
+        "An example commit\n\nSome body\n\nCo-authored-by: Someone <someone@example.com>\nCo-authored-by: Someone <someone@example.com>\n",
+    );
+    let lints = Lints::from(vec![Lint::DuplicatedTrailers]);
+    let config = LintConfig {
+        duplicated_trailers: DuplicatedTrailersConfig {
+            ignore: vec!["Co-authored-by".to_string()],
+        },
+        ..LintConfig::default()
+    };
+
+    assert_eq!(
+        lint_with_config(&message, lints.clone(), &LintConfig::default())
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::DuplicatedTrailers]
+    );
+    assert!(lint_with_config(&message, lints, &config).is_empty());
+}
+
+#[test]
+fn the_angular_types_can_be_required_through_the_config() {
+    let message = CommitMessage::from("wip: add login\n");
+    let lints = Lints::from(vec![Lint::NotConventionalCommit]);
+    let config = LintConfig {
+        conventional_commit: ConventionalCommitConfig::angular(),
+        ..LintConfig::default()
+    };
+
+    assert!(lint_with_config(&message, lints.clone(), &LintConfig::default()).is_empty());
+    assert_eq!(
+        lint_with_config(&message, lints.clone(), &config)
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::NotConventionalCommit]
+    );
+    assert!(lint_with_config(&CommitMessage::from("feat: add login\n"), lints, &config).is_empty());
+}
+
+#[test]
+fn the_trailer_limit_can_be_raised_through_the_config() {
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nSome body\n\n{}",
+        "Refs: #1\n".repeat(6)
+    ));
+    let lints = Lints::from(vec![Lint::TooManyTrailers]);
+    let config = LintConfig {
+        too_many_trailers: TooManyTrailersConfig { max: 6 },
+        ..LintConfig::default()
+    };
+
+    assert_eq!(
+        lint_with_config(&message, lints.clone(), &LintConfig::default())
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::TooManyTrailers]
+    );
+    assert!(lint_with_config(&message, lints, &config).is_empty());
+}
+
+#[test]
+fn subject_punctuation_can_be_chosen_through_the_config() {
+    let message = CommitMessage::from("Add login:\n");
+    let lints = Lints::from(vec![Lint::SubjectEndsWithPunctuation]);
+    let config = LintConfig {
+        subject_ends_with_punctuation: SubjectEndsWithPunctuationConfig {
+            disallowed: HashSet::from([':']),
+        },
+        ..LintConfig::default()
+    };
+
+    assert!(lint_with_config(&message, lints.clone(), &LintConfig::default()).is_empty());
+    assert_eq!(
+        lint_with_config(&message, lints, &config)
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::SubjectEndsWithPunctuation]
+    );
+}
+
+#[cfg(feature = "regex-lints")]
+#[test]
+fn ticket_patterns_can_be_chosen_through_the_config() {
+    use regex::Regex;
+
+    use crate::checks::subject_only_ticket::SubjectOnlyTicketConfig;
+
+    let message = CommitMessage::from("ticket-42\n");
+    let lints = Lints::from(vec![Lint::SubjectOnlyTicket]);
+    let config = LintConfig {
+        subject_only_ticket: SubjectOnlyTicketConfig {
+            patterns: vec![Regex::new(r"ticket-[0-9]+").unwrap()],
+        },
+        ..LintConfig::default()
+    };
+
+    assert!(lint_with_config(&message, lints.clone(), &LintConfig::default()).is_empty());
+    assert_eq!(
+        lint_with_config(&message, lints, &config)
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::SubjectOnlyTicket]
+    );
+}
+
+#[test]
+fn the_commit_template_can_be_given_through_the_config() {
+    let template = "Summary of the change\n\n# Why was this change made?\n";
+    let message = CommitMessage::from(template);
+    let lints = Lints::from(vec![Lint::UneditedTemplate]);
+    let config = LintConfig {
+        unedited_template: UneditedTemplateConfig {
+            template: template.to_string(),
+        },
+        ..LintConfig::default()
+    };
+
+    assert!(lint_with_config(&message, lints.clone(), &LintConfig::default()).is_empty());
+    assert_eq!(
+        lint_with_config(&message, lints, &config)
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::UneditedTemplate]
+    );
+}
+
+#[test]
+fn emoji_log_separators_can_be_chosen_through_the_config() {
+    let message = CommitMessage::from("🐛 FIX - Fix the login redirect\n");
+    let lints = Lints::from(vec![Lint::NotEmojiLog]);
+    let config = LintConfig {
+        not_emoji_log: NotEmojiLogConfig {
+            separators: vec![':', '-'],
+            ..NotEmojiLogConfig::default()
+        },
+        ..LintConfig::default()
+    };
+
+    assert_eq!(
+        lint_with_config(&message, lints.clone(), &LintConfig::default())
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::NotEmojiLog]
+    );
+    assert!(lint_with_config(&message, lints, &config).is_empty());
+}
+
+#[cfg(feature = "regex-lints")]
+#[test]
+fn full_github_urls_can_be_accepted_through_the_config() {
+    use crate::checks::missing_github_id::GitHubIdConfig;
+
+    let message = CommitMessage::from(
+        "An example commit\n\nRelates-to: https://github.com/AnOrganisation/git-mit/issues/642\n",
+    );
+    let lints = Lints::from(vec![Lint::GitHubIdMissing]);
+    let config = LintConfig {
+        github_id: GitHubIdConfig {
+            accept_full_urls: true,
+        },
+        ..LintConfig::default()
+    };
+
+    assert_eq!(
+        lint_with_config(&message, lints.clone(), &LintConfig::default())
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::GitHubIdMissing]
+    );
+    assert!(lint_with_config(&message, lints, &config).is_empty());
+}
+
+#[cfg(feature = "regex-lints")]
+#[test]
+fn the_subject_prefix_can_be_required_through_the_config() {
+    use regex::Regex;
+
+    use crate::checks::subject_missing_prefix::SubjectMissingPrefixConfig;
+
+    let lints = Lints::from(vec![Lint::SubjectMissingPrefix]);
+    let config = LintConfig {
+        subject_missing_prefix: SubjectMissingPrefixConfig {
+            pattern: Some(Regex::new(r"\[[A-Z]+-[0-9]+\] ").unwrap()),
+        },
+        ..LintConfig::default()
+    };
+    let message = CommitMessage::from("Add login\n");
+
+    assert!(lint_with_config(&message, lints.clone(), &LintConfig::default()).is_empty());
+    assert_eq!(
+        lint_with_config(&message, lints.clone(), &config)
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::SubjectMissingPrefix]
+    );
+    assert!(lint_with_config(
+        &CommitMessage::from("[ABC-123] Add login\n"),
+        lints,
+        &config
+    )
+    .is_empty());
+}
+
+#[test]
+fn allowed_trailer_tokens_can_be_given_through_the_config() {
+    let lints = Lints::from(vec![Lint::UnknownFooterToken]);
+    let config = LintConfig {
+        unknown_footer_token: UnknownFooterTokenConfig {
+            allowed: HashSet::from(["Refs".to_string()]),
+        },
+        ..LintConfig::default()
+    };
+    let message = CommitMessage::from("An example commit\n\nSome body\n\nFixes: #1\n");
+
+    assert!(lint_with_config(&message, lints.clone(), &LintConfig::default()).is_empty());
+    assert_eq!(
+        lint_with_config(&message, lints.clone(), &config)
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::UnknownFooterToken]
+    );
+    assert!(lint_with_config(
+        &CommitMessage::from("An example commit\n\nSome body\n\nRefs: #1\n"),
+        lints,
+        &config
+    )
+    .is_empty());
+}
+
+#[test]
+fn the_conventional_prefix_can_be_excluded_from_the_subject_length_through_the_config() {
+    let message = CommitMessage::from(format!("feat(scope): {}\n", "x".repeat(65)));
+    let lints = Lints::from(vec![Lint::SubjectLongerThan72Characters]);
+    let config = LintConfig {
+        subject_length: SubjectLengthConfig {
+            exclude_conventional_prefix: true,
+            ..SubjectLengthConfig::default()
+        },
+        ..LintConfig::default()
+    };
+
+    assert_eq!(
+        lint_with_config(&message, lints.clone(), &LintConfig::default())
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::SubjectLongerThan72Characters]
+    );
+    assert!(lint_with_config(&message, lints, &config).is_empty());
+}
+
+#[cfg(feature = "regex-lints")]
+#[test]
+fn pivotal_tracker_state_words_can_be_added_through_the_config() {
+    use crate::checks::missing_pivotal_tracker_id::PivotalTrackerIdConfig;
+
+    let message = CommitMessage::from("An example commit\n\n[resolves #12345678]\n");
+    let lints = Lints::from(vec![Lint::PivotalTrackerIdMissing]);
+    let config = LintConfig {
+        pivotal_tracker_id: PivotalTrackerIdConfig {
+            extra_state_words: vec!["resolves".to_string()],
+        },
+        ..LintConfig::default()
+    };
+
+    assert_eq!(
+        lint_with_config(&message, lints.clone(), &LintConfig::default())
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::PivotalTrackerIdMissing]
+    );
+    assert!(lint_with_config(&message, lints, &config).is_empty());
+}
+
+#[test]
+fn long_urls_can_be_allowed_through_the_config() {
+    let message = CommitMessage::from(format!(
+        "An example commit\n\nhttps://example.com/{}\n",
+        "x".repeat(80)
+    ));
+    let lints = Lints::from(vec![Lint::BodyWiderThan72Characters]);
+    let config = LintConfig {
+        body_width: BodyWidthConfig {
+            allow_long_urls: true,
+            ..BodyWidthConfig::default()
+        },
+        ..LintConfig::default()
+    };
+
+    assert_eq!(
+        lint_with_config(&message, lints.clone(), &LintConfig::default())
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::BodyWiderThan72Characters]
+    );
+    assert!(lint_with_config(&message, lints, &config).is_empty());
+}
+
+#[test]
+fn a_subject_byte_limit_can_be_set_through_the_config() {
+    let message = CommitMessage::from(format!("Add {}\n", "🎉".repeat(10)));
+    let lints = Lints::from(vec![Lint::SubjectLongerThan72Characters]);
+    let config = LintConfig {
+        subject_length: SubjectLengthConfig {
+            byte_limit: Some(40),
+            ..SubjectLengthConfig::default()
+        },
+        ..LintConfig::default()
+    };
+
+    assert!(lint_with_config(&message, lints.clone(), &LintConfig::default()).is_empty());
+    assert_eq!(
+        lint_with_config(&message, lints, &config)
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::SubjectLongerThan72Characters]
+    );
+}
+
+#[test]
+fn the_first_body_paragraph_can_be_exempt_through_the_config() {
+    let message = CommitMessage::from(format!(
+        "An example commit\n\n{}\n\nSome body\n",
+        "word ".repeat(20).trim_end()
+    ));
+    let lints = Lints::from(vec![Lint::BodyWiderThan72Characters]);
+    let config = LintConfig {
+        body_width: BodyWidthConfig {
+            exempt_first_paragraph: true,
+            ..BodyWidthConfig::default()
+        },
+        ..LintConfig::default()
+    };
+
+    assert_eq!(
+        lint_with_config(&message, lints.clone(), &LintConfig::default())
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::BodyWiderThan72Characters]
+    );
+    assert!(lint_with_config(&message, lints, &config).is_empty());
+}
+
+#[cfg(feature = "regex-lints")]
+#[test]
+fn the_issue_reference_can_be_required_in_the_footer_through_the_config() {
+    use crate::checks::missing_issue_reference::{Location, MissingIssueReferenceConfig};
+
+    let message = CommitMessage::from("Fix #123\n\nSome body\n");
+    let lints = Lints::from(vec![Lint::MissingIssueReference]);
+    let config = LintConfig {
+        missing_issue_reference: MissingIssueReferenceConfig {
+            location: Location::Footer,
+            ..MissingIssueReferenceConfig::default()
+        },
+        ..LintConfig::default()
+    };
+
+    assert!(lint_with_config(&message, lints.clone(), &LintConfig::default()).is_empty());
+    assert_eq!(
+        lint_with_config(&message, lints.clone(), &config)
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::MissingIssueReference]
+    );
+    assert!(lint_with_config(
+        &CommitMessage::from("Fix the login\n\nSome body\n\nRefs: #123\n"),
+        lints,
+        &config
+    )
+    .is_empty());
+}
+
+#[test]
+fn filler_words_can_be_given_through_the_config() {
+    let message = CommitMessage::from("Just fix the login\n");
+    let lints = Lints::from(vec![Lint::SubjectFillerWords]);
+    let config = LintConfig {
+        subject_filler_words: SubjectFillerWordsConfig {
+            words: HashSet::from(["just".to_string()]),
+        },
+        ..LintConfig::default()
+    };
+
+    assert!(lint_with_config(&message, lints.clone(), &LintConfig::default()).is_empty());
+    assert_eq!(
+        lint_with_config(&message, lints, &config)
+            .iter()
+            .map(Problem::code)
+            .collect::<Vec<_>>(),
+        vec![&Code::SubjectFillerWords]
+    );
+}
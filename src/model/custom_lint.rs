@@ -0,0 +1,54 @@
+use mit_commit::CommitMessage;
+
+use crate::model::{Code, Problem};
+
+/// A lint written outside this crate
+///
+/// Implement this for your own rules to run them alongside the built-in lints
+/// with [`lint_with_custom`](crate::lint_with_custom), so they're reported the
+/// same way. [`ProblemBuilder`](crate::ProblemBuilder) helps with building
+/// the problem.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{Code, CustomLint, Problem, ProblemBuilder};
+///
+/// struct NoLogin;
+///
+/// impl CustomLint for NoLogin {
+///     fn code(&self) -> Code {
+///         Code::NotConventionalCommit
+///     }
+///
+///     fn check(&self, commit: &CommitMessage<'_>) -> Option<Problem> {
+///         String::from(commit.get_subject())
+///             .contains("login")
+///             .then(|| {
+///                 ProblemBuilder::new(
+///                     "Your commit mentions logging in".to_string(),
+///                     "Describe the change without mentioning login".to_string(),
+///                     self.code(),
+///                     commit,
+///                 )
+///                 .with_label_for_line("Mentions login".to_string(), 0)
+///                 .build()
+///             })
+///     }
+/// }
+///
+/// assert!(NoLogin
+///     .check(&CommitMessage::from("Fix the login form\n"))
+///     .is_some());
+/// assert!(NoLogin
+///     .check(&CommitMessage::from("Fix the signup form\n"))
+///     .is_none());
+/// ```
+pub trait CustomLint {
+    /// The code the problems this lint finds are reported with
+    fn code(&self) -> Code;
+
+    /// Check the commit message, returning a [`Problem`] if it fails
+    fn check(&self, commit: &CommitMessage<'_>) -> Option<Problem>;
+}
@@ -0,0 +1,41 @@
+/// A labelled part of the commit message that a [`Problem`](crate::Problem)
+/// points at
+///
+/// The offset and length are in bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_lint::Label;
+///
+/// let label = Label::from(("Too long".to_string(), 72, 1));
+/// assert_eq!(label.text, "Too long");
+/// assert_eq!(label.offset, 72);
+/// assert_eq!(label.length, 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Label {
+    /// What is wrong with this part of the message
+    pub text: String,
+    /// Where the label starts
+    pub offset: usize,
+    /// How long the label is
+    pub length: usize,
+}
+
+impl From<(String, usize, usize)> for Label {
+    fn from((text, offset, length): (String, usize, usize)) -> Self {
+        Self {
+            text,
+            offset,
+            length,
+        }
+    }
+}
+
+impl From<Label> for (String, usize, usize) {
+    fn from(label: Label) -> Self {
+        (label.text, label.offset, label.length)
+    }
+}
@@ -3,8 +3,9 @@ use std::{
     str::FromStr,
 };
 
-use miette::Diagnostic;
+use miette::{Diagnostic, Severity};
 use mit_commit::CommitMessage;
+#[cfg(any(test, feature = "quickcheck"))]
 use quickcheck::{Arbitrary, Gen};
 use strum_macros::EnumIter;
 use thiserror::Error;
@@ -12,7 +13,7 @@ use thiserror::Error;
 use crate::{
     checks,
     model,
-    model::{Lints, Problem},
+    model::{Category, Code, LintConfig, Lints, Problem},
 };
 
 /// The lints that are supported
@@ -81,11 +82,15 @@ pub enum Lint {
     DuplicatedTrailers,
     /// Check for a missing pivotal tracker id
     ///
+    /// Only runs with the `regex-lints` feature enabled
+    ///
     /// # Examples
     ///
     /// Passing
     ///
     /// ```rust
+    /// # #[cfg(feature = "regex-lints")]
+    /// # {
     /// use mit_commit::CommitMessage;
     /// use mit_lint::Lint;
     ///
@@ -94,11 +99,14 @@ pub enum Lint {
     /// .into();
     /// let actual = Lint::PivotalTrackerIdMissing.lint(&CommitMessage::from(message));
     /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// # }
     /// ```
     ///
     /// Erring
     ///
     /// ```rust
+    /// # #[cfg(feature = "regex-lints")]
+    /// # {
     /// use mit_commit::CommitMessage;
     /// use mit_lint::{Code, Lint, Problem};
     ///
@@ -123,15 +131,20 @@ pub enum Lint {
     ///     "Expected {:?}, found {:?}",
     ///     expected, actual
     /// );
+    /// # }
     /// ```
     PivotalTrackerIdMissing,
     /// Check for a missing jira issue key
     ///
+    /// Only runs with the `regex-lints` feature enabled
+    ///
     /// # Examples
     ///
     /// Passing
     ///
     /// ```rust
+    /// # #[cfg(feature = "regex-lints")]
+    /// # {
     /// use mit_commit::CommitMessage;
     /// use mit_lint::Lint;
     ///
@@ -142,11 +155,14 @@ pub enum Lint {
     /// .into();
     /// let actual = Lint::JiraIssueKeyMissing.lint(&CommitMessage::from(message));
     /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// # }
     /// ```
     ///
     /// Erring
     ///
     /// ```rust
+    /// # #[cfg(feature = "regex-lints")]
+    /// # {
     /// use mit_commit::CommitMessage;
     /// use mit_lint::{Code, Lint, Problem};
     ///
@@ -170,15 +186,20 @@ pub enum Lint {
     ///     "Expected {:?}, found {:?}",
     ///     expected, actual
     /// );
+    /// # }
     /// ```
     JiraIssueKeyMissing,
     /// Check for a missing github id
     ///
+    /// Only runs with the `regex-lints` feature enabled
+    ///
     /// # Examples
     ///
     /// Passing
     ///
     /// ```rust
+    /// # #[cfg(feature = "regex-lints")]
+    /// # {
     /// use mit_commit::CommitMessage;
     /// use mit_lint::Lint;
     ///
@@ -189,11 +210,14 @@ pub enum Lint {
     /// .into();
     /// let actual = Lint::GitHubIdMissing.lint(&CommitMessage::from(message));
     /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// # }
     /// ```
     ///
     /// Erring
     ///
     /// ```rust
+    /// # #[cfg(feature = "regex-lints")]
+    /// # {
     /// use mit_commit::CommitMessage;
     /// use mit_lint::{Code, Lint, Problem};
     ///
@@ -216,6 +240,7 @@ pub enum Lint {
     ///     "Expected {:?}, found {:?}",
     ///     expected, actual
     /// );
+    /// # }
     /// ```
     GitHubIdMissing,
     /// Subject being not being seperated from the body
@@ -465,6 +490,9 @@ pub enum Lint {
     NotConventionalCommit,
     /// Check for commits following the emoji log standard
     ///
+    /// This is the opposite of [`Lint::SubjectLeadingEmoji`], so teams should
+    /// only turn on one of the two.
+    ///
     /// # Examples
     ///
     /// Passing
@@ -502,183 +530,2645 @@ pub enum Lint {
     /// );
     /// ```
     NotEmojiLog,
-}
-
-/// The prefix we put in front of the lint when serialising
-pub const CONFIG_KEY_PREFIX: &str = "mit.lint";
-
-impl TryFrom<&str> for Lint {
-    type Error = Error;
-
-    fn try_from(from: &str) -> Result<Self, Self::Error> {
-        Self::all_lints()
-            .zip(Self::all_lints().map(|lint| format!("{lint}")))
-            .filter_map(|(lint, name): (Self, String)| if name == from { Some(lint) } else { None })
-            .collect::<Vec<Self>>()
-            .first()
-            .copied()
-            .ok_or_else(|| Error::new_lint_not_found(from.into()))
-    }
-}
-
-impl From<Lint> for String {
-    fn from(from: Lint) -> Self {
-        format!("{from}")
-    }
-}
-
-impl From<Lint> for &str {
-    /// Get an lint's unique name
+    /// Check for a diff pasted into the body
     ///
     /// # Examples
     ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "An example commit\n\n- Some Body Content\n- More Body Content\n";
+    /// let actual = Lint::DiffPastedInBody.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::diff_pasted_in_body::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "An example commit\n\n@@ -1 +1 @@\n-Old\n+New\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::DiffPastedInBody,
+    ///     &message.into(),
+    ///     Some(vec![("Pasted diff".to_string(), 19, 11)]),
+    ///     Some("https://git-scm.com/docs/git-commit".to_string()),
+    /// ));
+    /// let actual = Lint::DiffPastedInBody.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
     /// ```
+    DiffPastedInBody,
+    /// Check for more trailers than the configured maximum
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
     /// use mit_lint::Lint;
-    /// let actual: &str = Lint::NotConventionalCommit.into();
-    /// assert_eq!(actual, Lint::NotConventionalCommit.name());
+    ///
+    /// let message: &str = "An example commit\n\nSome Body Content\n\nRelates-to: #123\n";
+    /// let actual = Lint::TooManyTrailers.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
     /// ```
-    fn from(lint: Lint) -> Self {
-        lint.name()
-    }
-}
-
-impl Lint {
-    /// Get an lint's unique name
-    #[must_use]
-    pub const fn name(self) -> &'static str {
-        match self {
-            Self::DuplicatedTrailers => checks::duplicate_trailers::CONFIG,
-            Self::PivotalTrackerIdMissing => checks::missing_pivotal_tracker_id::CONFIG,
-            Self::JiraIssueKeyMissing => checks::missing_jira_issue_key::CONFIG,
-            Self::GitHubIdMissing => checks::missing_github_id::CONFIG,
-            Self::SubjectNotSeparateFromBody => checks::subject_not_separate_from_body::CONFIG,
-            Self::SubjectLongerThan72Characters => {
-                checks::subject_longer_than_72_characters::CONFIG
-            }
-            Self::SubjectNotCapitalized => checks::subject_not_capitalized::CONFIG,
-            Self::SubjectEndsWithPeriod => checks::subject_line_ends_with_period::CONFIG,
-            Self::BodyWiderThan72Characters => checks::body_wider_than_72_characters::CONFIG,
-            Self::NotConventionalCommit => checks::not_conventional_commit::CONFIG,
-            Self::NotEmojiLog => checks::not_emoji_log::CONFIG,
-        }
-    }
-}
-
-lazy_static! {
-    /// All the available lints
-    static ref ALL_LINTS: [Lint; 11] = [
-        Lint::DuplicatedTrailers,
-        Lint::PivotalTrackerIdMissing,
-        Lint::JiraIssueKeyMissing,
-        Lint::SubjectNotSeparateFromBody,
-        Lint::GitHubIdMissing,
-        Lint::SubjectLongerThan72Characters,
-        Lint::SubjectNotCapitalized,
-        Lint::SubjectEndsWithPeriod,
-        Lint::BodyWiderThan72Characters,
-        Lint::NotConventionalCommit,
-        Lint::NotEmojiLog,
-    ];
-    /// The ones that are enabled by default
-    static ref DEFAULT_ENABLED_LINTS: [Lint; 4] = [
-        Lint::DuplicatedTrailers,
-        Lint::SubjectNotSeparateFromBody,
-        Lint::SubjectLongerThan72Characters,
-        Lint::BodyWiderThan72Characters,
-    ];
-}
-
-impl Lint {
-    /// Iterator over all the lints
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::too_many_trailers::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "An example commit\n\nSome Body Content\n\nRelates-to: #1\nRelates-to: #2\nRelates-to: #3\nRelates-to: #4\nRelates-to: #5\nRelates-to: #6\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::TooManyTrailers,
+    ///     &message.into(),
+    ///     Some(vec![("More than 5 trailers".to_string(), 113, 14)]),
+    ///     Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+    /// ));
+    /// let actual = Lint::TooManyTrailers.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    TooManyTrailers,
+    /// Check for subjects that end with punctuation
     ///
     /// # Examples
     ///
+    /// Passing
+    ///
     /// ```rust
+    /// use mit_commit::CommitMessage;
     /// use mit_lint::Lint;
-    /// assert!(Lint::all_lints().next().is_some())
+    ///
+    /// let message: &str = "An example commit\n\nSome Body Content\n";
+    /// let actual = Lint::SubjectEndsWithPunctuation.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
     /// ```
-    pub fn all_lints() -> impl Iterator<Item = Self> {
-        ALL_LINTS.iter().copied()
-    }
-
-    /// Iterator over all the lints
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::subject_ends_with_punctuation::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "An example commit?\n\nSome Body Content\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::SubjectEndsWithPunctuation,
+    ///     &message.into(),
+    ///     Some(vec![("Unneeded `?`".to_string(), 17, 1)]),
+    ///     Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+    /// ));
+    /// let actual = Lint::SubjectEndsWithPunctuation.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    SubjectEndsWithPunctuation,
+    /// Check for a subject that is only a ticket reference
+    ///
+    /// Only runs with the `regex-lints` feature enabled.
     ///
     /// # Examples
     ///
+    /// Passing
+    ///
     /// ```rust
+    /// # #[cfg(feature = "regex-lints")]
+    /// # {
+    /// use mit_commit::CommitMessage;
     /// use mit_lint::Lint;
-    /// assert!(Lint::iterator().next().is_some())
+    ///
+    /// let message: &str = "JRA-123 Fix the login page\n\nSome Body Content\n";
+    /// let actual = Lint::SubjectOnlyTicket.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// # }
     /// ```
-    #[deprecated(since = "0.1.5", note = "iterator was an unusual name. Use all_lints")]
-    pub fn iterator() -> impl Iterator<Item = Self> {
-        Self::all_lints()
-    }
-
-    /// Check if a lint is enabled by default
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "regex-lints")]
+    /// # {
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::subject_only_ticket::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "JRA-123\n\nSome Body Content\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::SubjectOnlyTicket,
+    ///     &message.into(),
+    ///     Some(vec![("No description".to_string(), 0, 7)]),
+    ///     Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+    /// ));
+    /// let actual = Lint::SubjectOnlyTicket.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// # }
+    /// ```
+    SubjectOnlyTicket,
+    /// Check for subjects that start with a verb ending in "ing"
     ///
     /// # Examples
     ///
+    /// Passing
+    ///
     /// ```rust
+    /// use mit_commit::CommitMessage;
     /// use mit_lint::Lint;
-    /// assert!(Lint::SubjectNotSeparateFromBody.enabled_by_default());
-    /// assert!(!Lint::NotConventionalCommit.enabled_by_default());
+    ///
+    /// let message: &str = "Add an example commit\n\nSome Body Content\n";
+    /// let actual = Lint::SubjectGerundStart.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
     /// ```
-    #[must_use]
-    pub fn enabled_by_default(self) -> bool {
-        DEFAULT_ENABLED_LINTS.contains(&self)
-    }
-
-    /// Get a key suitable for a configuration document
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{checks::subject_gerund_start::ERROR, Code, Lint, Problem};
+    ///
+    /// let message: &str = "Adding an example commit\n\nSome Body Content\n";
+    /// let actual = Lint::SubjectGerundStart
+    ///     .lint(&CommitMessage::from(message))
+    ///     .unwrap();
+    /// assert_eq!(actual.code(), &Code::SubjectGerundStart);
+    /// assert_eq!(actual.error(), ERROR);
+    /// assert!(actual.tip().ends_with("like \"Add\" instead of \"Adding\""));
+    /// ```
+    SubjectGerundStart,
+    /// Check for subjects written as a markdown heading
     ///
     /// # Examples
     ///
+    /// Passing
+    ///
     /// ```rust
+    /// use mit_commit::CommitMessage;
     /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "An example commit\n\nSome Body Content\n";
+    /// let actual = Lint::SubjectMarkdownHeading.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::subject_markdown_heading::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "## An example commit\n\nSome Body Content\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::SubjectMarkdownHeading,
+    ///     &message.into(),
+    ///     Some(vec![("Heading marker".to_string(), 0, 2)]),
+    ///     Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+    /// ));
+    /// let actual =
+    ///     Lint::SubjectMarkdownHeading.lint_with_comment_char(&CommitMessage::from(message), ';');
     /// assert_eq!(
-    ///     Lint::SubjectNotSeparateFromBody.config_key(),
-    ///     "mit.lint.subject-not-separated-from-body"
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
     /// );
     /// ```
-    #[must_use]
-    pub fn config_key(self) -> String {
-        format!("{CONFIG_KEY_PREFIX}.{self}")
-    }
-
-    /// Run this lint on a commit message
+    SubjectMarkdownHeading,
+    /// Check for a commit message that is still the unedited commit template
+    ///
+    /// There's no template to compare against by default, so this only
+    /// fails when a template is configured in
+    /// [`LintConfig::unedited_template`] and the lint is run with
+    /// [`Lint::lint_with_config`].
     ///
     /// # Examples
     ///
+    /// Passing
+    ///
     /// ```rust
     /// use mit_commit::CommitMessage;
     /// use mit_lint::Lint;
-    /// let actual =
-    ///     Lint::NotConventionalCommit.lint(&CommitMessage::from("An example commit message"));
-    /// assert!(actual.is_some());
+    ///
+    /// let message: &str = "An example commit\n\nSome Body Content\n";
+    /// let actual = Lint::UneditedTemplate.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
     /// ```
-    #[must_use]
-    pub fn lint(self, commit_message: &CommitMessage<'_>) -> Option<Problem> {
-        match self {
-            Self::DuplicatedTrailers => checks::duplicate_trailers::lint(commit_message),
-            Self::PivotalTrackerIdMissing => {
-                checks::missing_pivotal_tracker_id::lint(commit_message)
-            }
-            Self::JiraIssueKeyMissing => checks::missing_jira_issue_key::lint(commit_message),
-            Self::GitHubIdMissing => checks::missing_github_id::lint(commit_message),
-            Self::SubjectNotSeparateFromBody => {
-                checks::subject_not_separate_from_body::lint(commit_message)
-            }
-            Self::SubjectLongerThan72Characters => {
-                checks::subject_longer_than_72_characters::lint(commit_message)
-            }
-            Self::SubjectNotCapitalized => checks::subject_not_capitalized::lint(commit_message),
-            Self::SubjectEndsWithPeriod => {
-                checks::subject_line_ends_with_period::lint(commit_message)
-            }
-            Self::BodyWiderThan72Characters => {
-                checks::body_wider_than_72_characters::lint(commit_message)
-            }
-            Self::NotConventionalCommit => checks::not_conventional_commit::lint(commit_message),
-            Self::NotEmojiLog => checks::not_emoji_log::lint(commit_message),
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::unedited_template::{lint_with_config, UneditedTemplateConfig, ERROR},
+    ///     Code,
+    /// };
+    ///
+    /// let config = UneditedTemplateConfig {
+    ///     template: "Subject\n\nWhy this change is needed\n".into(),
+    /// };
+    /// let message: &str = "Subject\n\nWhy this change is needed\n# A comment\n";
+    /// let actual = lint_with_config(&CommitMessage::from(message), &config).unwrap();
+    /// assert_eq!(actual.code(), &Code::UneditedTemplate);
+    /// assert_eq!(actual.error(), ERROR);
+    /// ```
+    UneditedTemplate,
+    /// Check for subjects written in all capital letters
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Fix the LOGIN bug\n\nSome Body Content\n";
+    /// let actual = Lint::SubjectAllCaps.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::subject_all_caps::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "FIX THE LOGIN BUG\n\nSome Body Content\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::SubjectAllCaps,
+    ///     &message.into(),
+    ///     Some(vec![("Shouting".to_string(), 0, 17)]),
+    ///     Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+    /// ));
+    /// let actual = Lint::SubjectAllCaps.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    SubjectAllCaps,
+    /// Check for lines in the trailer block that git won't parse as trailers
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "An example commit\n\nSome Body Content\n\nSigned-off-by: Someone Else <someone@example.com>\n";
+    /// let actual = Lint::MalformedTrailer.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::malformed_trailer::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "An example commit\n\nSome Body Content\n\nSigned off by: Someone Else <someone@example.com>\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::MalformedTrailer,
+    ///     &message.into(),
+    ///     Some(vec![("Malformed trailer".to_string(), 38, 49)]),
+    ///     Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+    /// ));
+    /// let actual = Lint::MalformedTrailer.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    MalformedTrailer,
+    /// Check for commit messages that are empty once comments are removed
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "An example commit\n\nSome Body Content\n";
+    /// let actual = Lint::EmptyCommitMessage.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::empty_commit_message::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "\n# Please enter the commit message for your changes.\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::EmptyCommitMessage,
+    ///     &message.into(),
+    ///     None,
+    ///     Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+    /// ));
+    /// let actual = Lint::EmptyCommitMessage.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    EmptyCommitMessage,
+    /// Check for paragraphs in the body that are too long to skim
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "An example commit\n\nSome Body Content\n\nMore Body Content\n";
+    /// let actual = Lint::BodyParagraphTooLong.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{checks::body_paragraph_too_long::ERROR, Code, Lint};
+    ///
+    /// let message = format!(
+    ///     "An example commit\n\n{}",
+    ///     "Some body content that goes on and on\n".repeat(20)
+    /// );
+    /// let actual = Lint::BodyParagraphTooLong
+    ///     .lint(&CommitMessage::from(message))
+    ///     .unwrap();
+    /// assert_eq!(actual.code(), &Code::BodyParagraphTooLong);
+    /// assert_eq!(actual.error(), ERROR);
+    /// ```
+    BodyParagraphTooLong,
+    /// Check for extra blank lines at the start or end of the body
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "An example commit\n\nSome Body Content\n";
+    /// let actual = Lint::BodyLeadingTrailingBlankLines.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::body_leading_trailing_blanks::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "An example commit\n\n\nSome Body Content\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::BodyLeadingTrailingBlankLines,
+    ///     &message.into(),
+    ///     Some(vec![("Extra blank line".to_string(), 19, 1)]),
+    ///     Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+    /// ));
+    /// let actual = Lint::BodyLeadingTrailingBlankLines.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    BodyLeadingTrailingBlankLines,
+    /// Check for revert subjects that don't quote the reverted subject
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Revert \"Add login\"\n\nThis reverts commit 1234567.\n";
+    /// let actual = Lint::MalformedRevert.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::malformed_revert::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "Revert login change\n\nThis reverts commit 1234567.\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::MalformedRevert,
+    ///     &message.into(),
+    ///     Some(vec![("Malformed revert".to_string(), 0, 19)]),
+    ///     Some("https://git-scm.com/docs/git-revert".to_string()),
+    /// ));
+    /// let actual = Lint::MalformedRevert.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    MalformedRevert,
+    /// Check for subjects that don't start with a required prefix
+    ///
+    /// There's no prefix required by default, so this only fails when a
+    /// pattern is configured in [`LintConfig::subject_missing_prefix`] and
+    /// the lint is run with [`Lint::lint_with_config`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "regex-lints")]
+    /// # {
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::subject_missing_prefix::{SubjectMissingPrefixConfig, ERROR},
+    ///     Lint,
+    ///     LintConfig,
+    /// };
+    /// use regex::Regex;
+    ///
+    /// let message = CommitMessage::from("Add login\n\nSome Body Content\n");
+    /// assert!(Lint::SubjectMissingPrefix.lint(&message).is_none());
+    ///
+    /// let config = LintConfig {
+    ///     subject_missing_prefix: SubjectMissingPrefixConfig {
+    ///         pattern: Some(Regex::new(r"\[[A-Z]+-[0-9]+\] ").unwrap()),
+    ///     },
+    ///     ..LintConfig::default()
+    /// };
+    /// let actual = Lint::SubjectMissingPrefix
+    ///     .lint_with_config(&message, &config)
+    ///     .unwrap();
+    /// assert_eq!(actual.error(), ERROR);
+    /// # }
+    /// ```
+    SubjectMissingPrefix,
+    /// Check for subjects that start with an emoji
+    ///
+    /// This is the opposite of [`Lint::NotEmojiLog`], so teams should only
+    /// turn on one of the two.
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Fix bug\n\nSome Body Content\n";
+    /// let actual = Lint::SubjectLeadingEmoji.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::subject_leading_emoji::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "\u{1f41b} fix bug\n\nSome Body Content\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::SubjectLeadingEmoji,
+    ///     &message.into(),
+    ///     Some(vec![("Leading emoji".to_string(), 0, 4)]),
+    ///     Some("https://cbea.ms/git-commit/#limit-50".to_string()),
+    /// ));
+    /// let actual = Lint::SubjectLeadingEmoji.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    SubjectLeadingEmoji,
+    /// Check for lines in the body that are only a separator, like `----`
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "An example commit\n\n- A bullet\n";
+    /// let actual = Lint::BodySeparatorLine.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::body_separator_line::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "An example commit\n\nSome Body Content\n----\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::BodySeparatorLine,
+    ///     &message.into(),
+    ///     Some(vec![("Separator line".to_string(), 37, 4)]),
+    ///     Some("https://git-scm.com/docs/git-commit#_discussion".to_string()),
+    /// ));
+    /// let actual = Lint::BodySeparatorLine.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    BodySeparatorLine,
+    /// Check for tab characters in the body
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "An example commit\n\nSome Body Content\n";
+    /// let actual = Lint::BodyContainsTabs.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::body_contains_tabs::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "An example commit\n\nSome\tBody Content\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::BodyContainsTabs,
+    ///     &message.into(),
+    ///     Some(vec![("Tab".to_string(), 23, 1)]),
+    ///     Some("https://git-scm.com/docs/git-log".to_string()),
+    /// ));
+    /// let actual = Lint::BodyContainsTabs.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    BodyContainsTabs,
+    /// Check for trailers with a token that isn't allowed
+    ///
+    /// There are no tokens allowed by default, so this only fails when some
+    /// are configured in [`LintConfig::unknown_footer_token`] and the lint is
+    /// run with [`Lint::lint_with_config`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::unknown_footer_token::{lint_with_config, UnknownFooterTokenConfig, ERROR},
+    ///     Lint,
+    /// };
+    ///
+    /// let message = CommitMessage::from("An example commit\n\nSome Body Content\n\nFixes: #1\n");
+    /// assert!(Lint::UnknownFooterToken.lint(&message).is_none());
+    ///
+    /// let config = UnknownFooterTokenConfig {
+    ///     allowed: vec!["Refs".to_string()].into_iter().collect(),
+    /// };
+    /// let actual = lint_with_config(&message, &config).unwrap();
+    /// assert_eq!(actual.error(), ERROR);
+    /// ```
+    UnknownFooterToken,
+    /// Check for a body that doesn't say anything
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "An example commit\n\nExplain why the cache is cleared\n";
+    /// let actual = Lint::BodyNotMeaningful.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::body_not_meaningful::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "An example commit\n\nasdf asdf\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::BodyNotMeaningful,
+    ///     &message.into(),
+    ///     Some(vec![("Not meaningful".to_string(), 19, 9)]),
+    ///     Some("https://cbea.ms/git-commit/#why-not-how".to_string()),
+    /// ));
+    /// let actual = Lint::BodyNotMeaningful.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    BodyNotMeaningful,
+    /// Check for a subject that lists more than one change
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Add login\n";
+    /// let actual = Lint::SubjectNotAtomic.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::subject_not_atomic::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "Add login and fix logout\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::SubjectNotAtomic,
+    ///     &message.into(),
+    ///     Some(vec![("More than one change".to_string(), 10, 3)]),
+    ///     Some("https://git-scm.com/book/en/v2/Distributed-Git-Contributing-to-a-Project#_commit_guidelines".to_string()),
+    /// ));
+    /// let actual = Lint::SubjectNotAtomic.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    SubjectNotAtomic,
+    /// Check for a body that doesn't seem to be about the subject
+    ///
+    /// This is a heuristic that looks for words the subject and body have in
+    /// common, so it's wrong sometimes.
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Clear the cache on logout\n\nThe cache kept the old session\n";
+    /// let actual = Lint::BodyUnrelatedToSubject.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::body_unrelated_to_subject::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "Clear the cache on logout\n\nBump the linter version\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::BodyUnrelatedToSubject,
+    ///     &message.into(),
+    ///     Some(vec![("Unrelated to subject".to_string(), 27, 23)]),
+    ///     Some("https://cbea.ms/git-commit/#why-not-how".to_string()),
+    /// ));
+    /// let actual = Lint::BodyUnrelatedToSubject.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    BodyUnrelatedToSubject,
+    /// Check the subject is written in the configured case style
+    ///
+    /// By default this expects sentence case, where only the first word is
+    /// capitalised.
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Add login page for GitHub users\n";
+    /// let actual = Lint::SubjectCaseStyle.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::subject_case_style::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "Add Login Page\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::SubjectCaseStyle,
+    ///     &message.into(),
+    ///     Some(vec![("Not sentence case".to_string(), 4, 5)]),
+    ///     Some("https://cbea.ms/git-commit/#capitalize".to_string()),
+    /// ));
+    /// let actual = Lint::SubjectCaseStyle.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    SubjectCaseStyle,
+    /// Check for a body that doesn't end with punctuation
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "An example commit\n\nThe work is done.\n";
+    /// let actual = Lint::BodySentenceNoTerminator.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::body_sentence_no_terminator::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "An example commit\n\nThe work is done\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::BodySentenceNoTerminator,
+    ///     &message.into(),
+    ///     Some(vec![("No terminator".to_string(), 19, 16)]),
+    ///     None,
+    /// ));
+    /// let actual = Lint::BodySentenceNoTerminator.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    BodySentenceNoTerminator,
+    /// Check for email addresses in the body, outside of the trailers
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str =
+    ///     "An example commit\n\nPaired on this.\n\nCo-authored-by: Someone <someone@example.com>\n";
+    /// let actual = Lint::EmailInBody.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::email_in_body::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "An example commit\n\nAsk someone@example.com about it.\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::EmailInBody,
+    ///     &message.into(),
+    ///     Some(vec![("Email address".to_string(), 23, 19)]),
+    ///     None,
+    /// ));
+    /// let actual = Lint::EmailInBody.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    EmailInBody,
+    /// Check for `\u{FFFD}` replacement characters, which are left behind
+    /// when the message was read with the wrong encoding
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Update the caf\u{E9} menu\n";
+    /// let actual = Lint::ReplacementCharacters.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::replacement_characters::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "Update the caf\u{FFFD} menu\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::ReplacementCharacters,
+    ///     &message.into(),
+    ///     Some(vec![("Replacement character".to_string(), 14, 3)]),
+    ///     None,
+    /// ));
+    /// let actual = Lint::ReplacementCharacters.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    ReplacementCharacters,
+    /// Check for spaces or tabs at the start or end of the subject
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Add login\n";
+    /// let actual = Lint::SubjectWhitespace.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::subject_whitespace::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "  Add login \n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::SubjectWhitespace,
+    ///     &message.into(),
+    ///     Some(vec![
+    ///         ("Leading whitespace".to_string(), 0, 2),
+    ///         ("Trailing whitespace".to_string(), 11, 1),
+    ///     ]),
+    ///     None,
+    /// ));
+    /// let actual = Lint::SubjectWhitespace.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    SubjectWhitespace,
+    /// Check the message ends with a newline
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Add login\n";
+    /// let actual = Lint::MissingFinalNewline.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::missing_final_newline::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "Add login";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::MissingFinalNewline,
+    ///     &message.into(),
+    ///     Some(vec![("No final newline".to_string(), 8, 1)]),
+    ///     None,
+    /// ));
+    /// let actual = Lint::MissingFinalNewline.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    MissingFinalNewline,
+    /// Check for an issue reference, like `#123` or `ABC-123`
+    ///
+    /// Only runs with the `regex-lints` feature enabled. Set
+    /// [`LintConfig::missing_issue_reference`] and run the lint with
+    /// [`Lint::lint_with_config`] to change the pattern, or require the
+    /// reference in the subject or the footer.
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "regex-lints")]
+    /// # {
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Add login\n\nRefs: ABC-123\n";
+    /// let actual = Lint::MissingIssueReference.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// # }
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "regex-lints")]
+    /// # {
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::missing_issue_reference::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "Add login\n\nSome body content\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::MissingIssueReference,
+    ///     &message.into(),
+    ///     Some(vec![("No issue reference".to_string(), 11, 17)]),
+    ///     None,
+    /// ));
+    /// let actual = Lint::MissingIssueReference.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// # }
+    /// ```
+    MissingIssueReference,
+    /// Check for reverts of a revert, like `Revert "Revert "Add login""`
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Revert \"Add login\"\n";
+    /// let actual = Lint::DoubleRevert.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::double_revert::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "Revert \"Revert \"Add login\"\"\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::DoubleRevert,
+    ///     &message.into(),
+    ///     Some(vec![("Reverts a revert".to_string(), 8, 6)]),
+    ///     Some("https://git-scm.com/docs/git-revert".to_string()),
+    /// ));
+    /// let actual = Lint::DoubleRevert.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    DoubleRevert,
+    /// Check for bullet lists in the body that aren't indented consistently
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Add login\n\n- Add a form\n  - With a password field\n- Add a route\n";
+    /// let actual = Lint::InconsistentListIndent.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::inconsistent_list_indent::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "Add login\n\n- Add a form\n  - With a password field\n   - Add a route\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::InconsistentListIndent,
+    ///     &message.into(),
+    ///     Some(vec![("Inconsistent indent".to_string(), 50, 3)]),
+    ///     None,
+    /// ));
+    /// let actual = Lint::InconsistentListIndent.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    InconsistentListIndent,
+    /// Check for `git commit -m` options or stray quotes left in the subject
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Fix the login bug\n";
+    /// let actual = Lint::ShellArtifactInSubject.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::shell_artifact::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "Fix bug -m more\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::ShellArtifactInSubject,
+    ///     &message.into(),
+    ///     Some(vec![("Shell option".to_string(), 8, 2)]),
+    ///     None,
+    /// ));
+    /// let actual = Lint::ShellArtifactInSubject.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    ShellArtifactInSubject,
+    /// Check for subjects marking the commit as a work in progress, like
+    /// `WIP` or `fixup!`
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Add login\n";
+    /// let actual = Lint::WorkInProgress.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::work_in_progress::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "fixup! Add login\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::WorkInProgress,
+    ///     &message.into(),
+    ///     Some(vec![("Work in progress".to_string(), 0, 6)]),
+    ///     None,
+    /// ));
+    /// let actual = Lint::WorkInProgress.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    WorkInProgress,
+    /// Check for URLs in the trailers that are too long
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Add login\n\nRefs: https://example.com/issues/1\n";
+    /// let actual = Lint::FooterUrlTooLong.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::footer_url_too_long::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let url = format!("https://example.com/?utm_source={}", "a".repeat(80));
+    /// let message = format!("Add login\n\nRefs: {}\n", url);
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::FooterUrlTooLong,
+    ///     &message.clone().into(),
+    ///     Some(vec![("Longer than 100 characters".to_string(), 17, 112)]),
+    ///     Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+    /// ));
+    /// let actual = Lint::FooterUrlTooLong.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    FooterUrlTooLong,
+    /// Check for more `Co-authored-by` trailers than the configured maximum
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str =
+    ///     "An example commit\n\nSome Body Content\n\nCo-authored-by: A <a@example.com>\n";
+    /// let actual = Lint::TooManyCoAuthors.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::too_many_co_authors::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "An example commit\n\nSome Body Content\n\nCo-authored-by: A <a@example.com>\nCo-authored-by: B <b@example.com>\nCo-authored-by: C <c@example.com>\nCo-authored-by: D <d@example.com>\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::TooManyCoAuthors,
+    ///     &message.into(),
+    ///     Some(vec![("More than 3 co-authors".to_string(), 140, 33)]),
+    ///     Some("https://docs.github.com/en/pull-requests/committing-changes-to-your-project/creating-and-editing-commits/creating-a-commit-with-multiple-authors".to_string()),
+    /// ));
+    /// let actual = Lint::TooManyCoAuthors.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    TooManyCoAuthors,
+    /// Check for the subject repeated word for word on a line of the body
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Add login\n\nAdd a login form to the home page\n";
+    /// let actual = Lint::SubjectEchoedInBody.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::subject_echoed_in_body::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "Add login\n\nAdd a login form\n\nAdd login\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::SubjectEchoedInBody,
+    ///     &message.into(),
+    ///     Some(vec![("Repeats the subject".to_string(), 29, 9)]),
+    ///     None,
+    /// ));
+    /// let actual = Lint::SubjectEchoedInBody.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    SubjectEchoedInBody,
+    /// Check for bullet lists where only some of the items end with a period
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Add login\n\n- Add a form\n- Add a route\n";
+    /// let actual = Lint::InconsistentListPunctuation.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::inconsistent_list_punctuation::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "Add login\n\n- Add a form.\n- Add a route.\n- Add a test\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::InconsistentListPunctuation,
+    ///     &message.into(),
+    ///     Some(vec![("No period".to_string(), 40, 12)]),
+    ///     None,
+    /// ));
+    /// let actual = Lint::InconsistentListPunctuation.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    InconsistentListPunctuation,
+    /// Check for filler words in the subject, like "just" or "stuff"
+    ///
+    /// There are no filler words by default, so this only fails when some
+    /// are configured in [`LintConfig::subject_filler_words`] and the lint
+    /// is run with [`Lint::lint_with_config`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::subject_filler_words::{lint_with_config, SubjectFillerWordsConfig, ERROR},
+    ///     Lint,
+    /// };
+    ///
+    /// let message = CommitMessage::from("Just fix the stuff\n");
+    /// assert!(Lint::SubjectFillerWords.lint(&message).is_none());
+    ///
+    /// let config = SubjectFillerWordsConfig {
+    ///     words: vec!["just".to_string()].into_iter().collect(),
+    /// };
+    /// let actual = lint_with_config(&message, &config).unwrap();
+    /// assert_eq!(actual.error(), ERROR);
+    /// ```
+    SubjectFillerWords,
+    /// Check for footers placed before the body
+    ///
+    /// # Examples
+    ///
+    /// Passing
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    ///
+    /// let message: &str = "Add login\n\nAdd a login form to the home page.\n\nRefs: #1\n";
+    /// let actual = Lint::FooterBeforeBody.lint(&CommitMessage::from(message));
+    /// assert!(actual.is_none(), "Expected None, found {:?}", actual);
+    /// ```
+    ///
+    /// Erring
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{
+    ///     checks::footer_before_body::{ERROR, HELP_MESSAGE},
+    ///     Code,
+    ///     Lint,
+    ///     Problem,
+    /// };
+    ///
+    /// let message: &str = "Add login\n\nRefs: #1\n\nAdd a login form to the home page.\n";
+    /// let expected = Some(Problem::new(
+    ///     ERROR.into(),
+    ///     HELP_MESSAGE.into(),
+    ///     Code::FooterBeforeBody,
+    ///     &message.into(),
+    ///     Some(vec![("Footer before the body".to_string(), 11, 8)]),
+    ///     Some("https://git-scm.com/docs/git-interpret-trailers".to_string()),
+    /// ));
+    /// let actual = Lint::FooterBeforeBody.lint(&CommitMessage::from(message));
+    /// assert_eq!(
+    ///     actual, expected,
+    ///     "Expected {:?}, found {:?}",
+    ///     expected, actual
+    /// );
+    /// ```
+    FooterBeforeBody,
+}
+
+/// The prefix we put in front of the lint when serialising
+pub const CONFIG_KEY_PREFIX: &str = "mit.lint";
+
+impl TryFrom<&str> for Lint {
+    type Error = Error;
+
+    fn try_from(from: &str) -> Result<Self, Self::Error> {
+        Self::all_lints()
+            .zip(Self::all_lints().map(|lint| format!("{lint}")))
+            .filter_map(|(lint, name): (Self, String)| if name == from { Some(lint) } else { None })
+            .collect::<Vec<Self>>()
+            .first()
+            .copied()
+            .ok_or_else(|| Error::new_lint_not_found(from.into()))
+    }
+}
+
+impl From<Lint> for String {
+    fn from(from: Lint) -> Self {
+        format!("{from}")
+    }
+}
+
+impl From<Lint> for &str {
+    /// Get an lint's unique name
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_lint::Lint;
+    /// let actual: &str = Lint::NotConventionalCommit.into();
+    /// assert_eq!(actual, Lint::NotConventionalCommit.name());
+    /// ```
+    fn from(lint: Lint) -> Self {
+        lint.name()
+    }
+}
+
+impl Lint {
+    /// Get an lint's unique name
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::DuplicatedTrailers => checks::duplicate_trailers::CONFIG,
+            Self::PivotalTrackerIdMissing => "pivotal-tracker-id-missing",
+            Self::JiraIssueKeyMissing => "jira-issue-key-missing",
+            Self::GitHubIdMissing => "github-id-missing",
+            Self::SubjectNotSeparateFromBody => checks::subject_not_separate_from_body::CONFIG,
+            Self::SubjectLongerThan72Characters => {
+                checks::subject_longer_than_72_characters::CONFIG
+            }
+            Self::SubjectNotCapitalized => checks::subject_not_capitalized::CONFIG,
+            Self::SubjectEndsWithPeriod => checks::subject_line_ends_with_period::CONFIG,
+            Self::BodyWiderThan72Characters => checks::body_wider_than_72_characters::CONFIG,
+            Self::NotConventionalCommit => checks::not_conventional_commit::CONFIG,
+            Self::NotEmojiLog => checks::not_emoji_log::CONFIG,
+            Self::DiffPastedInBody => checks::diff_pasted_in_body::CONFIG,
+            Self::TooManyTrailers => checks::too_many_trailers::CONFIG,
+            Self::SubjectEndsWithPunctuation => checks::subject_ends_with_punctuation::CONFIG,
+            #[cfg(feature = "regex-lints")]
+            Self::SubjectOnlyTicket => checks::subject_only_ticket::CONFIG,
+            #[cfg(not(feature = "regex-lints"))]
+            Self::SubjectOnlyTicket => "subject-only-ticket",
+            Self::SubjectGerundStart => checks::subject_gerund_start::CONFIG,
+            Self::SubjectMarkdownHeading => checks::subject_markdown_heading::CONFIG,
+            Self::UneditedTemplate => checks::unedited_template::CONFIG,
+            Self::SubjectAllCaps => checks::subject_all_caps::CONFIG,
+            Self::MalformedTrailer => checks::malformed_trailer::CONFIG,
+            Self::EmptyCommitMessage => checks::empty_commit_message::CONFIG,
+            Self::BodyParagraphTooLong => checks::body_paragraph_too_long::CONFIG,
+            Self::BodyLeadingTrailingBlankLines => checks::body_leading_trailing_blanks::CONFIG,
+            Self::MalformedRevert => checks::malformed_revert::CONFIG,
+            #[cfg(feature = "regex-lints")]
+            Self::SubjectMissingPrefix => checks::subject_missing_prefix::CONFIG,
+            #[cfg(not(feature = "regex-lints"))]
+            Self::SubjectMissingPrefix => "subject-missing-prefix",
+            Self::SubjectLeadingEmoji => checks::subject_leading_emoji::CONFIG,
+            Self::BodySeparatorLine => checks::body_separator_line::CONFIG,
+            Self::BodyContainsTabs => checks::body_contains_tabs::CONFIG,
+            Self::UnknownFooterToken => checks::unknown_footer_token::CONFIG,
+            Self::BodyNotMeaningful => checks::body_not_meaningful::CONFIG,
+            Self::SubjectNotAtomic => checks::subject_not_atomic::CONFIG,
+            Self::BodyUnrelatedToSubject => checks::body_unrelated_to_subject::CONFIG,
+            Self::SubjectCaseStyle => checks::subject_case_style::CONFIG,
+            Self::BodySentenceNoTerminator => checks::body_sentence_no_terminator::CONFIG,
+            Self::EmailInBody => checks::email_in_body::CONFIG,
+            Self::ReplacementCharacters => checks::replacement_characters::CONFIG,
+            Self::SubjectWhitespace => checks::subject_whitespace::CONFIG,
+            Self::MissingFinalNewline => checks::missing_final_newline::CONFIG,
+            #[cfg(feature = "regex-lints")]
+            Self::MissingIssueReference => checks::missing_issue_reference::CONFIG,
+            #[cfg(not(feature = "regex-lints"))]
+            Self::MissingIssueReference => "missing-issue-reference",
+            Self::DoubleRevert => checks::double_revert::CONFIG,
+            Self::InconsistentListIndent => checks::inconsistent_list_indent::CONFIG,
+            Self::ShellArtifactInSubject => checks::shell_artifact::CONFIG,
+            Self::WorkInProgress => checks::work_in_progress::CONFIG,
+            Self::FooterUrlTooLong => checks::footer_url_too_long::CONFIG,
+            Self::TooManyCoAuthors => checks::too_many_co_authors::CONFIG,
+            Self::SubjectEchoedInBody => checks::subject_echoed_in_body::CONFIG,
+            Self::InconsistentListPunctuation => checks::inconsistent_list_punctuation::CONFIG,
+            Self::SubjectFillerWords => checks::subject_filler_words::CONFIG,
+            Self::FooterBeforeBody => checks::footer_before_body::CONFIG,
+        }
+    }
+
+    /// Get a short description of the problem this lint finds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mit_lint::Lint;
+    /// assert_eq!(
+    ///     Lint::DuplicatedTrailers.title(),
+    ///     "Your commit message has duplicated trailers"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::DuplicatedTrailers => checks::duplicate_trailers::ERROR,
+            Self::PivotalTrackerIdMissing => "Your commit message is missing a Pivotal Tracker ID",
+            Self::JiraIssueKeyMissing => "Your commit message is missing a JIRA Issue Key",
+            Self::GitHubIdMissing => "Your commit message is missing a GitHub ID",
+            Self::SubjectNotSeparateFromBody => checks::subject_not_separate_from_body::ERROR,
+            Self::SubjectLongerThan72Characters => checks::subject_longer_than_72_characters::ERROR,
+            Self::SubjectNotCapitalized => checks::subject_not_capitalized::ERROR,
+            Self::SubjectEndsWithPeriod => checks::subject_line_ends_with_period::ERROR,
+            Self::BodyWiderThan72Characters => checks::body_wider_than_72_characters::ERROR,
+            Self::NotConventionalCommit => checks::not_conventional_commit::ERROR,
+            Self::NotEmojiLog => checks::not_emoji_log::ERROR,
+            Self::DiffPastedInBody => checks::diff_pasted_in_body::ERROR,
+            Self::TooManyTrailers => checks::too_many_trailers::ERROR,
+            Self::SubjectEndsWithPunctuation => checks::subject_ends_with_punctuation::ERROR,
+            #[cfg(feature = "regex-lints")]
+            Self::SubjectOnlyTicket => checks::subject_only_ticket::ERROR,
+            #[cfg(not(feature = "regex-lints"))]
+            Self::SubjectOnlyTicket => "Your subject is only a ticket reference",
+            Self::SubjectGerundStart => checks::subject_gerund_start::ERROR,
+            Self::SubjectMarkdownHeading => checks::subject_markdown_heading::ERROR,
+            Self::UneditedTemplate => checks::unedited_template::ERROR,
+            Self::SubjectAllCaps => checks::subject_all_caps::ERROR,
+            Self::MalformedTrailer => checks::malformed_trailer::ERROR,
+            Self::EmptyCommitMessage => checks::empty_commit_message::ERROR,
+            Self::BodyParagraphTooLong => checks::body_paragraph_too_long::ERROR,
+            Self::BodyLeadingTrailingBlankLines => checks::body_leading_trailing_blanks::ERROR,
+            Self::MalformedRevert => checks::malformed_revert::ERROR,
+            #[cfg(feature = "regex-lints")]
+            Self::SubjectMissingPrefix => checks::subject_missing_prefix::ERROR,
+            #[cfg(not(feature = "regex-lints"))]
+            Self::SubjectMissingPrefix => "Your subject is missing the required prefix",
+            Self::SubjectLeadingEmoji => checks::subject_leading_emoji::ERROR,
+            Self::BodySeparatorLine => checks::body_separator_line::ERROR,
+            Self::BodyContainsTabs => checks::body_contains_tabs::ERROR,
+            Self::UnknownFooterToken => checks::unknown_footer_token::ERROR,
+            Self::BodyNotMeaningful => checks::body_not_meaningful::ERROR,
+            Self::SubjectNotAtomic => checks::subject_not_atomic::ERROR,
+            Self::BodyUnrelatedToSubject => checks::body_unrelated_to_subject::ERROR,
+            Self::SubjectCaseStyle => checks::subject_case_style::ERROR,
+            Self::BodySentenceNoTerminator => checks::body_sentence_no_terminator::ERROR,
+            Self::EmailInBody => checks::email_in_body::ERROR,
+            Self::ReplacementCharacters => checks::replacement_characters::ERROR,
+            Self::SubjectWhitespace => checks::subject_whitespace::ERROR,
+            Self::MissingFinalNewline => checks::missing_final_newline::ERROR,
+            #[cfg(feature = "regex-lints")]
+            Self::MissingIssueReference => checks::missing_issue_reference::ERROR,
+            #[cfg(not(feature = "regex-lints"))]
+            Self::MissingIssueReference => "Your commit message is missing an issue reference",
+            Self::DoubleRevert => checks::double_revert::ERROR,
+            Self::InconsistentListIndent => checks::inconsistent_list_indent::ERROR,
+            Self::ShellArtifactInSubject => checks::shell_artifact::ERROR,
+            Self::WorkInProgress => checks::work_in_progress::ERROR,
+            Self::FooterUrlTooLong => checks::footer_url_too_long::ERROR,
+            Self::TooManyCoAuthors => checks::too_many_co_authors::ERROR,
+            Self::SubjectEchoedInBody => checks::subject_echoed_in_body::ERROR,
+            Self::InconsistentListPunctuation => checks::inconsistent_list_punctuation::ERROR,
+            Self::SubjectFillerWords => checks::subject_filler_words::ERROR,
+            Self::FooterBeforeBody => checks::footer_before_body::ERROR,
+        }
+    }
+
+    /// Get the part of the commit message this lint checks
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Category, Lint};
+    /// assert_eq!(Lint::BodyContainsTabs.category(), Category::Body);
+    /// ```
+    #[must_use]
+    pub const fn category(self) -> Category {
+        match self {
+            Self::DuplicatedTrailers
+            | Self::TooManyTrailers
+            | Self::MalformedTrailer
+            | Self::UnknownFooterToken
+            | Self::FooterUrlTooLong
+            | Self::TooManyCoAuthors
+            | Self::FooterBeforeBody => Category::Trailers,
+            Self::PivotalTrackerIdMissing
+            | Self::JiraIssueKeyMissing
+            | Self::GitHubIdMissing
+            | Self::UneditedTemplate
+            | Self::EmptyCommitMessage
+            | Self::MalformedRevert
+            | Self::ReplacementCharacters
+            | Self::MissingFinalNewline
+            | Self::MissingIssueReference => Category::Message,
+            Self::SubjectNotSeparateFromBody
+            | Self::SubjectLongerThan72Characters
+            | Self::SubjectNotCapitalized
+            | Self::SubjectEndsWithPeriod
+            | Self::NotConventionalCommit
+            | Self::NotEmojiLog
+            | Self::SubjectEndsWithPunctuation
+            | Self::SubjectOnlyTicket
+            | Self::SubjectGerundStart
+            | Self::SubjectMarkdownHeading
+            | Self::SubjectAllCaps
+            | Self::SubjectMissingPrefix
+            | Self::SubjectLeadingEmoji
+            | Self::SubjectNotAtomic
+            | Self::SubjectCaseStyle
+            | Self::SubjectWhitespace
+            | Self::DoubleRevert
+            | Self::ShellArtifactInSubject
+            | Self::WorkInProgress
+            | Self::SubjectFillerWords => Category::Subject,
+            Self::BodyWiderThan72Characters
+            | Self::DiffPastedInBody
+            | Self::BodyParagraphTooLong
+            | Self::BodyLeadingTrailingBlankLines
+            | Self::BodySeparatorLine
+            | Self::BodyContainsTabs
+            | Self::BodyNotMeaningful
+            | Self::BodyUnrelatedToSubject
+            | Self::BodySentenceNoTerminator
+            | Self::EmailInBody
+            | Self::InconsistentListIndent
+            | Self::SubjectEchoedInBody
+            | Self::InconsistentListPunctuation => Category::Body,
+        }
+    }
+
+    /// Get how serious a problem found by this lint is
+    ///
+    /// Problems that break tooling, or that git and most teams agree on, are
+    /// errors. Matters of style are warnings, and heuristics that are wrong
+    /// sometimes are advice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use miette::Severity;
+    /// use mit_lint::Lint;
+    /// assert_eq!(Lint::DuplicatedTrailers.severity(), Severity::Error);
+    /// assert_eq!(Lint::SubjectNotAtomic.severity(), Severity::Advice);
+    /// ```
+    #[must_use]
+    pub const fn severity(self) -> Severity {
+        match self {
+            Self::DuplicatedTrailers
+            | Self::PivotalTrackerIdMissing
+            | Self::JiraIssueKeyMissing
+            | Self::GitHubIdMissing
+            | Self::SubjectNotSeparateFromBody
+            | Self::SubjectLongerThan72Characters
+            | Self::BodyWiderThan72Characters
+            | Self::NotConventionalCommit
+            | Self::NotEmojiLog
+            | Self::DiffPastedInBody
+            | Self::UneditedTemplate
+            | Self::MalformedTrailer
+            | Self::EmptyCommitMessage
+            | Self::MalformedRevert
+            | Self::SubjectMissingPrefix
+            | Self::ReplacementCharacters
+            | Self::MissingIssueReference => Severity::Error,
+            Self::SubjectNotCapitalized
+            | Self::SubjectEndsWithPeriod
+            | Self::TooManyTrailers
+            | Self::SubjectEndsWithPunctuation
+            | Self::SubjectOnlyTicket
+            | Self::SubjectGerundStart
+            | Self::SubjectMarkdownHeading
+            | Self::SubjectAllCaps
+            | Self::BodyParagraphTooLong
+            | Self::BodyLeadingTrailingBlankLines
+            | Self::SubjectLeadingEmoji
+            | Self::BodySeparatorLine
+            | Self::BodyContainsTabs
+            | Self::UnknownFooterToken
+            | Self::SubjectCaseStyle
+            | Self::BodySentenceNoTerminator
+            | Self::EmailInBody
+            | Self::SubjectWhitespace
+            | Self::MissingFinalNewline
+            | Self::DoubleRevert
+            | Self::InconsistentListIndent
+            | Self::ShellArtifactInSubject
+            | Self::WorkInProgress
+            | Self::FooterUrlTooLong
+            | Self::TooManyCoAuthors
+            | Self::SubjectEchoedInBody
+            | Self::InconsistentListPunctuation
+            | Self::SubjectFillerWords
+            | Self::FooterBeforeBody => Severity::Warning,
+            Self::BodyNotMeaningful | Self::SubjectNotAtomic | Self::BodyUnrelatedToSubject => {
+                Severity::Advice
+            }
+        }
+    }
+
+    /// Get the code of the problems this lint finds
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{Code, Lint};
+    ///
+    /// assert_eq!(
+    ///     Lint::SubjectNotCapitalized.code(),
+    ///     Code::SubjectNotCapitalized
+    /// );
+    /// assert_eq!(
+    ///     Lint::SubjectNotCapitalized
+    ///         .lint(&CommitMessage::from("an example commit"))
+    ///         .map(|problem| *problem.code()),
+    ///     Some(Lint::SubjectNotCapitalized.code())
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn code(self) -> Code {
+        match self {
+            Self::DuplicatedTrailers => Code::DuplicatedTrailers,
+            Self::PivotalTrackerIdMissing => Code::PivotalTrackerIdMissing,
+            Self::JiraIssueKeyMissing => Code::JiraIssueKeyMissing,
+            Self::SubjectNotSeparateFromBody => Code::SubjectNotSeparateFromBody,
+            Self::GitHubIdMissing => Code::GitHubIdMissing,
+            Self::SubjectLongerThan72Characters => Code::SubjectLongerThan72Characters,
+            Self::SubjectNotCapitalized => Code::SubjectNotCapitalized,
+            Self::SubjectEndsWithPeriod => Code::SubjectEndsWithPeriod,
+            Self::BodyWiderThan72Characters => Code::BodyWiderThan72Characters,
+            Self::NotConventionalCommit => Code::NotConventionalCommit,
+            Self::NotEmojiLog => Code::NotEmojiLog,
+            Self::DiffPastedInBody => Code::DiffPastedInBody,
+            Self::TooManyTrailers => Code::TooManyTrailers,
+            Self::SubjectEndsWithPunctuation => Code::SubjectEndsWithPunctuation,
+            Self::SubjectOnlyTicket => Code::SubjectOnlyTicket,
+            Self::SubjectGerundStart => Code::SubjectGerundStart,
+            Self::SubjectMarkdownHeading => Code::SubjectMarkdownHeading,
+            Self::UneditedTemplate => Code::UneditedTemplate,
+            Self::SubjectAllCaps => Code::SubjectAllCaps,
+            Self::MalformedTrailer => Code::MalformedTrailer,
+            Self::EmptyCommitMessage => Code::EmptyCommitMessage,
+            Self::BodyParagraphTooLong => Code::BodyParagraphTooLong,
+            Self::BodyLeadingTrailingBlankLines => Code::BodyLeadingTrailingBlankLines,
+            Self::MalformedRevert => Code::MalformedRevert,
+            Self::SubjectMissingPrefix => Code::SubjectMissingPrefix,
+            Self::SubjectLeadingEmoji => Code::SubjectLeadingEmoji,
+            Self::BodySeparatorLine => Code::BodySeparatorLine,
+            Self::BodyContainsTabs => Code::BodyContainsTabs,
+            Self::UnknownFooterToken => Code::UnknownFooterToken,
+            Self::BodyNotMeaningful => Code::BodyNotMeaningful,
+            Self::SubjectNotAtomic => Code::SubjectNotAtomic,
+            Self::BodyUnrelatedToSubject => Code::BodyUnrelatedToSubject,
+            Self::SubjectCaseStyle => Code::SubjectCaseStyle,
+            Self::BodySentenceNoTerminator => Code::BodySentenceNoTerminator,
+            Self::EmailInBody => Code::EmailInBody,
+            Self::ReplacementCharacters => Code::ReplacementCharacters,
+            Self::SubjectWhitespace => Code::SubjectWhitespace,
+            Self::MissingFinalNewline => Code::MissingFinalNewline,
+            Self::MissingIssueReference => Code::MissingIssueReference,
+            Self::DoubleRevert => Code::DoubleRevert,
+            Self::InconsistentListIndent => Code::InconsistentListIndent,
+            Self::ShellArtifactInSubject => Code::ShellArtifactInSubject,
+            Self::WorkInProgress => Code::WorkInProgress,
+            Self::FooterUrlTooLong => Code::FooterUrlTooLong,
+            Self::TooManyCoAuthors => Code::TooManyCoAuthors,
+            Self::SubjectEchoedInBody => Code::SubjectEchoedInBody,
+            Self::InconsistentListPunctuation => Code::InconsistentListPunctuation,
+            Self::SubjectFillerWords => Code::SubjectFillerWords,
+            Self::FooterBeforeBody => Code::FooterBeforeBody,
+        }
+    }
+}
+
+/// All the available lints
+///
+/// The ID lints are only available with the `regex-lints` feature
+static ALL_LINTS: &[Lint] = &[
+    Lint::DuplicatedTrailers,
+    #[cfg(feature = "regex-lints")]
+    Lint::PivotalTrackerIdMissing,
+    #[cfg(feature = "regex-lints")]
+    Lint::JiraIssueKeyMissing,
+    Lint::SubjectNotSeparateFromBody,
+    #[cfg(feature = "regex-lints")]
+    Lint::GitHubIdMissing,
+    Lint::SubjectLongerThan72Characters,
+    Lint::SubjectNotCapitalized,
+    Lint::SubjectEndsWithPeriod,
+    Lint::BodyWiderThan72Characters,
+    Lint::NotConventionalCommit,
+    Lint::NotEmojiLog,
+    Lint::DiffPastedInBody,
+    Lint::TooManyTrailers,
+    Lint::SubjectEndsWithPunctuation,
+    #[cfg(feature = "regex-lints")]
+    Lint::SubjectOnlyTicket,
+    Lint::SubjectGerundStart,
+    Lint::SubjectMarkdownHeading,
+    Lint::UneditedTemplate,
+    Lint::SubjectAllCaps,
+    Lint::MalformedTrailer,
+    Lint::EmptyCommitMessage,
+    Lint::BodyParagraphTooLong,
+    Lint::BodyLeadingTrailingBlankLines,
+    Lint::MalformedRevert,
+    #[cfg(feature = "regex-lints")]
+    Lint::SubjectMissingPrefix,
+    Lint::SubjectLeadingEmoji,
+    Lint::BodySeparatorLine,
+    Lint::BodyContainsTabs,
+    Lint::UnknownFooterToken,
+    Lint::BodyNotMeaningful,
+    Lint::SubjectNotAtomic,
+    Lint::BodyUnrelatedToSubject,
+    Lint::SubjectCaseStyle,
+    Lint::BodySentenceNoTerminator,
+    Lint::EmailInBody,
+    Lint::ReplacementCharacters,
+    Lint::SubjectWhitespace,
+    Lint::MissingFinalNewline,
+    #[cfg(feature = "regex-lints")]
+    Lint::MissingIssueReference,
+    Lint::DoubleRevert,
+    Lint::InconsistentListIndent,
+    Lint::ShellArtifactInSubject,
+    Lint::WorkInProgress,
+    Lint::FooterUrlTooLong,
+    Lint::TooManyCoAuthors,
+    Lint::SubjectEchoedInBody,
+    Lint::InconsistentListPunctuation,
+    Lint::SubjectFillerWords,
+    Lint::FooterBeforeBody,
+];
+
+lazy_static! {
+    /// The ones that are enabled by default
+    static ref DEFAULT_ENABLED_LINTS: [Lint; 4] = [
+        Lint::DuplicatedTrailers,
+        Lint::SubjectNotSeparateFromBody,
+        Lint::SubjectLongerThan72Characters,
+        Lint::BodyWiderThan72Characters,
+    ];
+}
+
+impl Lint {
+    /// Iterator over all the lints
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::Lint;
+    /// assert!(Lint::all_lints().next().is_some())
+    /// ```
+    pub fn all_lints() -> impl Iterator<Item = Self> {
+        ALL_LINTS.iter().copied()
+    }
+
+    /// Iterator over all the lints
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::Lint;
+    /// assert!(Lint::iterator().next().is_some())
+    /// ```
+    #[deprecated(since = "0.1.5", note = "iterator was an unusual name. Use all_lints")]
+    pub fn iterator() -> impl Iterator<Item = Self> {
+        Self::all_lints()
+    }
+
+    /// Check if a lint is enabled by default
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::Lint;
+    /// assert!(Lint::SubjectNotSeparateFromBody.enabled_by_default());
+    /// assert!(!Lint::NotConventionalCommit.enabled_by_default());
+    /// ```
+    #[must_use]
+    pub fn enabled_by_default(self) -> bool {
+        DEFAULT_ENABLED_LINTS.contains(&self)
+    }
+
+    /// Iterator over the lints that check a part of the commit message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Category, Lint};
+    /// assert!(Lint::by_category(Category::Trailers).any(|lint| lint == Lint::DuplicatedTrailers));
+    /// assert!(Lint::by_category(Category::Trailers).all(|lint| lint.category() == Category::Trailers));
+    /// ```
+    pub fn by_category(category: Category) -> impl Iterator<Item = Self> {
+        Self::all_lints().filter(move |lint| lint.category() == category)
+    }
+
+    /// Iterator over the lints with a severity
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use miette::Severity;
+    /// use mit_lint::Lint;
+    /// assert!(Lint::by_severity(Severity::Advice).any(|lint| lint == Lint::SubjectNotAtomic));
+    /// assert!(!Lint::by_severity(Severity::Advice).any(|lint| lint == Lint::DuplicatedTrailers));
+    /// ```
+    pub fn by_severity(severity: Severity) -> impl Iterator<Item = Self> {
+        Self::all_lints().filter(move |lint| lint.severity() == severity)
+    }
+
+    /// Check if this lint is in a set of lints
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Lint, Lints};
+    ///
+    /// let lints = Lints::default_enabled();
+    /// assert!(Lint::DuplicatedTrailers.is_enabled_in(&lints));
+    /// assert!(!Lint::NotConventionalCommit.is_enabled_in(&lints));
+    /// ```
+    #[must_use]
+    pub fn is_enabled_in(self, lints: &Lints) -> bool {
+        lints.contains(self)
+    }
+
+    /// Get a key suitable for a configuration document
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::Lint;
+    /// assert_eq!(
+    ///     Lint::SubjectNotSeparateFromBody.config_key(),
+    ///     "mit.lint.subject-not-separated-from-body"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn config_key(self) -> String {
+        format!("{CONFIG_KEY_PREFIX}.{self}")
+    }
+
+    /// Run this lint on a commit message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    /// let actual =
+    ///     Lint::NotConventionalCommit.lint(&CommitMessage::from("An example commit message"));
+    /// assert!(actual.is_some());
+    /// ```
+    #[must_use]
+    pub fn lint(self, commit_message: &CommitMessage<'_>) -> Option<Problem> {
+        self.lint_commit(commit_message, commit_message.get_comment_char())
+    }
+
+    /// Run this lint on a commit message, treating lines starting with
+    /// `comment_char` as comments
+    ///
+    /// The comment character is normally guessed from the message, which can
+    /// be wrong when git's `core.commentChar` is set. Use this when you know
+    /// which character git is using.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "regex-lints")]
+    /// # {
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    /// let message = CommitMessage::from("An example commit\n\n; A comment\n#642\n");
+    ///
+    /// assert!(Lint::GitHubIdMissing.lint(&message).is_some());
+    /// assert!(Lint::GitHubIdMissing
+    ///     .lint_with_comment_char(&message, ';')
+    ///     .is_none());
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn lint_with_comment_char(
+        self,
+        commit_message: &CommitMessage<'_>,
+        comment_char: char,
+    ) -> Option<Problem> {
+        self.lint_commit(commit_message, Some(comment_char))
+    }
+
+    /// Run this lint on a commit message with the given [`LintConfig`]
+    ///
+    /// Lints in [`LintConfig::skip_on_merge`] return `None` for merge
+    /// commits, and the problem has at most
+    /// [`LintConfig::max_labels_per_problem`] labels, plus one saying how
+    /// many were left out.
+    ///
+    /// The lints with their own settings in [`LintConfig`], like
+    /// [`LintConfig::duplicated_trailers`] and
+    /// [`LintConfig::conventional_commit`], run with them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{Lint, LintConfig};
+    /// let message = CommitMessage::from(format!("An example commit\n\n# {}\n", "x".repeat(80)));
+    ///
+    /// assert!(Lint::BodyWiderThan72Characters
+    ///     .lint_with_config(&message, &LintConfig::default())
+    ///     .is_none());
+    /// assert!(Lint::BodyWiderThan72Characters
+    ///     .lint_with_config(
+    ///         &message,
+    ///         &LintConfig {
+    ///             include_comments: true,
+    ///             ..LintConfig::default()
+    ///         }
+    ///     )
+    ///     .is_some());
+    /// ```
+    #[must_use]
+    pub fn lint_with_config(
+        self,
+        commit_message: &CommitMessage<'_>,
+        config: &LintConfig,
+    ) -> Option<Problem> {
+        if config.skips(self, commit_message) {
+            return None;
+        }
+
+        let problem = match self {
+            Self::DuplicatedTrailers => checks::duplicate_trailers::lint_with_config(
+                commit_message,
+                &config.duplicated_trailers,
+            ),
+            Self::NotConventionalCommit => checks::not_conventional_commit::lint_with_config(
+                commit_message,
+                &config.conventional_commit,
+            ),
+            Self::TooManyTrailers => checks::too_many_trailers::lint_with_config(
+                commit_message,
+                &config.too_many_trailers,
+            ),
+            Self::SubjectEndsWithPunctuation => {
+                checks::subject_ends_with_punctuation::lint_with_config(
+                    commit_message,
+                    &config.subject_ends_with_punctuation,
+                )
+            }
+            #[cfg(feature = "regex-lints")]
+            Self::SubjectOnlyTicket => checks::subject_only_ticket::lint_with_config(
+                commit_message,
+                &config.subject_only_ticket,
+            ),
+            Self::UneditedTemplate => checks::unedited_template::lint_with_config(
+                commit_message,
+                &config.unedited_template,
+            ),
+            Self::NotEmojiLog => {
+                checks::not_emoji_log::lint_with_config(commit_message, &config.not_emoji_log)
+            }
+            #[cfg(feature = "regex-lints")]
+            Self::GitHubIdMissing => checks::missing_github_id::lint_with_options(
+                commit_message,
+                config.comment_char(commit_message),
+                &config.github_id,
+            ),
+            #[cfg(feature = "regex-lints")]
+            Self::SubjectMissingPrefix => checks::subject_missing_prefix::lint_with_config(
+                commit_message,
+                &config.subject_missing_prefix,
+            ),
+            Self::UnknownFooterToken => checks::unknown_footer_token::lint_with_config(
+                commit_message,
+                &config.unknown_footer_token,
+            ),
+            Self::SubjectLongerThan72Characters => {
+                checks::subject_longer_than_72_characters::lint_with_config(
+                    commit_message,
+                    &config.subject_length,
+                )
+            }
+            #[cfg(feature = "regex-lints")]
+            Self::PivotalTrackerIdMissing => checks::missing_pivotal_tracker_id::lint_with_options(
+                commit_message,
+                config.comment_char(commit_message),
+                &config.pivotal_tracker_id,
+            ),
+            Self::BodyWiderThan72Characters => {
+                checks::body_wider_than_72_characters::lint_with_options(
+                    commit_message,
+                    config.comment_char(commit_message),
+                    &config.body_width,
+                )
+            }
+            #[cfg(feature = "regex-lints")]
+            Self::MissingIssueReference => checks::missing_issue_reference::lint_with_options(
+                commit_message,
+                config.comment_char(commit_message),
+                &config.missing_issue_reference,
+            ),
+            Self::SubjectFillerWords => checks::subject_filler_words::lint_with_options(
+                commit_message,
+                config.comment_char(commit_message),
+                &config.subject_filler_words,
+            ),
+            _ => self.lint_commit(commit_message, config.comment_char(commit_message)),
+        };
+
+        problem.map(|problem| problem.with_max_labels(config.max_labels_per_problem))
+    }
+
+    fn lint_commit(
+        self,
+        commit_message: &CommitMessage<'_>,
+        comment_char: Option<char>,
+    ) -> Option<Problem> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("lint", lint = self.name()).entered();
+
+        let problem = match self {
+            Self::DuplicatedTrailers => checks::duplicate_trailers::lint(commit_message),
+            #[cfg(feature = "regex-lints")]
+            Self::PivotalTrackerIdMissing => {
+                checks::missing_pivotal_tracker_id::lint_with_comment_char(
+                    commit_message,
+                    comment_char,
+                )
+            }
+            #[cfg(feature = "regex-lints")]
+            Self::JiraIssueKeyMissing => {
+                checks::missing_jira_issue_key::lint_with_comment_char(commit_message, comment_char)
+            }
+            #[cfg(feature = "regex-lints")]
+            Self::GitHubIdMissing => {
+                checks::missing_github_id::lint_with_comment_char(commit_message, comment_char)
+            }
+            #[cfg(not(feature = "regex-lints"))]
+            Self::PivotalTrackerIdMissing | Self::JiraIssueKeyMissing | Self::GitHubIdMissing => {
+                None
+            }
+            Self::SubjectNotSeparateFromBody => {
+                checks::subject_not_separate_from_body::lint(commit_message)
+            }
+            Self::SubjectLongerThan72Characters => {
+                checks::subject_longer_than_72_characters::lint(commit_message)
+            }
+            Self::SubjectNotCapitalized => checks::subject_not_capitalized::lint(commit_message),
+            Self::SubjectEndsWithPeriod => {
+                checks::subject_line_ends_with_period::lint(commit_message)
+            }
+            Self::BodyWiderThan72Characters => {
+                checks::body_wider_than_72_characters::lint_with_comment_char(
+                    commit_message,
+                    comment_char,
+                )
+            }
+            Self::NotConventionalCommit => checks::not_conventional_commit::lint(commit_message),
+            Self::NotEmojiLog => checks::not_emoji_log::lint(commit_message),
+            Self::DiffPastedInBody => checks::diff_pasted_in_body::lint(commit_message),
+            Self::TooManyTrailers => checks::too_many_trailers::lint(commit_message),
+            Self::SubjectEndsWithPunctuation => {
+                checks::subject_ends_with_punctuation::lint(commit_message)
+            }
+            #[cfg(feature = "regex-lints")]
+            Self::SubjectOnlyTicket => checks::subject_only_ticket::lint(commit_message),
+            #[cfg(not(feature = "regex-lints"))]
+            Self::SubjectOnlyTicket => None,
+            Self::SubjectGerundStart => checks::subject_gerund_start::lint(commit_message),
+            Self::SubjectMarkdownHeading => {
+                checks::subject_markdown_heading::lint_with_comment_char(
+                    commit_message,
+                    comment_char,
+                )
+            }
+            Self::UneditedTemplate => checks::unedited_template::lint(commit_message),
+            Self::SubjectAllCaps => checks::subject_all_caps::lint(commit_message),
+            Self::MalformedTrailer => checks::malformed_trailer::lint(commit_message),
+            Self::EmptyCommitMessage => {
+                checks::empty_commit_message::lint_with_comment_char(commit_message, comment_char)
+            }
+            Self::BodyParagraphTooLong => checks::body_paragraph_too_long::lint_with_comment_char(
+                commit_message,
+                comment_char,
+            ),
+            Self::BodyLeadingTrailingBlankLines => {
+                checks::body_leading_trailing_blanks::lint(commit_message)
+            }
+            Self::MalformedRevert => checks::malformed_revert::lint(commit_message),
+            #[cfg(feature = "regex-lints")]
+            Self::SubjectMissingPrefix => checks::subject_missing_prefix::lint(commit_message),
+            #[cfg(not(feature = "regex-lints"))]
+            Self::SubjectMissingPrefix => None,
+            Self::SubjectLeadingEmoji => checks::subject_leading_emoji::lint(commit_message),
+            Self::BodySeparatorLine => {
+                checks::body_separator_line::lint_with_comment_char(commit_message, comment_char)
+            }
+            Self::BodyContainsTabs => {
+                checks::body_contains_tabs::lint_with_comment_char(commit_message, comment_char)
+            }
+            Self::UnknownFooterToken => checks::unknown_footer_token::lint(commit_message),
+            Self::BodyNotMeaningful => {
+                checks::body_not_meaningful::lint_with_comment_char(commit_message, comment_char)
+            }
+            Self::SubjectNotAtomic => checks::subject_not_atomic::lint(commit_message),
+            Self::BodyUnrelatedToSubject => {
+                checks::body_unrelated_to_subject::lint_with_comment_char(
+                    commit_message,
+                    comment_char,
+                )
+            }
+            Self::SubjectCaseStyle => checks::subject_case_style::lint(commit_message),
+            Self::BodySentenceNoTerminator => {
+                checks::body_sentence_no_terminator::lint_with_comment_char(
+                    commit_message,
+                    comment_char,
+                )
+            }
+            Self::EmailInBody => {
+                checks::email_in_body::lint_with_comment_char(commit_message, comment_char)
+            }
+            Self::ReplacementCharacters => {
+                checks::replacement_characters::lint_with_comment_char(commit_message, comment_char)
+            }
+            Self::SubjectWhitespace => {
+                checks::subject_whitespace::lint_with_comment_char(commit_message, comment_char)
+            }
+            Self::MissingFinalNewline => {
+                checks::missing_final_newline::lint_with_comment_char(commit_message, comment_char)
+            }
+            #[cfg(feature = "regex-lints")]
+            Self::MissingIssueReference => checks::missing_issue_reference::lint_with_comment_char(
+                commit_message,
+                comment_char,
+            ),
+            #[cfg(not(feature = "regex-lints"))]
+            Self::MissingIssueReference => None,
+            Self::DoubleRevert => checks::double_revert::lint(commit_message),
+            Self::InconsistentListIndent => {
+                checks::inconsistent_list_indent::lint_with_comment_char(
+                    commit_message,
+                    comment_char,
+                )
+            }
+            Self::ShellArtifactInSubject => {
+                checks::shell_artifact::lint_with_comment_char(commit_message, comment_char)
+            }
+            Self::WorkInProgress => {
+                checks::work_in_progress::lint_with_comment_char(commit_message, comment_char)
+            }
+            Self::FooterUrlTooLong => checks::footer_url_too_long::lint(commit_message),
+            Self::TooManyCoAuthors => checks::too_many_co_authors::lint(commit_message),
+            Self::SubjectEchoedInBody => {
+                checks::subject_echoed_in_body::lint_with_comment_char(commit_message, comment_char)
+            }
+            Self::InconsistentListPunctuation => {
+                checks::inconsistent_list_punctuation::lint_with_comment_char(
+                    commit_message,
+                    comment_char,
+                )
+            }
+            Self::SubjectFillerWords => {
+                checks::subject_filler_words::lint_with_comment_char(commit_message, comment_char)
+            }
+            Self::FooterBeforeBody => checks::footer_before_body::lint(commit_message),
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(problem = problem.is_some(), "lint finished");
+
+        problem
+    }
+
+    /// Fix the problem this lint finds, if it can be fixed automatically
+    ///
+    /// Returns `None` if this lint has no automatic fix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::Lint;
+    /// let message = CommitMessage::from(format!("Subject\n\n{}\n", "word ".repeat(20)));
+    ///
+    /// let fixed = Lint::BodyWiderThan72Characters.fix(&message).unwrap();
+    /// assert!(Lint::BodyWiderThan72Characters.lint(&fixed).is_none());
+    /// assert!(Lint::SubjectNotCapitalized.fix(&message).is_none());
+    /// ```
+    #[must_use]
+    pub fn fix(self, commit_message: &CommitMessage<'_>) -> Option<CommitMessage<'static>> {
+        match self {
+            Self::BodyWiderThan72Characters => Some(checks::body_wider_than_72_characters::fix(
+                commit_message,
+                &checks::body_wider_than_72_characters::BodyWidthConfig::default(),
+            )),
+            Self::SubjectWhitespace => Some(checks::subject_whitespace::fix(commit_message)),
+            Self::MissingFinalNewline => Some(checks::missing_final_newline::fix(commit_message)),
+            Self::DuplicatedTrailers
+            | Self::PivotalTrackerIdMissing
+            | Self::JiraIssueKeyMissing
+            | Self::GitHubIdMissing
+            | Self::SubjectNotSeparateFromBody
+            | Self::SubjectLongerThan72Characters
+            | Self::SubjectNotCapitalized
+            | Self::SubjectEndsWithPeriod
+            | Self::NotConventionalCommit
+            | Self::NotEmojiLog
+            | Self::DiffPastedInBody
+            | Self::TooManyTrailers
+            | Self::SubjectEndsWithPunctuation
+            | Self::SubjectOnlyTicket
+            | Self::SubjectGerundStart
+            | Self::SubjectMarkdownHeading
+            | Self::UneditedTemplate
+            | Self::SubjectAllCaps
+            | Self::MalformedTrailer
+            | Self::EmptyCommitMessage
+            | Self::BodyParagraphTooLong
+            | Self::BodyLeadingTrailingBlankLines
+            | Self::MalformedRevert
+            | Self::SubjectMissingPrefix
+            | Self::SubjectLeadingEmoji
+            | Self::BodySeparatorLine
+            | Self::BodyContainsTabs
+            | Self::UnknownFooterToken
+            | Self::BodyNotMeaningful
+            | Self::SubjectNotAtomic
+            | Self::BodyUnrelatedToSubject
+            | Self::SubjectCaseStyle
+            | Self::BodySentenceNoTerminator
+            | Self::EmailInBody
+            | Self::ReplacementCharacters
+            | Self::MissingIssueReference
+            | Self::DoubleRevert
+            | Self::InconsistentListIndent
+            | Self::ShellArtifactInSubject
+            | Self::WorkInProgress
+            | Self::FooterUrlTooLong
+            | Self::TooManyCoAuthors
+            | Self::SubjectEchoedInBody
+            | Self::InconsistentListPunctuation
+            | Self::SubjectFillerWords
+            | Self::FooterBeforeBody => None,
+        }
+    }
+
+    /// Get the regular expression used to detect this lint, if it uses one
+    ///
+    /// This is the same pattern the lint uses internally, so downstream tools
+    /// can detect the same IDs without reimplementing them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "regex-lints")]
+    /// # {
+    /// use mit_lint::{checks, Lint};
+    /// assert_eq!(
+    ///     Lint::GitHubIdMissing.detection_pattern(),
+    ///     Some(checks::missing_github_id::PATTERN)
+    /// );
+    /// assert_eq!(Lint::SubjectNotCapitalized.detection_pattern(), None);
+    /// # }
+    /// ```
+    #[must_use]
+    pub const fn detection_pattern(self) -> Option<&'static str> {
+        match self {
+            #[cfg(feature = "regex-lints")]
+            Self::PivotalTrackerIdMissing => Some(checks::missing_pivotal_tracker_id::PATTERN),
+            #[cfg(feature = "regex-lints")]
+            Self::JiraIssueKeyMissing => Some(checks::missing_jira_issue_key::PATTERN),
+            #[cfg(feature = "regex-lints")]
+            Self::GitHubIdMissing => Some(checks::missing_github_id::PATTERN),
+            #[cfg(not(feature = "regex-lints"))]
+            Self::PivotalTrackerIdMissing | Self::JiraIssueKeyMissing | Self::GitHubIdMissing => {
+                None
+            }
+            Self::DuplicatedTrailers
+            | Self::SubjectNotSeparateFromBody
+            | Self::SubjectLongerThan72Characters
+            | Self::SubjectNotCapitalized
+            | Self::SubjectEndsWithPeriod
+            | Self::BodyWiderThan72Characters
+            | Self::NotConventionalCommit
+            | Self::NotEmojiLog
+            | Self::DiffPastedInBody
+            | Self::TooManyTrailers
+            | Self::SubjectEndsWithPunctuation
+            | Self::SubjectOnlyTicket
+            | Self::SubjectGerundStart
+            | Self::SubjectMarkdownHeading
+            | Self::UneditedTemplate
+            | Self::SubjectAllCaps
+            | Self::MalformedTrailer
+            | Self::EmptyCommitMessage
+            | Self::BodyParagraphTooLong
+            | Self::BodyLeadingTrailingBlankLines
+            | Self::MalformedRevert
+            | Self::SubjectMissingPrefix
+            | Self::SubjectLeadingEmoji
+            | Self::BodySeparatorLine
+            | Self::BodyContainsTabs
+            | Self::UnknownFooterToken
+            | Self::BodyNotMeaningful
+            | Self::SubjectNotAtomic
+            | Self::BodyUnrelatedToSubject
+            | Self::SubjectCaseStyle
+            | Self::BodySentenceNoTerminator
+            | Self::EmailInBody
+            | Self::ReplacementCharacters
+            | Self::SubjectWhitespace
+            | Self::MissingFinalNewline
+            | Self::MissingIssueReference
+            | Self::DoubleRevert
+            | Self::InconsistentListIndent
+            | Self::ShellArtifactInSubject
+            | Self::WorkInProgress
+            | Self::FooterUrlTooLong
+            | Self::TooManyCoAuthors
+            | Self::SubjectEchoedInBody
+            | Self::InconsistentListPunctuation
+            | Self::SubjectFillerWords
+            | Self::FooterBeforeBody => None,
         }
     }
 
@@ -703,10 +3193,10 @@ impl Lint {
     }
 }
 
+#[cfg(any(test, feature = "quickcheck"))]
 impl Arbitrary for Lint {
     fn arbitrary(g: &mut Gen) -> Self {
-        *g.choose(&ALL_LINTS.iter().copied().collect::<Vec<_>>())
-            .unwrap()
+        *g.choose(ALL_LINTS).unwrap()
     }
 
     fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
@@ -742,7 +3232,7 @@ pub enum Error {
 }
 
 impl Error {
-    fn new_lint_not_found(missing_lint: String) -> Self {
+    pub(crate) fn new_lint_not_found(missing_lint: String) -> Self {
         let length = missing_lint.len();
         Self::LintNotFound(missing_lint, (0, length))
     }
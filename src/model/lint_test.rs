@@ -1,6 +1,12 @@
-use std::convert::TryInto;
+use std::{collections::BTreeSet, convert::TryInto};
 
-use crate::model::Lint;
+use miette::Severity;
+#[cfg(feature = "regex-lints")]
+use regex::Regex;
+
+#[cfg(feature = "regex-lints")]
+use crate::checks;
+use crate::model::{Category, Lint, Lints};
 
 #[quickcheck]
 fn it_is_creatable_from_string(expected: Lint) -> bool {
@@ -36,6 +42,7 @@ fn example_it_is_convertible_to_string() {
     assert_eq!("pivotal-tracker-id-missing".to_string(), string);
 }
 
+#[cfg(feature = "regex-lints")]
 #[test]
 fn example_it_can_be_created_from_string() {
     let lint: Lint = "pivotal-tracker-id-missing".try_into().unwrap();
@@ -50,6 +57,7 @@ fn example_it_is_printable() {
     );
 }
 
+#[cfg(feature = "regex-lints")]
 #[test]
 fn example_i_can_get_all_the_lints() {
     let all: Vec<Lint> = Lint::all_lints().collect();
@@ -67,10 +75,119 @@ fn example_i_can_get_all_the_lints() {
             Lint::BodyWiderThan72Characters,
             Lint::NotConventionalCommit,
             Lint::NotEmojiLog,
+            Lint::DiffPastedInBody,
+            Lint::TooManyTrailers,
+            Lint::SubjectEndsWithPunctuation,
+            Lint::SubjectOnlyTicket,
+            Lint::SubjectGerundStart,
+            Lint::SubjectMarkdownHeading,
+            Lint::UneditedTemplate,
+            Lint::SubjectAllCaps,
+            Lint::MalformedTrailer,
+            Lint::EmptyCommitMessage,
+            Lint::BodyParagraphTooLong,
+            Lint::BodyLeadingTrailingBlankLines,
+            Lint::MalformedRevert,
+            Lint::SubjectMissingPrefix,
+            Lint::SubjectLeadingEmoji,
+            Lint::BodySeparatorLine,
+            Lint::BodyContainsTabs,
+            Lint::UnknownFooterToken,
+            Lint::BodyNotMeaningful,
+            Lint::SubjectNotAtomic,
+            Lint::BodyUnrelatedToSubject,
+            Lint::SubjectCaseStyle,
+            Lint::BodySentenceNoTerminator,
+            Lint::EmailInBody,
+            Lint::ReplacementCharacters,
+            Lint::SubjectWhitespace,
+            Lint::MissingFinalNewline,
+            Lint::MissingIssueReference,
+            Lint::DoubleRevert,
+            Lint::InconsistentListIndent,
+            Lint::ShellArtifactInSubject,
+            Lint::WorkInProgress,
+            Lint::FooterUrlTooLong,
+            Lint::TooManyCoAuthors,
+            Lint::SubjectEchoedInBody,
+            Lint::InconsistentListPunctuation,
+            Lint::SubjectFillerWords,
+            Lint::FooterBeforeBody,
+        ]
+    );
+}
+
+#[cfg(not(feature = "regex-lints"))]
+#[test]
+fn example_i_can_get_all_the_lints_without_the_regex_lints() {
+    let all: Vec<Lint> = Lint::all_lints().collect();
+    assert_eq!(
+        all,
+        vec![
+            Lint::DuplicatedTrailers,
+            Lint::SubjectNotSeparateFromBody,
+            Lint::SubjectLongerThan72Characters,
+            Lint::SubjectNotCapitalized,
+            Lint::SubjectEndsWithPeriod,
+            Lint::BodyWiderThan72Characters,
+            Lint::NotConventionalCommit,
+            Lint::NotEmojiLog,
+            Lint::DiffPastedInBody,
+            Lint::TooManyTrailers,
+            Lint::SubjectEndsWithPunctuation,
+            Lint::SubjectGerundStart,
+            Lint::SubjectMarkdownHeading,
+            Lint::UneditedTemplate,
+            Lint::SubjectAllCaps,
+            Lint::MalformedTrailer,
+            Lint::EmptyCommitMessage,
+            Lint::BodyParagraphTooLong,
+            Lint::BodyLeadingTrailingBlankLines,
+            Lint::MalformedRevert,
+            Lint::SubjectLeadingEmoji,
+            Lint::BodySeparatorLine,
+            Lint::BodyContainsTabs,
+            Lint::UnknownFooterToken,
+            Lint::BodyNotMeaningful,
+            Lint::SubjectNotAtomic,
+            Lint::BodyUnrelatedToSubject,
+            Lint::SubjectCaseStyle,
+            Lint::BodySentenceNoTerminator,
+            Lint::EmailInBody,
+            Lint::ReplacementCharacters,
+            Lint::SubjectWhitespace,
+            Lint::MissingFinalNewline,
+            Lint::DoubleRevert,
+            Lint::InconsistentListIndent,
+            Lint::ShellArtifactInSubject,
+            Lint::WorkInProgress,
+            Lint::FooterUrlTooLong,
+            Lint::TooManyCoAuthors,
+            Lint::SubjectEchoedInBody,
+            Lint::InconsistentListPunctuation,
+            Lint::SubjectFillerWords,
+            Lint::FooterBeforeBody,
         ]
     );
 }
 
+#[cfg(not(feature = "regex-lints"))]
+#[test]
+fn example_the_regex_lints_can_not_be_created_from_string() {
+    for name in [
+        "pivotal-tracker-id-missing",
+        "jira-issue-key-missing",
+        "github-id-missing",
+        "subject-only-ticket",
+    ] {
+        assert!(
+            name.parse::<Lint>().is_err(),
+            "Expected {:?} to be unavailable",
+            name
+        );
+    }
+}
+
 #[test]
 fn example_i_can_get_if_a_lint_is_enabled_by_default() {
     assert!(Lint::DuplicatedTrailers.enabled_by_default());
@@ -79,3 +196,207 @@ fn example_i_can_get_if_a_lint_is_enabled_by_default() {
     assert!(Lint::SubjectNotSeparateFromBody.enabled_by_default());
     assert!(!Lint::GitHubIdMissing.enabled_by_default());
 }
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn is_enabled_in_matches_membership(lint: Lint, lints: BTreeSet<Lint>) -> bool {
+    let expected = lints.contains(&lint);
+    let lints = Lints::new(lints);
+
+    lint.is_enabled_in(&lints) == expected && lints.contains(lint) == expected
+}
+
+#[test]
+fn example_i_can_check_if_a_lint_is_enabled() {
+    let lints = Lints::from(vec![Lint::NotEmojiLog]);
+
+    assert!(Lint::NotEmojiLog.is_enabled_in(&lints));
+    assert!(!Lint::DuplicatedTrailers.is_enabled_in(&lints));
+    assert!(!Lint::NotEmojiLog.is_enabled_in(&Lints::new(BTreeSet::new())));
+}
+
+#[cfg(feature = "regex-lints")]
+#[test]
+fn the_exposed_patterns_compile() {
+    for pattern in [
+        checks::missing_github_id::PATTERN,
+        checks::missing_jira_issue_key::PATTERN,
+        checks::missing_pivotal_tracker_id::PATTERN,
+    ] {
+        assert!(
+            Regex::new(pattern).is_ok(),
+            "Expected {:?} to be a valid regex",
+            pattern
+        );
+    }
+}
+
+#[quickcheck]
+fn only_the_regex_lints_have_a_detection_pattern(lint: Lint) -> bool {
+    let is_regex_lint = matches!(
+        lint,
+        Lint::GitHubIdMissing | Lint::JiraIssueKeyMissing | Lint::PivotalTrackerIdMissing
+    );
+
+    lint.detection_pattern().is_some() == is_regex_lint
+}
+
+#[cfg(feature = "regex-lints")]
+#[test]
+fn example_only_the_regex_lints_have_a_detection_pattern() {
+    assert_eq!(
+        Lint::GitHubIdMissing.detection_pattern(),
+        Some(checks::missing_github_id::PATTERN)
+    );
+    assert_eq!(
+        Lint::JiraIssueKeyMissing.detection_pattern(),
+        Some(checks::missing_jira_issue_key::PATTERN)
+    );
+    assert_eq!(
+        Lint::PivotalTrackerIdMissing.detection_pattern(),
+        Some(checks::missing_pivotal_tracker_id::PATTERN)
+    );
+    assert_eq!(Lint::DuplicatedTrailers.detection_pattern(), None);
+    assert_eq!(Lint::NotConventionalCommit.detection_pattern(), None);
+}
+
+#[test]
+fn the_subject_category_has_exactly_the_subject_lints() {
+    let actual: BTreeSet<Lint> = Lint::by_category(Category::Subject).collect();
+    let expected: BTreeSet<Lint> = vec![
+        Lint::SubjectNotSeparateFromBody,
+        Lint::SubjectLongerThan72Characters,
+        Lint::SubjectNotCapitalized,
+        Lint::SubjectEndsWithPeriod,
+        Lint::NotConventionalCommit,
+        Lint::NotEmojiLog,
+        Lint::SubjectEndsWithPunctuation,
+        Lint::SubjectOnlyTicket,
+        Lint::SubjectGerundStart,
+        Lint::SubjectMarkdownHeading,
+        Lint::SubjectAllCaps,
+        Lint::SubjectMissingPrefix,
+        Lint::SubjectLeadingEmoji,
+        Lint::SubjectNotAtomic,
+        Lint::SubjectCaseStyle,
+        Lint::SubjectWhitespace,
+        Lint::DoubleRevert,
+        Lint::ShellArtifactInSubject,
+        Lint::WorkInProgress,
+        Lint::SubjectFillerWords,
+    ]
+    .into_iter()
+    .filter(is_available)
+    .collect();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn the_trailers_category_has_exactly_the_trailer_lints() {
+    let actual: BTreeSet<Lint> = Lint::by_category(Category::Trailers).collect();
+    let expected: BTreeSet<Lint> = vec![
+        Lint::DuplicatedTrailers,
+        Lint::TooManyTrailers,
+        Lint::MalformedTrailer,
+        Lint::UnknownFooterToken,
+        Lint::FooterUrlTooLong,
+        Lint::TooManyCoAuthors,
+        Lint::FooterBeforeBody,
+    ]
+    .into_iter()
+    .filter(is_available)
+    .collect();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn the_errors_are_the_lints_that_break_tooling_or_conventions() {
+    let actual: BTreeSet<Lint> = Lint::by_severity(Severity::Error).collect();
+    let expected: BTreeSet<Lint> = vec![
+        Lint::DuplicatedTrailers,
+        Lint::PivotalTrackerIdMissing,
+        Lint::JiraIssueKeyMissing,
+        Lint::GitHubIdMissing,
+        Lint::SubjectNotSeparateFromBody,
+        Lint::SubjectLongerThan72Characters,
+        Lint::BodyWiderThan72Characters,
+        Lint::NotConventionalCommit,
+        Lint::NotEmojiLog,
+        Lint::DiffPastedInBody,
+        Lint::UneditedTemplate,
+        Lint::MalformedTrailer,
+        Lint::EmptyCommitMessage,
+        Lint::MalformedRevert,
+        Lint::SubjectMissingPrefix,
+        Lint::ReplacementCharacters,
+        Lint::MissingIssueReference,
+    ]
+    .into_iter()
+    .filter(is_available)
+    .collect();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn the_heuristic_lints_are_advice() {
+    let actual: BTreeSet<Lint> = Lint::by_severity(Severity::Advice).collect();
+    let expected: BTreeSet<Lint> = vec![
+        Lint::BodyNotMeaningful,
+        Lint::SubjectNotAtomic,
+        Lint::BodyUnrelatedToSubject,
+    ]
+    .into_iter()
+    .filter(is_available)
+    .collect();
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn every_lint_is_in_one_category_and_one_severity() {
+    let categories = [
+        Category::Subject,
+        Category::Body,
+        Category::Trailers,
+        Category::Message,
+    ];
+    let severities = [Severity::Error, Severity::Warning, Severity::Advice];
+
+    assert_eq!(
+        categories
+            .iter()
+            .map(|category| Lint::by_category(*category).count())
+            .sum::<usize>(),
+        Lint::all_lints().count()
+    );
+    assert_eq!(
+        severities
+            .iter()
+            .map(|severity| Lint::by_severity(*severity).count())
+            .sum::<usize>(),
+        Lint::all_lints().count()
+    );
+}
+
+#[test]
+fn every_lint_has_its_own_code() {
+    let lints: Vec<Lint> = Lint::all_lints().collect();
+
+    for (index, lint) in lints.iter().enumerate() {
+        assert!(
+            lints[index + 1..]
+                .iter()
+                .all(|other| other.code() != lint.code()),
+            "Expected {:?} to have a code no other lint has",
+            lint
+        );
+        assert_eq!(format!("{:?}", lint.code()), format!("{lint:?}"));
+    }
+}
+
+fn is_available(lint: &Lint) -> bool {
+    Lint::all_lints().any(|available| available == *lint)
+}
@@ -7,14 +7,17 @@ use std::{
 use miette::Diagnostic;
 use thiserror::Error;
 
-use crate::model::{lint, Lint};
+use crate::model::{lint, Category, Lint, CONFIG_KEY_PREFIX};
 
 /// A collection of lints
-#[derive(Debug, Eq, PartialEq, Clone)]
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
 pub struct Lints {
     lints: BTreeSet<Lint>,
 }
 
+/// The names of the built-in presets
+const PRESET_NAMES: [&str; 3] = ["conventional", "minimal", "strict"];
+
 lazy_static! {
     /// All the available lints
     static ref AVAILABLE: Lints = {
@@ -54,6 +57,125 @@ impl Lints {
         &AVAILABLE
     }
 
+    /// Get the lints that are enabled by default
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Lint, Lints};
+    ///
+    /// let lints = Lints::default_enabled();
+    /// assert!(lints.names().contains(&Lint::DuplicatedTrailers.name()));
+    /// assert!(!Lints::default_enabled()
+    ///     .names()
+    ///     .contains(&Lint::NotEmojiLog.name()));
+    /// ```
+    #[must_use]
+    pub fn default_enabled() -> Self {
+        Lint::all_lints()
+            .filter(|lint| lint.enabled_by_default())
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Get the available lints that check the subject
+    ///
+    /// These are cheap enough to run as the subject is typed. Together with
+    /// [`Lints::body_lints`] they cover every available lint, with no lint
+    /// in both.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Lint, Lints};
+    ///
+    /// let lints = Lints::subject_lints();
+    /// assert!(lints.contains(Lint::SubjectLongerThan72Characters));
+    /// assert!(!lints.contains(Lint::BodyWiderThan72Characters));
+    ///
+    /// // Only run the subject lints that are enabled
+    /// let enabled = Lints::default_enabled().subtract(&Lints::body_lints());
+    /// assert!(enabled.contains(Lint::SubjectNotSeparateFromBody));
+    /// assert!(!enabled.contains(Lint::DuplicatedTrailers));
+    /// ```
+    #[must_use]
+    pub fn subject_lints() -> Self {
+        Lint::by_category(Category::Subject)
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Get the available lints that check anything but the subject
+    ///
+    /// This is the body, the trailers, and the message as a whole, so these
+    /// are best run when the message is saved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Lint, Lints};
+    ///
+    /// let lints = Lints::body_lints();
+    /// assert!(lints.contains(Lint::BodyWiderThan72Characters));
+    /// assert!(lints.contains(Lint::DuplicatedTrailers));
+    /// assert!(!lints.contains(Lint::SubjectLongerThan72Characters));
+    /// ```
+    #[must_use]
+    pub fn body_lints() -> Self {
+        Lint::all_lints()
+            .filter(|lint| lint.category() != Category::Subject)
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Get a named preset of lints
+    ///
+    /// The presets are `conventional`, `minimal` and `strict`. Returns `None`
+    /// if there is no preset with that name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Lint, Lints};
+    ///
+    /// let lints = Lints::preset("minimal").unwrap();
+    /// assert!(lints.names().contains(&Lint::DuplicatedTrailers.name()));
+    /// assert_eq!(Lints::preset("unknown"), None);
+    /// ```
+    #[must_use]
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "conventional" => Some(
+                vec![
+                    Lint::NotConventionalCommit,
+                    Lint::SubjectLongerThan72Characters,
+                    Lint::BodyWiderThan72Characters,
+                    Lint::DuplicatedTrailers,
+                ]
+                .into(),
+            ),
+            "minimal" => {
+                Some(vec![Lint::DuplicatedTrailers, Lint::SubjectNotSeparateFromBody].into())
+            }
+            "strict" => Some(Self::available().clone()),
+            _ => None,
+        }
+    }
+
+    /// Get the names of the presets
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::Lints;
+    ///
+    /// assert!(Lints::preset_names().contains(&"strict"));
+    /// ```
+    #[must_use]
+    pub const fn preset_names() -> &'static [&'static str] {
+        &PRESET_NAMES
+    }
+
     /// Get all the names of these lints
     ///
     /// # Examples
@@ -69,6 +191,42 @@ impl Lints {
         self.lints.iter().map(|lint| lint.name()).collect()
     }
 
+    /// Iterate over the names of these lints, without consuming them
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Lint, Lints};
+    ///
+    /// let lints = Lints::from(vec![Lint::NotEmojiLog, Lint::DuplicatedTrailers]);
+    /// for name in lints.iter_names() {
+    ///     println!("{name}");
+    /// }
+    /// assert_eq!(
+    ///     lints.iter_names().collect::<Vec<_>>(),
+    ///     vec!["duplicated-trailers", "not-emoji-log"]
+    /// );
+    /// ```
+    pub fn iter_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.lints.iter().map(|lint| lint.name())
+    }
+
+    /// Check if a lint is in this set
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Lint, Lints};
+    ///
+    /// let lints = Lints::from(vec![Lint::NotEmojiLog]);
+    /// assert!(lints.contains(Lint::NotEmojiLog));
+    /// assert!(!lints.contains(Lint::DuplicatedTrailers));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, lint: Lint) -> bool {
+        self.lints.contains(&lint)
+    }
+
     /// Get all the config keys of these lints
     ///
     /// # Examples
@@ -84,6 +242,144 @@ impl Lints {
         self.lints.iter().map(|lint| lint.config_key()).collect()
     }
 
+    /// Get the config keys of these lints, sorted alphabetically
+    ///
+    /// The order only depends on the keys, not on the order the lints were
+    /// added or declared in, so it's stable to persist. Turn them back into
+    /// lints with [`Lints::from_sorted_config_keys`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Lint, Lints};
+    ///
+    /// let lints = Lints::from(vec![Lint::SubjectLongerThan72Characters, Lint::NotEmojiLog]);
+    /// assert_eq!(
+    ///     lints.to_sorted_config_keys(),
+    ///     vec![
+    ///         "mit.lint.not-emoji-log",
+    ///         "mit.lint.subject-longer-than-72-characters"
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_sorted_config_keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.lints.iter().map(|lint| lint.config_key()).collect();
+        keys.sort();
+        keys
+    }
+
+    /// Create the lints from their config keys, like
+    /// `mit.lint.duplicated-trailers`
+    ///
+    /// This reverses [`Lints::to_sorted_config_keys`]. The keys can be in any
+    /// order.
+    ///
+    /// # Errors
+    ///
+    /// If a key isn't the config key of a lint
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Lint, Lints};
+    ///
+    /// let lints = Lints::from(vec![Lint::NotEmojiLog, Lint::DuplicatedTrailers]);
+    /// assert_eq!(
+    ///     Lints::from_sorted_config_keys(&lints.to_sorted_config_keys()).unwrap(),
+    ///     lints
+    /// );
+    /// assert!(Lints::from_sorted_config_keys(&["not-emoji-log".to_string()]).is_err());
+    /// ```
+    pub fn from_sorted_config_keys(keys: &[String]) -> Result<Self, Error> {
+        keys.iter()
+            .map(|key| {
+                key.strip_prefix(CONFIG_KEY_PREFIX)
+                    .and_then(|name| name.strip_prefix('.'))
+                    .map_or_else(
+                        || Err(lint::Error::new_lint_not_found(key.clone())),
+                        Lint::try_from,
+                    )
+            })
+            .collect::<Result<BTreeSet<Lint>, lint::Error>>()
+            .map(Self::new)
+            .map_err(Error::from)
+    }
+
+    /// Pair every available lint with whether it is in this set
+    ///
+    /// Useful for working out which lints will run before linting anything.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Lint, Lints};
+    ///
+    /// let lints = Lints::from(vec![Lint::NotEmojiLog]);
+    /// let explained = lints.explain();
+    /// assert!(explained.contains(&(Lint::NotEmojiLog, true)));
+    /// assert!(explained.contains(&(Lint::DuplicatedTrailers, false)));
+    /// ```
+    #[must_use]
+    pub fn explain(&self) -> Vec<(Lint, bool)> {
+        Self::available()
+            .lints
+            .iter()
+            .map(|lint| (*lint, self.lints.contains(lint)))
+            .collect()
+    }
+
+    /// Create the lints from a map of lints to whether they are enabled
+    ///
+    /// Only the lints mapped to `true` are included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    ///
+    /// use mit_lint::{Lint, Lints};
+    ///
+    /// let mut map = BTreeMap::new();
+    /// map.insert(Lint::NotEmojiLog, true);
+    /// map.insert(Lint::DuplicatedTrailers, false);
+    /// assert_eq!(
+    ///     Lints::from_enabled_map(&map),
+    ///     Lints::from(vec![Lint::NotEmojiLog])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn from_enabled_map(map: &BTreeMap<Lint, bool>) -> Self {
+        Self::new(
+            map.iter()
+                .filter(|(_, enabled)| **enabled)
+                .map(|(lint, _)| *lint)
+                .collect(),
+        )
+    }
+
+    /// Map every available lint to whether it is in this set
+    ///
+    /// This has the same entries as the `[mit.lint]` table you get by
+    /// converting the lints to a toml `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Lint, Lints};
+    ///
+    /// let map = Lints::from(vec![Lint::NotEmojiLog]).to_enabled_map();
+    /// assert_eq!(map.get(&Lint::NotEmojiLog), Some(&true));
+    /// assert_eq!(map.get(&Lint::DuplicatedTrailers), Some(&false));
+    /// assert_eq!(map.len(), Lint::all_lints().count());
+    /// ```
+    #[must_use]
+    pub fn to_enabled_map(&self) -> BTreeMap<Lint, bool> {
+        Lint::all_lints()
+            .map(|lint| (lint, self.lints.contains(&lint)))
+            .collect()
+    }
+
     /// Create the union of two lints
     ///
     /// # Examples
@@ -115,6 +411,69 @@ impl Lints {
     pub fn subtract(&self, other: &Self) -> Self {
         Self::new(self.lints.difference(&other.lints).copied().collect())
     }
+
+    /// Get the lints that have been added and removed, relative to other
+    ///
+    /// Wrap the result in a [`LintsDiff`](crate::LintsDiff) to display it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Lint, Lints};
+    ///
+    /// let lints = Lints::from(vec![Lint::NotEmojiLog, Lint::DuplicatedTrailers]);
+    /// let (added, removed) = lints.diff(&Lints::default_enabled());
+    /// assert_eq!(added, Lints::from(vec![Lint::NotEmojiLog]));
+    /// let removed = removed.names();
+    /// assert!(removed.contains(&Lint::SubjectNotSeparateFromBody.name()));
+    /// assert!(!removed.contains(&Lint::DuplicatedTrailers.name()));
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> (Self, Self) {
+        (self.subtract(other), other.subtract(self))
+    }
+
+    /// Read the lints from the `[mit.lint]` table of a toml document
+    ///
+    /// Lints that aren't mentioned keep their default state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Lint, Lints};
+    ///
+    /// let lints = Lints::from_toml("[mit.lint]\nnot-emoji-log = true\nduplicated-trailers = false\n")
+    ///     .unwrap();
+    /// let names = lints.names();
+    /// assert!(names.contains(&Lint::NotEmojiLog.name()));
+    /// assert!(!names.contains(&Lint::DuplicatedTrailers.name()));
+    /// assert!(names.contains(&Lint::SubjectNotSeparateFromBody.name()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the toml can't be parsed, or names a lint that doesn't exist
+    pub fn from_toml(toml: &str) -> Result<Self, Error> {
+        let document: toml::Table = toml.parse()?;
+        let settings: BTreeMap<String, bool> =
+            match document.get("mit").and_then(|mit| mit.get("lint")).cloned() {
+                Some(lint) => lint.try_into()?,
+                None => BTreeMap::new(),
+            };
+
+        settings.into_iter().try_fold(
+            Self::default_enabled(),
+            |lints, (name, enabled)| -> Result<Self, Error> {
+                let lint = Self::new(vec![Lint::try_from(name.as_str())?].into_iter().collect());
+
+                Ok(if enabled {
+                    lints.merge(&lint)
+                } else {
+                    lints.subtract(&lint)
+                })
+            },
+        )
+    }
 }
 
 impl IntoIterator for Lints {
@@ -130,14 +489,11 @@ impl TryFrom<Lints> for String {
     type Error = Error;
 
     fn try_from(lints: Lints) -> Result<Self, Self::Error> {
-        let enabled: Vec<_> = lints.into();
-
-        let config: BTreeMap<Self, bool> = Lint::all_lints()
-            .map(|x| (x, enabled.contains(&x)))
-            .fold(BTreeMap::new(), |mut acc, (lint, state)| {
-                acc.insert(lint.to_string(), state);
-                acc
-            });
+        let config: BTreeMap<Self, bool> = lints
+            .to_enabled_map()
+            .into_iter()
+            .map(|(lint, state)| (lint.to_string(), state))
+            .collect();
 
         let mut inner: BTreeMap<Self, BTreeMap<Self, bool>> = BTreeMap::new();
         inner.insert("lint".into(), config);
@@ -0,0 +1,86 @@
+use std::collections::BTreeSet;
+
+use crate::model::{Lint, Lints};
+
+/// Build up a set of lints one change at a time
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_lint::{Lint, Lints, LintsBuilder};
+///
+/// let lints = LintsBuilder::from_defaults()
+///     .enable(Lint::NotConventionalCommit)
+///     .disable(Lint::BodyWiderThan72Characters)
+///     .build();
+///
+/// assert!(lints
+///     .clone()
+///     .names()
+///     .contains(&Lint::NotConventionalCommit.name()));
+/// assert!(!lints
+///     .names()
+///     .contains(&Lint::BodyWiderThan72Characters.name()));
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Hash, Default)]
+pub struct LintsBuilder {
+    lints: BTreeSet<Lint>,
+}
+
+impl LintsBuilder {
+    /// Start with no lints enabled
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start with the lints that are enabled by default
+    #[must_use]
+    pub fn from_defaults() -> Self {
+        Self {
+            lints: Lints::default_enabled().into_iter().collect(),
+        }
+    }
+
+    /// Enable a lint
+    #[must_use]
+    pub fn enable(mut self, lint: Lint) -> Self {
+        self.lints.insert(lint);
+        self
+    }
+
+    /// Disable a lint
+    #[must_use]
+    pub fn disable(mut self, lint: Lint) -> Self {
+        self.lints.remove(&lint);
+        self
+    }
+
+    /// Enable every available lint
+    #[must_use]
+    pub fn enable_all(mut self) -> Self {
+        self.lints = Lints::available().clone().into_iter().collect();
+        self
+    }
+
+    /// Disable every lint
+    #[must_use]
+    pub fn disable_all(mut self) -> Self {
+        self.lints.clear();
+        self
+    }
+
+    /// Get the lints that have been enabled
+    #[must_use]
+    pub fn build(self) -> Lints {
+        Lints::new(self.lints)
+    }
+}
+
+impl From<Lints> for LintsBuilder {
+    fn from(lints: Lints) -> Self {
+        Self {
+            lints: lints.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt::{Display, Formatter};
+
+use crate::model::Lints;
+
+/// The lints added and removed between two sets of lints, for showing to
+/// people
+///
+/// Each added lint is shown on its own line starting with `+`, followed by
+/// each removed lint starting with `-`.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_lint::{Lint, Lints, LintsDiff};
+///
+/// let lints = Lints::from(vec![Lint::NotEmojiLog, Lint::DuplicatedTrailers]);
+/// let other = Lints::from(vec![
+///     Lint::DuplicatedTrailers,
+///     Lint::SubjectNotSeparateFromBody,
+/// ]);
+/// let diff = LintsDiff::from(lints.diff(&other));
+/// assert_eq!(
+///     diff.to_string(),
+///     "+ not-emoji-log\n- subject-not-separated-from-body\n"
+/// );
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct LintsDiff {
+    added: Lints,
+    removed: Lints,
+}
+
+impl LintsDiff {
+    /// The lints that have been added
+    #[must_use]
+    pub const fn added(&self) -> &Lints {
+        &self.added
+    }
+
+    /// The lints that have been removed
+    #[must_use]
+    pub const fn removed(&self) -> &Lints {
+        &self.removed
+    }
+
+    /// Check if nothing was added or removed
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.clone().into_iter().next().is_none()
+            && self.removed.clone().into_iter().next().is_none()
+    }
+}
+
+impl From<(Lints, Lints)> for LintsDiff {
+    fn from((added, removed): (Lints, Lints)) -> Self {
+        Self { added, removed }
+    }
+}
+
+impl Display for LintsDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for lint in self.added.clone() {
+            writeln!(f, "+ {lint}")?;
+        }
+        for lint in self.removed.clone() {
+            writeln!(f, "- {lint}")?;
+        }
+
+        Ok(())
+    }
+}
@@ -11,13 +11,17 @@ use crate::model::{
         BodyWiderThan72Characters,
         DuplicatedTrailers,
         JiraIssueKeyMissing,
+        NotConventionalCommit,
         PivotalTrackerIdMissing,
         SubjectLongerThan72Characters,
         SubjectNotSeparateFromBody,
     },
     lints::Error,
+    Category,
     Lint,
     Lints,
+    LintsBuilder,
+    LintsDiff,
 };
 
 #[allow(clippy::needless_pass_by_value)]
@@ -69,6 +73,7 @@ fn it_can_construct_itself_from_names(lints: Vec<Lint>) -> bool {
     expected == actual
 }
 
+#[cfg(feature = "regex-lints")]
 #[test]
 fn example_it_can_construct_itself_from_names() {
     let lints = vec!["pivotal-tracker-id-missing", "jira-issue-key-missing"];
@@ -152,6 +157,14 @@ fn example_it_can_give_me_the_names() {
     assert_eq!(expected, actual);
 }
 
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn iterating_names_matches_the_names(lints: BTreeSet<Lint>) -> bool {
+    let lints = Lints::new(lints);
+
+    lints.iter_names().collect::<Vec<_>>() == lints.clone().names()
+}
+
 #[quickcheck]
 fn it_can_give_me_the_config_keys(lints: BTreeSet<Lint>) -> bool {
     let lint_names: Vec<String> = lints.clone().into_iter().map(Lint::config_key).collect();
@@ -230,6 +243,55 @@ fn get_toml(expected: BTreeMap<Lint, bool>) -> bool {
     })
 }
 
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn enabled_map_round_trips(lints: Vec<Lint>) -> bool {
+    let lints = Lints::from(lints);
+    Lints::from_enabled_map(&lints.to_enabled_map()) == lints
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn enabled_map_matches_toml(map: BTreeMap<Lint, bool>) -> bool {
+    let lints = Lints::from_enabled_map(&map);
+    let toml = String::try_from(lints.clone()).expect("To be able to convert lints to toml");
+    let full: BTreeMap<String, BTreeMap<String, BTreeMap<String, bool>>> =
+        toml::from_str(toml.as_str()).unwrap();
+    let from_toml: BTreeMap<Lint, bool> = full["mit"]["lint"]
+        .iter()
+        .map(|(lint, enabled)| (Lint::try_from(lint.as_str()).unwrap(), *enabled))
+        .collect();
+
+    from_toml == lints.to_enabled_map()
+}
+
+#[test]
+fn enabled_map_has_every_lint() {
+    let map = Lints::from(vec![DuplicatedTrailers]).to_enabled_map();
+
+    assert_eq!(map.len(), Lint::all_lints().count());
+    assert_eq!(
+        map.iter()
+            .filter(|(_, enabled)| **enabled)
+            .map(|(lint, _)| *lint)
+            .collect::<Vec<_>>(),
+        vec![DuplicatedTrailers]
+    );
+}
+
+#[test]
+fn disabled_entries_are_left_out_of_the_enabled_map() {
+    let mut map = BTreeMap::new();
+    map.insert(DuplicatedTrailers, true);
+    map.insert(SubjectNotSeparateFromBody, false);
+
+    assert_eq!(
+        Lints::from_enabled_map(&map),
+        Lints::from(vec![DuplicatedTrailers])
+    );
+}
+
+#[cfg(feature = "regex-lints")]
 #[test]
 fn example_get_toml() {
     let mut lints_on = BTreeSet::new();
@@ -240,17 +302,116 @@ fn example_get_toml() {
     lints_on.insert(PivotalTrackerIdMissing);
     let actual = String::try_from(Lints::new(lints_on)).expect("Failed to serialise");
     let expected = "[mit.lint]
+body-contains-tabs = false
+body-leading-trailing-blank-lines = false
+body-not-meaningful = false
+body-paragraph-too-long = false
+body-sentence-no-terminator = false
+body-separator-line = false
+body-unrelated-to-subject = false
 body-wider-than-72-characters = true
+diff-pasted-in-body = false
+double-revert = false
 duplicated-trailers = true
+email-in-body = false
+empty-commit-message = false
+footer-before-body = false
+footer-url-too-long = false
 github-id-missing = false
+inconsistent-list-indent = false
+inconsistent-list-punctuation = false
 jira-issue-key-missing = false
+malformed-revert = false
+malformed-trailer = false
+missing-final-newline = false
+missing-issue-reference = false
 not-conventional-commit = false
 not-emoji-log = false
 pivotal-tracker-id-missing = true
+replacement-characters = false
+shell-artifact-in-subject = false
+subject-all-caps = false
+subject-case-style = false
+subject-echoed-in-body = false
+subject-filler-words = false
+subject-gerund-start = false
+subject-leading-emoji = false
+subject-line-ends-with-period = false
+subject-line-ends-with-punctuation = false
+subject-line-not-capitalized = false
+subject-longer-than-72-characters = true
+subject-markdown-heading = false
+subject-missing-prefix = false
+subject-not-atomic = false
+subject-not-separated-from-body = true
+subject-only-ticket = false
+subject-whitespace = false
+too-many-co-authors = false
+too-many-trailers = false
+unedited-template = false
+unknown-footer-token = false
+work-in-progress = false
+";
+
+    assert_eq!(
+        expected, actual,
+        "Expected the list of lint identifiers to be {expected:?}, instead got {actual:?}"
+    );
+}
+
+#[cfg(not(feature = "regex-lints"))]
+#[test]
+fn example_get_toml_without_the_regex_lints() {
+    let mut lints_on = BTreeSet::new();
+    lints_on.insert(DuplicatedTrailers);
+    lints_on.insert(SubjectNotSeparateFromBody);
+    lints_on.insert(SubjectLongerThan72Characters);
+    lints_on.insert(BodyWiderThan72Characters);
+    let actual = String::try_from(Lints::new(lints_on)).expect("Failed to serialise");
+    let expected = "[mit.lint]
+body-contains-tabs = false
+body-leading-trailing-blank-lines = false
+body-not-meaningful = false
+body-paragraph-too-long = false
+body-sentence-no-terminator = false
+body-separator-line = false
+body-unrelated-to-subject = false
+body-wider-than-72-characters = true
+diff-pasted-in-body = false
+double-revert = false
+duplicated-trailers = true
+email-in-body = false
+empty-commit-message = false
+footer-before-body = false
+footer-url-too-long = false
+inconsistent-list-indent = false
+inconsistent-list-punctuation = false
+malformed-revert = false
+malformed-trailer = false
+missing-final-newline = false
+not-conventional-commit = false
+not-emoji-log = false
+replacement-characters = false
+shell-artifact-in-subject = false
+subject-all-caps = false
+subject-case-style = false
+subject-echoed-in-body = false
+subject-filler-words = false
+subject-gerund-start = false
+subject-leading-emoji = false
 subject-line-ends-with-period = false
+subject-line-ends-with-punctuation = false
 subject-line-not-capitalized = false
 subject-longer-than-72-characters = true
+subject-markdown-heading = false
+subject-not-atomic = false
 subject-not-separated-from-body = true
+subject-whitespace = false
+too-many-co-authors = false
+too-many-trailers = false
+unedited-template = false
+unknown-footer-token = false
+work-in-progress = false
 ";
 
     assert_eq!(
@@ -363,3 +524,276 @@ fn example_when_merging_overlapping_does_not_lead_to_duplication() {
         "Expected the list of lint identifiers to be {expected:?}, instead got {actual:?}"
     );
 }
+
+#[test]
+fn example_the_conventional_preset_has_the_conventional_lints() {
+    let actual = Lints::preset("conventional");
+    let expected = Some(Lints::from(vec![
+        BodyWiderThan72Characters,
+        DuplicatedTrailers,
+        NotConventionalCommit,
+        SubjectLongerThan72Characters,
+    ]));
+
+    assert_eq!(
+        expected, actual,
+        "Expected the preset to be {expected:?}, instead got {actual:?}"
+    );
+}
+
+#[test]
+fn example_the_minimal_preset_has_the_minimal_lints() {
+    let actual = Lints::preset("minimal");
+    let expected = Some(Lints::from(vec![
+        DuplicatedTrailers,
+        SubjectNotSeparateFromBody,
+    ]));
+
+    assert_eq!(
+        expected, actual,
+        "Expected the preset to be {expected:?}, instead got {actual:?}"
+    );
+}
+
+#[test]
+fn example_the_strict_preset_has_all_the_lints() {
+    let actual = Lints::preset("strict");
+    let expected = Some(Lints::available().clone());
+
+    assert_eq!(
+        expected, actual,
+        "Expected the preset to be {expected:?}, instead got {actual:?}"
+    );
+}
+
+#[test]
+fn example_every_preset_name_is_a_preset() {
+    assert_eq!(
+        Lints::preset_names(),
+        &["conventional", "minimal", "strict"]
+    );
+    for name in Lints::preset_names() {
+        assert!(
+            Lints::preset(name).is_some(),
+            "Expected {:?} to be a preset",
+            name
+        );
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn unknown_presets_are_none(name: String) -> TestResult {
+    if Lints::preset_names().contains(&name.as_str()) {
+        return TestResult::discard();
+    }
+
+    TestResult::from_bool(Lints::preset(&name).is_none())
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn explain_pairs_every_available_lint_with_its_state(lints: BTreeSet<Lint>) -> bool {
+    let explained = Lints::new(lints.clone()).explain();
+
+    explained.len() == Lint::all_lints().count()
+        && explained
+            .into_iter()
+            .all(|(lint, enabled)| lints.contains(&lint) == enabled)
+}
+
+#[test]
+fn example_explain_pairs_every_available_lint_with_its_state() {
+    let actual = Lints::from(vec![DuplicatedTrailers]).explain();
+
+    assert!(actual.contains(&(DuplicatedTrailers, true)));
+    assert!(actual.contains(&(SubjectNotSeparateFromBody, false)));
+    assert_eq!(actual.iter().filter(|(_, enabled)| *enabled).count(), 1);
+}
+
+#[test]
+fn diffing_available_against_the_defaults_adds_the_non_default_lints() {
+    let (added, removed) = Lints::available().diff(&Lints::default_enabled());
+
+    assert_eq!(
+        added,
+        Lint::all_lints()
+            .filter(|lint| !lint.enabled_by_default())
+            .collect::<Vec<_>>()
+            .into()
+    );
+    assert_eq!(removed, Lints::new(BTreeSet::new()));
+}
+
+#[test]
+fn diffing_the_other_way_removes_the_non_default_lints() {
+    let (added, removed) = Lints::default_enabled().diff(Lints::available());
+
+    assert_eq!(added, Lints::new(BTreeSet::new()));
+    assert_eq!(
+        removed,
+        Lints::available().subtract(&Lints::default_enabled())
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn diff_reverses_to_the_original(lints: BTreeSet<Lint>, other: BTreeSet<Lint>) -> bool {
+    let lints = Lints::new(lints);
+    let other = Lints::new(other);
+    let (added, removed) = lints.diff(&other);
+
+    other.merge(&added).subtract(&removed) == lints
+}
+
+#[test]
+fn diffs_display_added_then_removed_lints() {
+    let lints = Lints::from(vec![NotConventionalCommit, DuplicatedTrailers]);
+    let other = Lints::from(vec![DuplicatedTrailers, SubjectNotSeparateFromBody]);
+    let diff = LintsDiff::from(lints.diff(&other));
+
+    assert_eq!(
+        diff.to_string(),
+        "+ not-conventional-commit\n- subject-not-separated-from-body\n"
+    );
+    assert!(!diff.is_empty());
+}
+
+#[test]
+fn identical_lints_have_an_empty_diff() {
+    let diff = LintsDiff::from(Lints::available().diff(Lints::available()));
+
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "");
+}
+
+#[test]
+fn the_builder_can_adjust_the_defaults() {
+    let actual = LintsBuilder::from_defaults()
+        .enable(NotConventionalCommit)
+        .disable(BodyWiderThan72Characters)
+        .build();
+
+    let mut expected: BTreeSet<Lint> = Lints::default_enabled().into_iter().collect();
+    expected.insert(NotConventionalCommit);
+    expected.remove(&BodyWiderThan72Characters);
+    assert_eq!(actual, Lints::new(expected));
+}
+
+#[test]
+fn the_builder_can_enable_and_disable_everything() {
+    assert_eq!(
+        LintsBuilder::new().enable_all().build(),
+        Lints::available().clone()
+    );
+    assert_eq!(
+        LintsBuilder::from_defaults()
+            .disable_all()
+            .enable(DuplicatedTrailers)
+            .build(),
+        Lints::from(vec![DuplicatedTrailers])
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn lints_round_trip_through_toml(lints: BTreeSet<Lint>) -> bool {
+    let lints = Lints::new(lints);
+    let toml: String = lints.clone().try_into().unwrap();
+
+    Lints::from_toml(&toml).unwrap() == lints
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn lints_round_trip_through_sorted_config_keys(lints: BTreeSet<Lint>) -> bool {
+    let lints = Lints::new(lints);
+
+    Lints::from_sorted_config_keys(&lints.to_sorted_config_keys()).unwrap() == lints
+}
+
+#[test]
+fn sorted_config_keys_are_alphabetical_whatever_the_order_added() {
+    let forwards = Lints::from(Lint::all_lints().collect::<Vec<_>>());
+    let mut reversed: Vec<Lint> = Lint::all_lints().collect();
+    reversed.reverse();
+    let backwards = Lints::from(reversed);
+    let keys = forwards.to_sorted_config_keys();
+
+    assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(keys, backwards.to_sorted_config_keys());
+    assert_eq!(keys.len(), Lint::all_lints().count());
+}
+
+#[test]
+fn config_keys_without_the_prefix_are_an_error() {
+    let actual = Lints::from_sorted_config_keys(&["duplicated-trailers".to_string()]);
+
+    assert!(
+        matches!(actual, Err(Error::LintNameUnknown(_))),
+        "Expected an unknown lint error, found {:?}",
+        actual
+    );
+}
+
+#[test]
+fn unmentioned_lints_keep_their_default_in_toml() {
+    assert_eq!(
+        Lints::from_toml("[mit.author]\nname = \"Someone\"\n").unwrap(),
+        Lints::default_enabled()
+    );
+}
+
+#[test]
+fn unknown_lints_in_toml_are_an_error() {
+    let actual = Lints::from_toml("[mit.lint]\nnot-a-lint = true\n");
+
+    assert!(
+        matches!(actual, Err(Error::LintNameUnknown(_))),
+        "Expected an unknown lint error, found {:?}",
+        actual
+    );
+}
+
+#[test]
+fn invalid_toml_is_an_error() {
+    let actual = Lints::from_toml("[mit.lint]\nnot-emoji-log = \"yes\"\n");
+
+    assert!(
+        matches!(actual, Err(Error::TomlParse(_))),
+        "Expected a parse error, found {:?}",
+        actual
+    );
+}
+
+#[test]
+fn the_subject_and_body_lints_do_not_overlap() {
+    let subject: BTreeSet<Lint> = Lints::subject_lints().into_iter().collect();
+    let body: BTreeSet<Lint> = Lints::body_lints().into_iter().collect();
+
+    assert!(subject.is_disjoint(&body));
+}
+
+#[test]
+fn the_subject_and_body_lints_cover_every_lint() {
+    assert_eq!(
+        Lints::subject_lints().merge(&Lints::body_lints()),
+        Lints::available().clone()
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn each_lint_is_a_subject_lint_or_a_body_lint(lint: Lint) -> bool {
+    Lints::subject_lints().contains(lint) != Lints::body_lints().contains(lint)
+}
+
+#[test]
+fn the_subject_lints_are_the_subject_category() {
+    assert!(Lints::subject_lints()
+        .into_iter()
+        .all(|lint| lint.category() == Category::Subject));
+    assert!(Lints::subject_lints().contains(SubjectLongerThan72Characters));
+    assert!(Lints::body_lints().contains(BodyWiderThan72Characters));
+    assert!(Lints::body_lints().contains(DuplicatedTrailers));
+}
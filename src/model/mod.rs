@@ -1,15 +1,31 @@
+pub use category::Category;
 pub use code::Code;
+pub use config::{is_merge_commit, sample_toml, LintConfig};
+pub use custom_lint::CustomLint;
+pub use label::Label;
 pub use lint::{Error as LintError, Lint, CONFIG_KEY_PREFIX};
 pub use lints::{Error, Lints};
+pub use lints_builder::LintsBuilder;
+pub use lints_diff::LintsDiff;
 pub use problem::Problem;
+pub use problem_builder::ProblemBuilder;
 
+mod category;
 mod code;
+mod config;
+#[cfg(test)]
+mod config_test;
+mod custom_lint;
+mod label;
 mod lint;
 #[cfg(test)]
 mod lint_test;
 mod lints;
+mod lints_builder;
+mod lints_diff;
 #[cfg(test)]
 mod lints_test;
 mod problem;
+mod problem_builder;
 #[cfg(test)]
 mod problem_test;
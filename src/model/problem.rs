@@ -1,13 +1,14 @@
 use std::fmt::Display;
 
-use miette::{Diagnostic, LabeledSpan, SourceCode};
+use miette::{Diagnostic, LabeledSpan, Report, SourceCode};
 use mit_commit::CommitMessage;
 use thiserror::Error;
 
-use crate::model::code::Code;
+use crate::model::{code::Code, problem_builder::cap_labels, Label};
 
 /// Information about the breaking of the lint
 #[derive(Error, Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[error("{error}")]
 pub struct Problem {
     error: String,
@@ -91,17 +92,46 @@ impl Problem {
         commit_message: &CommitMessage<'_>,
         labels: Option<Vec<(String, usize, usize)>>,
         url: Option<String>,
+    ) -> Self {
+        Self::from_commit_text(
+            error,
+            tip,
+            code,
+            String::from(commit_message.clone()),
+            labels,
+            url,
+        )
+    }
+
+    /// Create a new problem from commit text that's already been converted
+    ///
+    /// Converting a commit to text and back doesn't always give the same
+    /// text, so use this when the labels were worked out against the text.
+    pub(crate) const fn from_commit_text(
+        error: String,
+        tip: String,
+        code: Code,
+        commit_message: String,
+        labels: Option<Vec<(String, usize, usize)>>,
+        url: Option<String>,
     ) -> Self {
         Self {
             error,
             tip,
             code,
-            commit_message: String::from(commit_message.clone()),
+            commit_message,
             labels,
             url,
         }
     }
 
+    /// Limit how many labels this problem has, replacing the rest with one
+    /// label saying how many were left out
+    pub(crate) fn with_max_labels(mut self, max: Option<usize>) -> Self {
+        self.labels = self.labels.map(|labels| cap_labels(labels, max));
+        self
+    }
+
     /// Get the code for this problem
     ///
     /// # Examples
@@ -126,6 +156,98 @@ impl Problem {
         &self.code
     }
 
+    /// Get the labels for this problem, as plain data rather than miette
+    /// spans
+    ///
+    /// Use this when drawing the problem in your own interface. Problems
+    /// without labels give an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Code, Label, Problem};
+    /// let problem = Problem::new(
+    ///     "Error title".to_string(),
+    ///     "Some advice on how to fix it".to_string(),
+    ///     Code::BodyWiderThan72Characters,
+    ///     &"Commit Message".into(),
+    ///     Some(vec![("Too long".to_string(), 7, 7)]),
+    ///     None,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     problem.structured_labels(),
+    ///     vec![Label {
+    ///         text: "Too long".to_string(),
+    ///         offset: 7,
+    ///         length: 7,
+    ///     }]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn structured_labels(&self) -> Vec<Label> {
+        self.labels
+            .iter()
+            .flatten()
+            .cloned()
+            .map(Label::from)
+            .collect()
+    }
+
+    /// Get the labels for this problem, with offsets and lengths counted in
+    /// characters rather than bytes
+    ///
+    /// Labels are stored as byte spans, which is what miette expects. Use this
+    /// when your tool indexes the commit message by character, so multibyte
+    /// characters like emoji don't push the labels out of place. Problems
+    /// without labels give `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_lint::{Code, Label, Problem};
+    /// let problem = Problem::new(
+    ///     "Error title".to_string(),
+    ///     "Some advice on how to fix it".to_string(),
+    ///     Code::BodyWiderThan72Characters,
+    ///     &"🎉 Commit Message".into(),
+    ///     Some(vec![("Too long".to_string(), 12, 7)]),
+    ///     None,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     problem.labels_as_char_offsets(),
+    ///     Some(vec![Label {
+    ///         text: "Too long".to_string(),
+    ///         offset: 9,
+    ///         length: 7,
+    ///     }])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn labels_as_char_offsets(&self) -> Option<Vec<Label>> {
+        let char_offset = |byte_offset: usize| {
+            self.commit_message
+                .char_indices()
+                .take_while(|(index, _)| *index < byte_offset)
+                .count()
+        };
+
+        self.labels.as_ref().map(|labels| {
+            labels
+                .iter()
+                .map(|(text, offset, length)| {
+                    let start = char_offset(*offset);
+                    Label {
+                        text: text.clone(),
+                        offset: start,
+                        length: char_offset(offset + length) - start,
+                    }
+                })
+                .collect()
+        })
+    }
+
     /// Get the commit message for this problem
     ///
     /// # Examples
@@ -154,6 +276,12 @@ impl Problem {
         self.commit_message.clone().into()
     }
 
+    /// Get the text of the commit message exactly as the labels were worked
+    /// out against
+    pub(crate) fn commit_text(&self) -> &str {
+        &self.commit_message
+    }
+
     /// Get the descriptive title for this error
     ///
     /// # Examples
@@ -203,4 +331,66 @@ impl Problem {
     pub fn tip(&self) -> &str {
         &self.tip
     }
+
+    /// Check whether this is the same finding as another problem, even if
+    /// they were found in different commit messages
+    ///
+    /// The code, the error, and the text and position of the labels are
+    /// compared. Useful for counting how often the same problem comes up
+    /// across many commits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::option::Option::None;
+    ///
+    /// use mit_lint::{Code, Problem};
+    /// let first = Problem::new(
+    ///     "Error title".to_string(),
+    ///     "Some advice on how to fix it".to_string(),
+    ///     Code::SubjectEndsWithPeriod,
+    ///     &"Add login.".into(),
+    ///     Some(vec![("Unneeded period".to_string(), 9, 1)]),
+    ///     None,
+    /// );
+    /// let second = Problem::new(
+    ///     "Error title".to_string(),
+    ///     "Some advice on how to fix it".to_string(),
+    ///     Code::SubjectEndsWithPeriod,
+    ///     &"Fix links.".into(),
+    ///     Some(vec![("Unneeded period".to_string(), 9, 1)]),
+    ///     None,
+    /// );
+    ///
+    /// assert!(first.same_finding(&second));
+    /// assert_ne!(first, second);
+    /// ```
+    #[must_use]
+    pub fn same_finding(&self, other: &Self) -> bool {
+        self.code == other.code && self.error == other.error && self.labels == other.labels
+    }
+
+    /// Convert this problem into a [`miette::Report`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::option::Option::None;
+    ///
+    /// use mit_lint::{Code, Problem};
+    /// let problem = Problem::new(
+    ///     "Error title".to_string(),
+    ///     "Some advice on how to fix it".to_string(),
+    ///     Code::BodyWiderThan72Characters,
+    ///     &"Commit Message".into(),
+    ///     None,
+    ///     None,
+    /// );
+    ///
+    /// assert_eq!(problem.into_report().to_string(), "Error title")
+    /// ```
+    #[must_use]
+    pub fn into_report(self) -> Report {
+        Report::new(self)
+    }
 }
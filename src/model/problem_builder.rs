@@ -0,0 +1,244 @@
+use mit_commit::CommitMessage;
+
+use crate::model::{Code, Problem};
+
+/// Build up a [`Problem`] one part at a time
+///
+/// Use this when writing your own lints, so you don't have to work out the
+/// byte offsets of whole lines yourself.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{Code, Label, ProblemBuilder};
+///
+/// let commit = CommitMessage::from("Add login\n\nSome body\n");
+/// let problem = ProblemBuilder::new(
+///     "Your commit mentions logging in".to_string(),
+///     "Describe the change without mentioning login".to_string(),
+///     Code::NotConventionalCommit,
+///     &commit,
+/// )
+/// .with_label_for_line("Mentions login".to_string(), 0)
+/// .with_url("https://example.com/our-commit-style".to_string())
+/// .build();
+///
+/// assert_eq!(problem.error(), "Your commit mentions logging in");
+/// assert_eq!(
+///     problem.structured_labels(),
+///     vec![Label {
+///         text: "Mentions login".to_string(),
+///         offset: 0,
+///         length: 9,
+///     }]
+/// );
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ProblemBuilder {
+    error: String,
+    tip: String,
+    code: Code,
+    commit_message: String,
+    labels: Vec<(String, usize, usize)>,
+    url: Option<String>,
+    max_labels: Option<usize>,
+}
+
+/// Keep the first `max` labels, replacing the rest with one label saying how
+/// many were left out
+///
+/// The extra label spans the first label left out, so it's still drawn near
+/// the problems it stands for.
+pub(crate) fn cap_labels(
+    mut labels: Vec<(String, usize, usize)>,
+    max: Option<usize>,
+) -> Vec<(String, usize, usize)> {
+    let Some(max) = max else {
+        return labels;
+    };
+    if labels.len() <= max {
+        return labels;
+    }
+
+    let left_out = labels.len() - max;
+    let (_, offset, length) = labels[max];
+    labels.truncate(max);
+    labels.push((format!("… and {left_out} more"), offset, length));
+    labels
+}
+
+impl ProblemBuilder {
+    /// Start a problem with its title, advice on how to fix it, code, and the
+    /// commit it was found in
+    #[must_use]
+    pub fn new(error: String, tip: String, code: Code, commit_message: &CommitMessage<'_>) -> Self {
+        Self {
+            error,
+            tip,
+            code,
+            commit_message: String::from(commit_message.clone()),
+            labels: vec![],
+            url: None,
+            max_labels: None,
+        }
+    }
+
+    /// Label a span of the commit message, as a byte offset and length
+    #[must_use]
+    pub fn with_label(mut self, label: String, offset: usize, length: usize) -> Self {
+        self.labels.push((label, offset, length));
+        self
+    }
+
+    /// Label a whole line of the commit message, counting from 0 for the
+    /// subject
+    ///
+    /// Lines are split the same way as [`str::lines`], so the line ending
+    /// isn't labelled. Lines past the end of the message aren't labelled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{Code, Label, ProblemBuilder};
+    ///
+    /// let problem = ProblemBuilder::new(
+    ///     "Error title".to_string(),
+    ///     "Some advice on how to fix it".to_string(),
+    ///     Code::BodyWiderThan72Characters,
+    ///     &CommitMessage::from("Subject\n\nBody\n"),
+    /// )
+    /// .with_label_for_line("Here".to_string(), 2)
+    /// .build();
+    ///
+    /// assert_eq!(
+    ///     problem.structured_labels(),
+    ///     vec![Label {
+    ///         text: "Here".to_string(),
+    ///         offset: 9,
+    ///         length: 4,
+    ///     }]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_label_for_line(self, label: String, line_index: usize) -> Self {
+        let span = self
+            .commit_message
+            .split_inclusive('\n')
+            .scan(0, |offset, line| {
+                let start = *offset;
+                *offset += line.len();
+                let line = line
+                    .strip_suffix('\n')
+                    .map_or(line, |line| line.strip_suffix('\r').unwrap_or(line));
+                Some((start, line.len()))
+            })
+            .nth(line_index);
+
+        match span {
+            Some((offset, length)) => self.with_label(label, offset, length),
+            None => self,
+        }
+    }
+
+    /// Label the last line of the commit message that isn't blank
+    ///
+    /// This is where a missing trailer, like an issue ID, would go.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{Code, Label, ProblemBuilder};
+    ///
+    /// let problem = ProblemBuilder::new(
+    ///     "Error title".to_string(),
+    ///     "Some advice on how to fix it".to_string(),
+    ///     Code::JiraIssueKeyMissing,
+    ///     &CommitMessage::from("Subject\n\nBody\n\n"),
+    /// )
+    /// .with_label_at_last_line("No ID".to_string())
+    /// .build();
+    ///
+    /// assert_eq!(
+    ///     problem.structured_labels(),
+    ///     vec![Label {
+    ///         text: "No ID".to_string(),
+    ///         offset: 9,
+    ///         length: 4,
+    ///     }]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_label_at_last_line(self, label: String) -> Self {
+        let text = self.commit_message.trim_end();
+        let offset = text.rfind('\n').map_or(0, |newline| newline + 1);
+        let length = text.len() - offset;
+
+        self.with_label(label, offset, length)
+    }
+
+    /// Link to more information about the problem
+    #[must_use]
+    pub fn with_url(mut self, url: String) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Limit how many labels the problem has, so a message with thousands
+    /// of problems doesn't give a report too big to read
+    ///
+    /// Labels past the limit are replaced with one label saying how many
+    /// were left out. With `None`, every label is kept. Pass
+    /// [`LintConfig::max_labels_per_problem`](crate::LintConfig::max_labels_per_problem)
+    /// to use the same limit as the built in lints.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mit_commit::CommitMessage;
+    /// use mit_lint::{Code, ProblemBuilder};
+    ///
+    /// let problem = ProblemBuilder::new(
+    ///     "Error title".to_string(),
+    ///     "Some advice on how to fix it".to_string(),
+    ///     Code::BodyWiderThan72Characters,
+    ///     &CommitMessage::from("Subject\n\nBody\n"),
+    /// )
+    /// .with_label("One".to_string(), 0, 1)
+    /// .with_label("Two".to_string(), 1, 1)
+    /// .with_label("Three".to_string(), 2, 1)
+    /// .with_max_labels(Some(1))
+    /// .build();
+    ///
+    /// let labels: Vec<String> = problem
+    ///     .structured_labels()
+    ///     .into_iter()
+    ///     .map(|label| label.text)
+    ///     .collect();
+    /// assert_eq!(labels, vec!["One", "… and 2 more"]);
+    /// ```
+    #[must_use]
+    pub const fn with_max_labels(mut self, max: Option<usize>) -> Self {
+        self.max_labels = max;
+        self
+    }
+
+    /// Get the finished problem
+    #[must_use]
+    pub fn build(self) -> Problem {
+        Problem::from_commit_text(
+            self.error,
+            self.tip,
+            self.code,
+            self.commit_message,
+            if self.labels.is_empty() {
+                None
+            } else {
+                Some(cap_labels(self.labels, self.max_labels))
+            },
+            self.url,
+        )
+    }
+}
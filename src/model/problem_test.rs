@@ -2,8 +2,12 @@ use std::option::Option::None;
 
 use miette::Diagnostic;
 use mit_commit::CommitMessage;
+use quickcheck::TestResult;
 
-use crate::model::{code::Code, Problem};
+use crate::{
+    checks::duplicate_trailers,
+    model::{code::Code, Label, Problem, ProblemBuilder},
+};
 
 #[test]
 fn examples_has_error() {
@@ -172,3 +176,365 @@ fn test_it_contains_the_labels(start: usize, offset: usize) {
         vec![("String".to_string(), start, offset)]
     );
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn it_can_be_serialised_to_json() {
+    let problem = Problem::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &CommitMessage::from("Commit message"),
+        Some(vec![("String".to_string(), 10_usize, 20_usize)]),
+        Some("https://example.com".into()),
+    );
+
+    let actual = serde_json::to_string(&problem).unwrap();
+
+    assert_eq!(
+        actual,
+        r#"{"error":"Some error","tip":"Some tip","code":"NotConventionalCommit","commit_message":"Commit message","labels":[["String",10,20]],"url":"https://example.com"}"#
+    );
+    assert_eq!(serde_json::from_str::<Problem>(&actual).unwrap(), problem);
+}
+
+#[test]
+fn structured_labels_match_the_label_tuples() {
+    let message = CommitMessage::from(
+        "An example commit\n\nSigned-off-by: Someone <someone@example.com>\nSigned-off-by: \
+         Someone <someone@example.com>\n",
+    );
+    let problem = duplicate_trailers::lint(&message).unwrap();
+
+    let expected: Vec<Label> = problem
+        .labels()
+        .unwrap()
+        .map(|span| Label {
+            text: span.label().unwrap().to_string(),
+            offset: span.offset(),
+            length: span.len(),
+        })
+        .collect();
+
+    assert!(!expected.is_empty());
+    assert_eq!(problem.structured_labels(), expected);
+}
+
+#[test]
+fn structured_labels_are_empty_without_labels() {
+    let problem = Problem::new(
+        String::new(),
+        String::new(),
+        Code::NotConventionalCommit,
+        &"Commit Message".into(),
+        None,
+        None,
+    );
+
+    assert!(problem.structured_labels().is_empty());
+}
+
+#[test]
+fn the_builder_makes_the_same_problem_as_new() {
+    let commit = CommitMessage::from("Subject\n\nBody\n");
+
+    assert_eq!(
+        ProblemBuilder::new(
+            "Some error".into(),
+            "Some tip".into(),
+            Code::NotConventionalCommit,
+            &commit,
+        )
+        .with_label("Label".into(), 1, 2)
+        .with_url("https://example.com".into())
+        .build(),
+        Problem::new(
+            "Some error".into(),
+            "Some tip".into(),
+            Code::NotConventionalCommit,
+            &commit,
+            Some(vec![("Label".into(), 1, 2)]),
+            Some("https://example.com".into()),
+        )
+    );
+}
+
+#[test]
+fn the_builder_has_no_labels_or_url_by_default() {
+    let problem = ProblemBuilder::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &"Subject\n".into(),
+    )
+    .build();
+
+    assert!(problem.labels().is_none());
+    assert!(problem.url().is_none());
+}
+
+#[test]
+fn the_builder_ignores_lines_past_the_end() {
+    let problem = ProblemBuilder::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &"Subject\n".into(),
+    )
+    .with_label_for_line("Nowhere".into(), 5)
+    .build();
+
+    assert!(problem.structured_labels().is_empty());
+}
+
+#[test]
+fn the_builder_labels_the_last_line_without_a_trailing_newline() {
+    let problem = ProblemBuilder::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &"Subject".into(),
+    )
+    .with_label_at_last_line("Here".into())
+    .build();
+
+    assert_eq!(
+        problem.structured_labels(),
+        vec![Label {
+            text: "Here".into(),
+            offset: 0,
+            length: 7,
+        }]
+    );
+}
+
+#[test]
+fn char_offsets_count_multibyte_characters_once() {
+    let message = "🎉 Add café\n";
+    let byte_offset = message.find("café").unwrap();
+    let problem = Problem::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &message.into(),
+        Some(vec![("Word".into(), byte_offset, "café".len())]),
+        None,
+    );
+
+    let labels = problem.labels_as_char_offsets().unwrap();
+    assert_eq!(
+        labels,
+        vec![Label {
+            text: "Word".into(),
+            offset: 6,
+            length: 4,
+        }]
+    );
+    assert_ne!(labels[0].offset, byte_offset);
+    assert_ne!(labels[0].length, "café".len());
+    assert_eq!(
+        message
+            .chars()
+            .skip(labels[0].offset)
+            .take(labels[0].length)
+            .collect::<String>(),
+        "café"
+    );
+}
+
+#[test]
+fn char_offsets_are_none_without_labels() {
+    let problem = Problem::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &"🎉 Add café\n".into(),
+        None,
+        None,
+    );
+
+    assert_eq!(problem.labels_as_char_offsets(), None);
+}
+
+fn is_whole_span(text: &str, label: &Label) -> bool {
+    text.is_char_boundary(label.offset)
+        && text.is_char_boundary(label.offset + label.length)
+        && !text[label.offset..label.offset + label.length].contains('\n')
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn line_labels_land_on_char_boundaries(lines: Vec<String>, line_index: usize) -> bool {
+    let problem = ProblemBuilder::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &CommitMessage::from(lines.join("\n")),
+    )
+    .with_label_for_line("Here".into(), line_index % (lines.len() + 1))
+    .build();
+    let text = String::from(CommitMessage::from(lines.join("\n")));
+
+    problem
+        .structured_labels()
+        .iter()
+        .all(|label| is_whole_span(&text, label))
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn line_labels_cover_the_whole_line(lines: Vec<String>, line_index: usize) -> TestResult {
+    let problem = ProblemBuilder::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &CommitMessage::from(lines.join("\n")),
+    )
+    .with_label_for_line("Here".into(), line_index % (lines.len() + 1))
+    .build();
+    let text = String::from(CommitMessage::from(lines.join("\n")));
+    let Some(line) = text.lines().nth(line_index % (lines.len() + 1)) else {
+        return TestResult::from_bool(problem.structured_labels().is_empty());
+    };
+    let labels = problem.structured_labels();
+
+    TestResult::from_bool(
+        labels.len() == 1 && text[labels[0].offset..labels[0].offset + labels[0].length] == *line,
+    )
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn last_line_labels_land_on_char_boundaries(text: String) -> bool {
+    let commit = CommitMessage::from(text);
+    let problem = ProblemBuilder::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &commit,
+    )
+    .with_label_at_last_line("Here".into())
+    .build();
+    let text = String::from(commit);
+
+    problem
+        .structured_labels()
+        .iter()
+        .all(|label| is_whole_span(&text, label))
+}
+
+#[test]
+fn the_builder_keeps_the_text_it_labelled() {
+    // Converting this to a commit and back drops a `\r` each time
+    let commit = CommitMessage::from("\r\r\n\0");
+    let problem = ProblemBuilder::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &commit,
+    )
+    .with_label_for_line("Here".into(), 1)
+    .build();
+
+    assert_eq!(
+        problem,
+        Problem::from_commit_text(
+            "Some error".into(),
+            "Some tip".into(),
+            Code::NotConventionalCommit,
+            String::from(commit),
+            Some(vec![("Here".into(), 2, 1)]),
+            None,
+        )
+    );
+}
+
+#[test]
+fn the_same_finding_in_different_messages_is_not_equal() {
+    let first = duplicate_trailers::lint(&CommitMessage::from(
+        "An example commit\n\nSigned-off-by: Someone <someone@example.com>\nSigned-off-by: \
+         Someone <someone@example.com>\n",
+    ))
+    .unwrap();
+    let second = duplicate_trailers::lint(&CommitMessage::from(
+        "A similar commit!\n\nSigned-off-by: Someone <someone@example.com>\nSigned-off-by: \
+         Someone <someone@example.com>\n",
+    ))
+    .unwrap();
+
+    assert!(first.same_finding(&second));
+    assert_ne!(first, second);
+}
+
+#[test]
+fn findings_with_labels_in_different_places_are_different() {
+    let first = Problem::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::SubjectEndsWithPeriod,
+        &"Add login.\n".into(),
+        Some(vec![("Unneeded period".to_string(), 9, 1)]),
+        None,
+    );
+    let second = Problem::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::SubjectEndsWithPeriod,
+        &"Add a login.\n".into(),
+        Some(vec![("Unneeded period".to_string(), 11, 1)]),
+        None,
+    );
+
+    assert!(!first.same_finding(&second));
+}
+
+#[test]
+fn the_builder_caps_the_labels() {
+    let commit = CommitMessage::from("x".repeat(1000));
+    let problem = (0..1000)
+        .fold(
+            ProblemBuilder::new(
+                "Some error".into(),
+                "Some tip".into(),
+                Code::BodyWiderThan72Characters,
+                &commit,
+            ),
+            |builder, offset| builder.with_label("Here".to_string(), offset, 1),
+        )
+        .with_max_labels(Some(10))
+        .build();
+
+    let labels = problem.structured_labels();
+    assert_eq!(labels.len(), 11);
+    assert!(labels[..10].iter().all(|label| label.text == "Here"));
+    assert_eq!(
+        labels[10],
+        Label {
+            text: "… and 990 more".to_string(),
+            offset: 10,
+            length: 1,
+        }
+    );
+}
+
+#[test]
+fn the_builder_keeps_every_label_under_the_cap() {
+    let problem = ProblemBuilder::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::BodyWiderThan72Characters,
+        &CommitMessage::from("Subject\n"),
+    )
+    .with_label("Here".to_string(), 0, 7)
+    .with_max_labels(Some(1))
+    .build();
+
+    assert_eq!(
+        problem.structured_labels(),
+        vec![Label {
+            text: "Here".to_string(),
+            offset: 0,
+            length: 7,
+        }]
+    );
+}
@@ -0,0 +1,67 @@
+use miette::Diagnostic;
+
+use crate::model::Problem;
+
+/// Turn the problems from a lint into a [`Result`], so they can be bubbled
+/// up with `?`
+///
+/// The problem with the highest severity becomes the error. When several
+/// problems share it, the first one wins.
+///
+/// # Errors
+///
+/// If there are any problems
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{lint, report::first_error, Code, Lint, Lints};
+///
+/// let lints = Lints::new(vec![Lint::SubjectNotCapitalized].into_iter().collect());
+///
+/// let clean = lint(&CommitMessage::from("An example commit"), lints.clone());
+/// assert!(first_error(clean).is_ok());
+///
+/// let failing = lint(&CommitMessage::from("an example commit"), lints);
+/// assert_eq!(
+///     first_error(failing).unwrap_err().code(),
+///     &Code::SubjectNotCapitalized
+/// );
+/// ```
+// Returning the problem itself, rather than a box, keeps `?` and matching simple
+#[allow(clippy::result_large_err)]
+pub fn first_error(problems: Vec<Problem>) -> Result<(), Problem> {
+    problems
+        .into_iter()
+        .rev()
+        .max_by_key(|problem| problem.severity().unwrap_or_default())
+        .map_or(Ok(()), Err)
+}
+
+/// Turn a problem into an [`anyhow::Error`]
+///
+/// The problem can be got back out with [`anyhow::Error::downcast_ref`] to
+/// render it with miette.
+///
+/// # Examples
+///
+/// ```rust
+/// use miette::Diagnostic;
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{lint, report::into_anyhow, Lint, Lints, Problem};
+///
+/// let problems = lint(
+///     &CommitMessage::from("an example commit"),
+///     Lints::new(vec![Lint::SubjectNotCapitalized].into_iter().collect()),
+/// );
+/// let error = into_anyhow(problems[0].clone());
+///
+/// let problem = error.downcast_ref::<Problem>().unwrap();
+/// assert!(problem.help().is_some());
+/// ```
+#[cfg(feature = "anyhow")]
+#[must_use]
+pub fn into_anyhow(problem: Problem) -> anyhow::Error {
+    anyhow::Error::new(problem)
+}
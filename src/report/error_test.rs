@@ -0,0 +1,71 @@
+use miette::Diagnostic;
+use mit_commit::CommitMessage;
+
+use super::error::first_error;
+use crate::{
+    cmd::lint,
+    model::{Code, Lint, Lints},
+};
+
+#[test]
+fn no_problems_is_ok() {
+    assert_eq!(first_error(vec![]), Ok(()));
+}
+
+#[test]
+fn a_clean_message_is_ok() {
+    let problems = lint(
+        &CommitMessage::from("An example commit\n\nSome body content\n"),
+        Lints::new(vec![Lint::SubjectNotCapitalized].into_iter().collect()),
+    );
+
+    assert_eq!(first_error(problems), Ok(()));
+}
+
+#[test]
+fn the_first_problem_is_the_error() {
+    let problems = lint(
+        &CommitMessage::from("an example commit."),
+        Lints::new(
+            vec![Lint::SubjectNotCapitalized, Lint::SubjectEndsWithPeriod]
+                .into_iter()
+                .collect(),
+        ),
+    );
+    let expected = problems[0].clone();
+
+    let actual = first_error(problems);
+
+    assert_eq!(actual, Err(expected));
+}
+
+#[test]
+fn the_error_keeps_its_diagnostic() {
+    let problems = lint(
+        &CommitMessage::from("an example commit"),
+        Lints::new(vec![Lint::SubjectNotCapitalized].into_iter().collect()),
+    );
+
+    let actual = first_error(problems).unwrap_err();
+
+    assert_eq!(actual.code(), &Code::SubjectNotCapitalized);
+    assert!(actual.labels().is_some());
+}
+
+#[cfg(feature = "anyhow")]
+#[test]
+fn anyhow_errors_can_be_downcast_back_to_the_problem() {
+    use super::error::into_anyhow;
+    use crate::model::Problem;
+
+    let problems = lint(
+        &CommitMessage::from("an example commit"),
+        Lints::new(vec![Lint::SubjectNotCapitalized].into_iter().collect()),
+    );
+    let expected = problems[0].clone();
+
+    let actual = into_anyhow(expected.clone());
+
+    assert_eq!(actual.to_string(), expected.error());
+    assert_eq!(actual.downcast_ref::<Problem>(), Some(&expected));
+}
@@ -0,0 +1,117 @@
+use miette::{GraphicalReportHandler, GraphicalTheme};
+
+use crate::model::Problem;
+
+/// How to render a graphical report
+///
+/// # Examples
+///
+/// ```rust
+/// use miette::GraphicalTheme;
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{report::GraphicalOptions, Lint};
+///
+/// let problem = Lint::SubjectNotCapitalized
+///     .lint(&CommitMessage::from("an example commit"))
+///     .unwrap();
+/// let options = GraphicalOptions {
+///     width: 100,
+///     links: true,
+///     theme: GraphicalTheme::unicode_nocolor(),
+/// };
+/// let actual = options.render(&problem);
+/// assert!(actual.contains("Your commit message is missing a capital letter"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct GraphicalOptions {
+    /// The width to wrap the report at
+    pub width: usize,
+    /// Render the url as a terminal hyperlink on the code, rather than
+    /// printing it next to the code
+    pub links: bool,
+    /// The characters and colours to draw the report with
+    pub theme: GraphicalTheme,
+}
+
+impl Default for GraphicalOptions {
+    fn default() -> Self {
+        Self {
+            width: 80,
+            links: false,
+            theme: GraphicalTheme::none(),
+        }
+    }
+}
+
+impl GraphicalOptions {
+    /// Render a problem with these options
+    #[must_use]
+    pub fn render(&self, problem: &Problem) -> String {
+        let mut out = String::new();
+        GraphicalReportHandler::new_themed(self.theme.clone())
+            .with_width(self.width)
+            .with_links(self.links)
+            .render_report(&mut out, problem)
+            .expect("writing to a string can not fail");
+        out
+    }
+}
+
+/// Render a problem as a graphical report, like the ones miette prints
+///
+/// Links are never rendered, so the url is printed next to the code instead.
+/// Use [`GraphicalOptions`] for more control over how it looks.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{report, Lint};
+///
+/// let problem = Lint::SubjectNotCapitalized
+///     .lint(&CommitMessage::from("an example commit"))
+///     .unwrap();
+/// let actual = report::render_graphical(&problem, 80, false);
+/// assert!(actual.contains("Your commit message is missing a capital letter"));
+/// ```
+#[must_use]
+pub fn render_graphical(problem: &Problem, width: usize, color: bool) -> String {
+    GraphicalOptions {
+        width,
+        links: false,
+        theme: if color {
+            GraphicalTheme::ascii()
+        } else {
+            GraphicalTheme::none()
+        },
+    }
+    .render(problem)
+}
+
+/// Render several problems as one graphical report
+///
+/// Each problem is rendered as with [`render_graphical`], in the order given,
+/// with a blank line between them. An empty slice renders as an empty string.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{lint, report, Lints};
+///
+/// let problems = lint(
+///     &CommitMessage::from("an example commit."),
+///     Lints::available().clone(),
+/// );
+/// let actual = report::render_all_graphical(&problems, 80, false);
+/// assert!(actual.contains("Your commit message is missing a capital letter"));
+/// assert!(actual.contains("Your commit message ends with a period"));
+/// ```
+#[must_use]
+pub fn render_all_graphical(problems: &[Problem], width: usize, color: bool) -> String {
+    problems
+        .iter()
+        .map(|problem| render_graphical(problem, width, color))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,129 @@
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use mit_commit::CommitMessage;
+
+use crate::{
+    cmd::lint,
+    model::{Lint, Lints},
+    report::{render_all_graphical, render_graphical, GraphicalOptions},
+};
+
+#[test]
+fn it_renders_the_same_as_a_hand_rolled_handler() {
+    let problem = Lint::SubjectNotCapitalized
+        .lint(&CommitMessage::from(
+            "an example commit\n\nThis is an example commit\n",
+        ))
+        .unwrap();
+
+    let actual = render_graphical(&problem, 80, false);
+    let expected = fmt_report(&problem.into_report());
+
+    assert_eq!(actual, expected, "Expected {expected:?}, found {actual:?}");
+}
+
+#[test]
+fn the_default_options_match_the_test_handler() {
+    let problem = Lint::SubjectNotCapitalized
+        .lint(&CommitMessage::from("an example commit"))
+        .unwrap();
+
+    let actual = GraphicalOptions::default().render(&problem);
+    let expected = fmt_report(&problem.into_report());
+
+    assert_eq!(actual, expected, "Expected {expected:?}, found {actual:?}");
+}
+
+#[test]
+fn links_are_only_rendered_when_asked() {
+    let problem = Lint::SubjectNotCapitalized
+        .lint(&CommitMessage::from("an example commit"))
+        .unwrap();
+    let with_links = GraphicalOptions {
+        links: true,
+        ..GraphicalOptions::default()
+    };
+
+    assert!(!GraphicalOptions::default()
+        .render(&problem)
+        .contains("\u{1b}]8;;"));
+    assert!(with_links.render(&problem).contains("\u{1b}]8;;"));
+}
+
+#[test]
+fn it_respects_the_width() {
+    let problem = Lint::SubjectNotCapitalized
+        .lint(&CommitMessage::from("an example commit"))
+        .unwrap();
+
+    let narrow = render_graphical(&problem, 40, false);
+    let wide = render_graphical(&problem, 80, false);
+
+    assert!(
+        narrow.lines().count() > wide.lines().count(),
+        "Expected {:?} to wrap more than {:?}",
+        narrow,
+        wide
+    );
+}
+
+#[test]
+fn it_only_uses_colour_when_asked() {
+    let problem = Lint::SubjectNotCapitalized
+        .lint(&CommitMessage::from("an example commit"))
+        .unwrap();
+
+    assert!(!render_graphical(&problem, 80, false).contains('\u{1b}'));
+    assert!(render_graphical(&problem, 80, true).contains('\u{1b}'));
+}
+
+#[test]
+fn it_renders_every_problem_in_order() {
+    let message = CommitMessage::from(format!(
+        "{}\n\nSigned-off-by: Someone <someone@example.com>\nSigned-off-by: Someone \
+         <someone@example.com>\n",
+        "x".repeat(73)
+    ));
+    let problems = lint(
+        &message,
+        Lints::from(vec![
+            Lint::SubjectLongerThan72Characters,
+            Lint::DuplicatedTrailers,
+        ]),
+    );
+
+    let actual = render_all_graphical(&problems, 80, false);
+    let expected = format!(
+        "{}\n{}",
+        render_graphical(&problems[0], 80, false),
+        render_graphical(&problems[1], 80, false)
+    );
+
+    assert_eq!(problems.len(), 2);
+    assert_eq!(actual, expected, "Expected {expected:?}, found {actual:?}");
+    let trailers = actual
+        .find("Your commit message has duplicated trailers")
+        .unwrap();
+    let subject = actual
+        .find("Your subject is longer than 72 characters")
+        .unwrap();
+    assert!(
+        trailers < subject,
+        "Expected the duplicated trailers before the long subject in {:?}",
+        actual
+    );
+}
+
+#[test]
+fn nothing_is_rendered_for_no_problems() {
+    assert_eq!(render_all_graphical(&[], 80, false), "");
+}
+
+fn fmt_report(diag: &Report) -> String {
+    let mut out = String::new();
+    GraphicalReportHandler::new_themed(GraphicalTheme::none())
+        .with_width(80)
+        .with_links(false)
+        .render_report(&mut out, diag.as_ref())
+        .unwrap();
+    out
+}
@@ -0,0 +1,139 @@
+//! Rendering problems as JUnit XML, for test dashboards
+
+use mit_commit::CommitMessage;
+
+use crate::{
+    cmd::lint,
+    model::{Lint, Lints, Problem},
+};
+
+/// Render problems as a JUnit XML test suite
+///
+/// There is a `<testcase>` for every lint, named after the lint, so a clean
+/// run is a suite of passing tests. Each problem fails the testcase for the
+/// lint that found it, with a `<failure>` carrying the error as its message
+/// and the help text as its body. Use [`lint_to_junit`] if you only want
+/// testcases for the lints you ran.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{report::junit::to_junit, Lint};
+///
+/// let problem = Lint::SubjectNotCapitalized
+///     .lint(&CommitMessage::from("an example commit"))
+///     .unwrap();
+/// let actual = to_junit(&[problem], "commit");
+/// assert!(actual.contains(r#"<testcase name="subject-line-not-capitalized" classname="commit">"#));
+/// assert!(actual.contains(r#"<testcase name="duplicated-trailers" classname="commit"/>"#));
+/// ```
+#[must_use]
+pub fn to_junit(problems: &[Problem], suite_name: &str) -> String {
+    render_suite(suite_name, &cases(Lint::all_lints(), problems))
+}
+
+/// Lint a commit message, rendering the result as a JUnit XML test suite
+///
+/// There is a `<testcase>` for every lint in `lints`, named after the lint.
+/// Lints that found a problem have a `<failure>`, as in [`to_junit`].
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{report::junit::lint_to_junit, Lint, Lints};
+///
+/// let actual = lint_to_junit(
+///     &CommitMessage::from("An example commit"),
+///     Lints::from(vec![Lint::SubjectNotCapitalized]),
+///     "commit",
+/// );
+/// assert!(
+///     actual.contains(r#"<testcase name="subject-line-not-capitalized" classname="commit"/>"#)
+/// );
+/// ```
+#[must_use]
+pub fn lint_to_junit(commit_message: &CommitMessage<'_>, lints: Lints, suite_name: &str) -> String {
+    let problems = lint(commit_message, lints.clone());
+
+    render_suite(suite_name, &cases(lints, &problems))
+}
+
+/// Pair the name of each lint with the problems it found, or with `None` if
+/// it found nothing
+///
+/// Problems with a code that isn't one of the lints, like those from a
+/// [`CustomLint`](crate::CustomLint), are named after their code instead.
+fn cases(
+    lints: impl IntoIterator<Item = Lint>,
+    problems: &[Problem],
+) -> Vec<(String, Option<&Problem>)> {
+    let lints: Vec<Lint> = lints.into_iter().collect();
+    let mut cases: Vec<(String, Option<&Problem>)> = vec![];
+
+    for lint in &lints {
+        let found: Vec<&Problem> = problems
+            .iter()
+            .filter(|problem| *problem.code() == lint.code())
+            .collect();
+        if found.is_empty() {
+            cases.push((lint.name().to_string(), None));
+        }
+        for problem in found {
+            cases.push((lint.name().to_string(), Some(problem)));
+        }
+    }
+
+    cases.extend(
+        problems
+            .iter()
+            .filter(|problem| !lints.iter().any(|lint| lint.code() == *problem.code()))
+            .map(|problem| (format!("{:?}", problem.code()), Some(problem))),
+    );
+    cases
+}
+
+fn render_suite(suite_name: &str, cases: &[(String, Option<&Problem>)]) -> String {
+    let suite_name = escape(suite_name);
+    let failures = cases
+        .iter()
+        .filter(|(_, problem)| problem.is_some())
+        .count();
+    let mut out = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuite name=\"{suite_name}\" \
+         tests=\"{}\" failures=\"{failures}\">\n",
+        cases.len()
+    );
+
+    for (name, problem) in cases {
+        let name = escape(name);
+        match problem {
+            Some(problem) => out.push_str(&format!(
+                "  <testcase name=\"{name}\" classname=\"{suite_name}\">\n    <failure \
+                 message=\"{}\">{}</failure>\n  </testcase>\n",
+                escape(problem.error()),
+                escape(problem.tip())
+            )),
+            None => out.push_str(&format!(
+                "  <testcase name=\"{name}\" classname=\"{suite_name}\"/>\n"
+            )),
+        }
+    }
+
+    out.push_str("</testsuite>\n");
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.chars()
+        .map(|character| match character {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&quot;".to_string(),
+            '\'' => "&apos;".to_string(),
+            _ => character.to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,128 @@
+use mit_commit::CommitMessage;
+
+use super::junit::{lint_to_junit, to_junit};
+use crate::{
+    cmd::lint,
+    model::{Code, Lint, Lints, Problem},
+};
+
+#[test]
+fn one_failure_and_one_pass() {
+    let actual = lint_to_junit(
+        &CommitMessage::from("an example commit"),
+        Lints::from(vec![
+            Lint::SubjectNotCapitalized,
+            Lint::SubjectLongerThan72Characters,
+        ]),
+        "commit",
+    );
+    let expected = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<testsuite name=\"commit\" tests=\"2\" failures=\"1\">
+  <testcase name=\"subject-longer-than-72-characters\" classname=\"commit\"/>
+  <testcase name=\"subject-line-not-capitalized\" classname=\"commit\">
+    <failure message=\"Your commit message is missing a capital letter\">The subject line is a title, and as such should be capitalised.
+
+You can fix this by capitalising the first character in the subject</failure>
+  </testcase>
+</testsuite>
+";
+
+    assert_eq!(
+        actual, expected,
+        "Expected {:?}, found {:?}",
+        expected, actual
+    );
+}
+
+#[test]
+fn problems_fail_the_testcase_for_their_lint() {
+    let problems = lint(
+        &CommitMessage::from("an example commit"),
+        Lints::from(vec![Lint::SubjectNotCapitalized]),
+    );
+    let actual = to_junit(&problems, "commit");
+
+    assert!(actual.contains(&format!(
+        "<testsuite name=\"commit\" tests=\"{}\" failures=\"1\">",
+        Lint::all_lints().count()
+    )));
+    assert!(actual.contains(
+        "  <testcase name=\"subject-line-not-capitalized\" classname=\"commit\">
+    <failure message=\"Your commit message is missing a capital letter\">The subject line is a title, and as such should be capitalised.
+
+You can fix this by capitalising the first character in the subject</failure>
+  </testcase>
+"
+    ));
+}
+
+#[test]
+fn a_clean_run_passes_every_lint() {
+    let actual = to_junit(&[], "commit");
+
+    assert!(actual.contains(&format!(
+        "<testsuite name=\"commit\" tests=\"{}\" failures=\"0\">",
+        Lint::all_lints().count()
+    )));
+    for lint in Lint::all_lints() {
+        let testcase = format!(
+            "  <testcase name=\"{}\" classname=\"commit\"/>\n",
+            lint.name()
+        );
+        assert!(
+            actual.contains(&testcase),
+            "Expected {:?} in {:?}",
+            testcase,
+            actual
+        );
+    }
+}
+
+#[test]
+fn testcases_have_the_same_names_as_lint_to_junit() {
+    let message = CommitMessage::from("an example commit");
+    let lints = Lints::from(vec![Lint::SubjectNotCapitalized]);
+    let from_lint = lint_to_junit(&message, lints.clone(), "commit");
+    let from_problems = to_junit(&lint(&message, lints), "commit");
+
+    let failing = "<testcase name=\"subject-line-not-capitalized\" classname=\"commit\">";
+    assert!(from_lint.contains(failing));
+    assert!(from_problems.contains(failing));
+}
+
+#[test]
+fn problems_without_a_lint_are_named_after_their_code() {
+    let problem = Problem::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::StaleAuthor,
+        &"An example commit".into(),
+        None,
+        None,
+    );
+    let actual = to_junit(&[problem], "commit");
+
+    assert!(actual.contains(
+        "  <testcase name=\"StaleAuthor\" classname=\"commit\">
+    <failure message=\"Some error\">Some tip</failure>
+  </testcase>
+"
+    ));
+}
+
+#[test]
+fn text_is_escaped() {
+    let problem = Problem::new(
+        "Don't use <, > or &".into(),
+        "Say \"less than\"".into(),
+        Code::SubjectNotCapitalized,
+        &"An example commit".into(),
+        None,
+        None,
+    );
+    let actual = to_junit(&[problem], "a \"suite\"");
+
+    assert!(actual.contains("name=\"a &quot;suite&quot;\""));
+    assert!(actual
+        .contains("message=\"Don&apos;t use &lt;, &gt; or &amp;\">Say &quot;less than&quot;<"));
+}
@@ -0,0 +1,47 @@
+use std::collections::BTreeMap;
+
+use crate::model::{Label, Problem};
+
+/// Group the labels of a problem by the line they start on
+///
+/// Lines are counted from 0 for the subject, like
+/// [`ProblemBuilder::with_label_for_line`](crate::ProblemBuilder::with_label_for_line),
+/// and the labels on each line keep the order they have in the problem. This
+/// is handy for drawing markers in an editor gutter.
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_lint::{report::group_by_line, Code, Problem};
+///
+/// let problem = Problem::new(
+///     "Error title".to_string(),
+///     "Some advice on how to fix it".to_string(),
+///     Code::BodyWiderThan72Characters,
+///     &"Subject\n\nBody\n".into(),
+///     Some(vec![
+///         ("First".to_string(), 0, 3),
+///         ("Second".to_string(), 9, 4),
+///     ]),
+///     None,
+/// );
+///
+/// let grouped = group_by_line(&problem);
+/// assert_eq!(grouped.keys().copied().collect::<Vec<_>>(), vec![0, 2]);
+/// assert_eq!(grouped[&2][0].text, "Second");
+/// ```
+#[must_use]
+pub fn group_by_line(problem: &Problem) -> BTreeMap<usize, Vec<Label>> {
+    let commit_text = problem.commit_text().as_bytes();
+    let mut grouped: BTreeMap<usize, Vec<Label>> = BTreeMap::new();
+
+    for label in problem.structured_labels() {
+        let line = commit_text[..label.offset.min(commit_text.len())]
+            .iter()
+            .filter(|byte| **byte == b'\n')
+            .count();
+        grouped.entry(line).or_default().push(label);
+    }
+
+    grouped
+}
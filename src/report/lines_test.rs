@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use mit_commit::CommitMessage;
+
+use super::lines::group_by_line;
+use crate::model::{Code, Label, Problem};
+
+fn label(text: &str, offset: usize, length: usize) -> Label {
+    Label {
+        text: text.to_string(),
+        offset,
+        length,
+    }
+}
+
+#[test]
+fn labels_are_grouped_by_the_line_they_start_on() {
+    let problem = Problem::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &CommitMessage::from("Subject\n\nFirst body line\nSecond body line\n"),
+        Some(vec![
+            ("Subject".into(), 0, 7),
+            ("First".into(), 9, 5),
+            ("Line".into(), 20, 4),
+            ("Second".into(), 25, 6),
+        ]),
+        None,
+    );
+
+    let actual = group_by_line(&problem);
+    let mut expected = BTreeMap::new();
+    expected.insert(0, vec![label("Subject", 0, 7)]);
+    expected.insert(2, vec![label("First", 9, 5), label("Line", 20, 4)]);
+    expected.insert(3, vec![label("Second", 25, 6)]);
+
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn problems_without_labels_have_no_lines() {
+    let problem = Problem::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &CommitMessage::from("Subject\n"),
+        None,
+        None,
+    );
+
+    assert!(group_by_line(&problem).is_empty());
+}
+
+#[test]
+fn labels_past_the_end_are_on_the_last_line() {
+    let problem = Problem::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &CommitMessage::from("Subject\nBody"),
+        Some(vec![("End".into(), 100, 1)]),
+        None,
+    );
+
+    assert_eq!(
+        group_by_line(&problem).keys().copied().collect::<Vec<_>>(),
+        vec![1]
+    );
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[quickcheck]
+fn every_label_is_in_exactly_one_group(text: String, offsets: Vec<usize>) -> bool {
+    let labels: Vec<(String, usize, usize)> = offsets
+        .iter()
+        .map(|offset| ("Label".to_string(), offset % (text.len() + 1), 0))
+        .collect();
+    let problem = Problem::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &CommitMessage::from(text),
+        Some(labels.clone()),
+        None,
+    );
+
+    group_by_line(&problem)
+        .values()
+        .map(Vec::len)
+        .sum::<usize>()
+        == labels.len()
+}
@@ -0,0 +1,31 @@
+//! Rendering problems for people and tools to read, and turning them into
+//! errors
+//!
+//! With the `serde` feature, wrap problems in a [`Report`] to hand them to
+//! other tools. It carries a [`SCHEMA_VERSION`] so readers can tell which
+//! format they have.
+
+pub use error::first_error;
+#[cfg(feature = "anyhow")]
+pub use error::into_anyhow;
+pub use graphical::{render_all_graphical, render_graphical, GraphicalOptions};
+pub use lines::group_by_line;
+#[cfg(feature = "serde")]
+pub use schema::Error as SchemaError;
+pub use schema::{Report, SCHEMA_VERSION};
+
+mod error;
+#[cfg(test)]
+mod error_test;
+mod graphical;
+#[cfg(test)]
+mod graphical_test;
+pub mod junit;
+#[cfg(test)]
+mod junit_test;
+mod lines;
+#[cfg(test)]
+mod lines_test;
+mod schema;
+#[cfg(test)]
+mod schema_test;
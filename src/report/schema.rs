@@ -0,0 +1,127 @@
+//! A versioned collection of problems, for handing to other tools
+
+#[cfg(feature = "serde")]
+use std::convert::TryFrom;
+
+#[cfg(feature = "serde")]
+use miette::Diagnostic;
+#[cfg(feature = "serde")]
+use thiserror::Error;
+
+use crate::model::Problem;
+
+/// The version of the serialized [`Report`] format
+///
+/// This goes up by one whenever a field is removed or renamed, or its meaning
+/// changes. Adding a field doesn't change it. A [`Report`] will only
+/// deserialize from the version it was built with, so a consumer finds out
+/// straight away that it's reading a format it doesn't understand, rather
+/// than getting odd results later on.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// The problems found in a commit, along with the version of the format
+/// they're in
+///
+/// # Examples
+///
+/// ```rust
+/// use mit_commit::CommitMessage;
+/// use mit_lint::{
+///     lint,
+///     report::{Report, SCHEMA_VERSION},
+///     Lint,
+///     Lints,
+/// };
+///
+/// let report = Report::new(lint(
+///     &CommitMessage::from("an example commit"),
+///     Lints::from(vec![Lint::SubjectNotCapitalized]),
+/// ));
+///
+/// assert_eq!(report.schema_version(), SCHEMA_VERSION);
+/// assert_eq!(report.problems().len(), 1);
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "UncheckedReport"))]
+pub struct Report {
+    schema_version: u32,
+    problems: Vec<Problem>,
+}
+
+impl Report {
+    /// Create a report of the current [`SCHEMA_VERSION`]
+    #[must_use]
+    pub const fn new(problems: Vec<Problem>) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            problems,
+        }
+    }
+
+    /// The version of the format this report is in
+    #[must_use]
+    pub const fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+
+    /// The problems found
+    #[must_use]
+    pub fn problems(&self) -> &[Problem] {
+        &self.problems
+    }
+}
+
+impl From<Vec<Problem>> for Report {
+    fn from(problems: Vec<Problem>) -> Self {
+        Self::new(problems)
+    }
+}
+
+impl From<Report> for Vec<Problem> {
+    fn from(report: Report) -> Self {
+        report.problems
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UncheckedReport {
+    schema_version: u32,
+    problems: Vec<Problem>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UncheckedReport> for Report {
+    type Error = Error;
+
+    fn try_from(report: UncheckedReport) -> Result<Self, Self::Error> {
+        if report.schema_version != SCHEMA_VERSION {
+            return Err(Error::UnsupportedSchemaVersion {
+                found: report.schema_version,
+                expected: SCHEMA_VERSION,
+            });
+        }
+
+        Ok(Self::new(report.problems))
+    }
+}
+
+/// Errors reading a [`Report`]
+#[cfg(feature = "serde")]
+#[derive(Error, Debug, Diagnostic, Eq, PartialEq, Clone, Copy)]
+pub enum Error {
+    /// The report is in a version of the format we can't read
+    #[error("Unsupported report schema version {found}, expected {expected}")]
+    #[diagnostic(
+        code(mit_lint::report::schema::error::unsupported_schema_version),
+        url(docsrs),
+        help("use a version of mit-lint that matches the one that wrote the report")
+    )]
+    UnsupportedSchemaVersion {
+        /// The version in the report
+        found: u32,
+        /// The version we can read
+        expected: u32,
+    },
+}
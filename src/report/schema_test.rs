@@ -0,0 +1,69 @@
+use mit_commit::CommitMessage;
+
+use super::schema::{Report, SCHEMA_VERSION};
+use crate::model::{Code, Problem};
+
+fn problem() -> Problem {
+    Problem::new(
+        "Some error".into(),
+        "Some tip".into(),
+        Code::NotConventionalCommit,
+        &CommitMessage::from("Commit message"),
+        Some(vec![("String".to_string(), 10_usize, 20_usize)]),
+        Some("https://example.com".into()),
+    )
+}
+
+#[test]
+fn new_reports_are_the_current_version() {
+    let report = Report::new(vec![problem()]);
+
+    assert_eq!(report.schema_version(), SCHEMA_VERSION);
+    assert_eq!(report.problems(), &[problem()]);
+    assert_eq!(Vec::<Problem>::from(report), vec![problem()]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn it_can_be_serialised_to_json() {
+    let report = Report::from(vec![problem()]);
+
+    let actual = serde_json::to_string(&report).unwrap();
+
+    assert_eq!(
+        actual,
+        r#"{"schema_version":1,"problems":[{"error":"Some error","tip":"Some tip","code":"NotConventionalCommit","commit_message":"Commit message","labels":[["String",10,20]],"url":"https://example.com"}]}"#
+    );
+    assert_eq!(serde_json::from_str::<Report>(&actual).unwrap(), report);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn other_versions_fail_to_deserialize() {
+    for version in [0, SCHEMA_VERSION + 1] {
+        let actual = serde_json::from_str::<Report>(&format!(
+            r#"{{"schema_version":{version},"problems":[]}}"#
+        ))
+        .unwrap_err()
+        .to_string();
+
+        assert_eq!(
+            actual,
+            format!("Unsupported report schema version {version}, expected {SCHEMA_VERSION}")
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn a_missing_version_fails_to_deserialize() {
+    let actual = serde_json::from_str::<Report>(r#"{"problems":[]}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        actual.contains("missing field `schema_version`"),
+        "Expected a missing field error, found {:?}",
+        actual
+    );
+}